@@ -1,5 +1,9 @@
+#![feature(portable_simd)]
+
 // use std::time::Duration;
 
+use std::simd::Simd;
+
 // use criterion::{black_box, criterion_group, criterion_main, Criterion};
 // use fast_array::{fast_array::fast_array_basics::AsFastArray, FastArray}; // Replace with your actual crate
 
@@ -58,24 +62,33 @@
 // // }
 
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use fast_collections::{fast_matrix, prelude::{IntoFastArray, IntoFastMatrix}, FastMatrix};
+use fast_collections::{fast_matrix, prelude::IntoFastMatrix, FastMatrix};
 
 fn bench_fast_matrix(c: &mut Criterion) {
     c.bench_function("FastMatrix demo bench", |b| {
-        println!("xxx1");
         b.iter(|| {
             let fast_matrix: FastMatrix<u32> = fast_matrix!(6; 250; 250);
-            println!("xxx2");
             let x: FastMatrix<u32> = fast_matrix.into_fast_iter_arrays().map(|mut x| {x.simd_add_8_lanes(5); x}).into_fast_matrix(250, 250);
 
-            // let x = black_box(5);
+            black_box(x);
+        });
+    });
+
+    c.bench_function("FastMatrix rows_mut simd bench", |b| {
+        b.iter(|| {
+            let mut fast_matrix: FastMatrix<u32> = fast_matrix!(6; 250; 250);
+
+            for mut row in fast_matrix.rows_mut() {
+                let (prefix, middle, suffix) = row.as_mut_slice().unwrap().as_simd_mut::<8>();
+                prefix.iter_mut().chain(suffix.iter_mut()).for_each(|x| *x += 5);
+                middle.iter_mut().for_each(|x| *x += Simd::splat(5));
+            }
 
-            // println!("{}", x)
+            black_box(fast_matrix);
         });
     });
 }
 
 criterion_group!(benches, bench_fast_matrix);
 criterion_main!(benches);
-// fn main() {}
-
+// fn main() {}
\ No newline at end of file
@@ -0,0 +1,50 @@
+use std::time::Duration;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use fast_collections::FastMatrix;
+
+const SIZE: usize = 4096;
+
+fn bench_transpose(c: &mut Criterion) {
+    let fast_matrix: FastMatrix<u32> = FastMatrix::new_func(SIZE, SIZE, |(row, column)| (row * SIZE + column) as u32);
+    let rectangular: FastMatrix<u32> = FastMatrix::new_func(SIZE, SIZE / 2, |(row, column)| (row * SIZE + column) as u32);
+
+    let mut group = c.benchmark_group("FastMatrix transpose 4k x 4k");
+    group.sample_size(10);
+    group.measurement_time(Duration::from_secs(10));
+
+    // the element by element implementation `transpose` used to have
+    group.bench_function("naive", |b| {
+        b.iter(|| {
+            let transposed = FastMatrix::new_func(fast_matrix.columns, fast_matrix.rows, |(row, column)| fast_matrix[(column, row)]);
+            black_box(transposed);
+        })
+    });
+
+    group.bench_function("cache-oblivious", |b| {
+        b.iter(|| {
+            black_box(fast_matrix.transpose());
+        })
+    });
+
+    group.bench_function("in place, square", |b| {
+        let mut fast_matrix = fast_matrix.clone();
+        b.iter(|| {
+            fast_matrix.transpose_in_place();
+            black_box(&fast_matrix);
+        })
+    });
+
+    group.bench_function("in place, 4k x 2k", |b| {
+        let mut rectangular = rectangular.clone();
+        b.iter(|| {
+            rectangular.transpose_in_place();
+            black_box(&rectangular);
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_transpose);
+criterion_main!(benches);
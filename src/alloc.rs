@@ -0,0 +1,116 @@
+//! the allocation every collection of the library goes through.
+//!
+//! every buffer is allocated with [`layout`] and freed with [`deallocate`], so a pointer can be moved between collections
+//! (like [`crate::FastArray`] to [`crate::FastMatrix`]) without copying the elements.
+
+use std::{
+    alloc::{alloc, alloc_zeroed, dealloc, handle_alloc_error, Layout},
+    ptr,
+};
+
+use std::alloc::realloc;
+
+use crate::error::AllocError;
+
+/// the smallest alignment of a buffer, so it can be loaded with aligned simd instructions.
+const ALIGN: usize = 32;
+
+/// the layout of a buffer of `len` elements of type T.
+#[inline(always)]
+pub(crate) fn layout<T>(len: usize) -> Result<Layout, AllocError> {
+    let size = len.checked_mul(std::mem::size_of::<T>()).ok_or(AllocError::CapacityOverflow)?;
+    Layout::from_size_align(size, ALIGN.max(std::mem::align_of::<T>())).map_err(|_| AllocError::CapacityOverflow)
+}
+
+/// the pointer given out for buffers of zero bytes (like the ones of zero-sized types), which are never really allocated.
+#[inline(always)]
+fn dangling<T>(layout: Layout) -> *mut T {
+    ptr::without_provenance_mut(layout.align())
+}
+
+/// the pointer of a buffer of zero elements, which can be given to [`deallocate`] with `len == 0`.
+#[inline(always)]
+pub(crate) fn empty<T>() -> *mut T {
+    dangling(unwrap_alloc(layout::<T>(0)))
+}
+
+#[inline(always)]
+fn try_allocate_with<T>(len: usize, allocator: unsafe fn(Layout) -> *mut u8) -> Result<*mut T, AllocError> {
+    if len == 0 {
+        return Err(AllocError::ZeroLength);
+    }
+
+    let layout = layout::<T>(len)?;
+    if layout.size() == 0 {
+        return Ok(dangling(layout));
+    }
+
+    let pointer = unsafe { allocator(layout) as *mut T };
+    if pointer.is_null() {
+        return Err(AllocError::AllocFailed { layout });
+    }
+
+    Ok(pointer)
+}
+
+/// allocates uninitialized memory for `len` elements.
+pub(crate) fn try_allocate<T>(len: usize) -> Result<*mut T, AllocError> {
+    try_allocate_with(len, alloc)
+}
+
+/// allocates memory for `len` elements with every byte set to zero.
+pub(crate) fn try_allocate_zeroed<T>(len: usize) -> Result<*mut T, AllocError> {
+    try_allocate_with(len, alloc_zeroed)
+}
+
+/// unwraps an allocation the way [`Vec`] does: panicking on overflow and calling [`handle_alloc_error`] if the allocator failed.
+#[inline(always)]
+pub(crate) fn unwrap_alloc<T>(result: Result<T, AllocError>) -> T {
+    match result {
+        Ok(value) => value,
+        Err(AllocError::AllocFailed { layout }) => handle_alloc_error(layout),
+        Err(error) => panic!("{error}"),
+    }
+}
+
+/// same as [`try_allocate`], but panics or aborts like [`unwrap_alloc`].
+#[inline(always)]
+pub(crate) fn allocate<T>(len: usize) -> *mut T {
+    unwrap_alloc(try_allocate(len))
+}
+
+/// same as [`try_allocate_zeroed`], but panics or aborts like [`unwrap_alloc`].
+#[inline(always)]
+pub(crate) fn allocate_zeroed<T>(len: usize) -> *mut T {
+    unwrap_alloc(try_allocate_zeroed(len))
+}
+
+/// moves a buffer of `old_len` elements to one of `new_len` elements, keeping the first `min(old_len, new_len)` of them.
+///
+/// if `old_len == 0` nothing has been allocated yet, and `pointer` is ignored.
+pub(crate) unsafe fn reallocate<T>(pointer: *mut T, old_len: usize, new_len: usize) -> *mut T {
+    if old_len == 0 {
+        return allocate(new_len);
+    }
+
+    let old_layout = unwrap_alloc(layout::<T>(old_len));
+    let new_layout = unwrap_alloc(layout::<T>(new_len));
+    if old_layout.size() == 0 {
+        return dangling(new_layout);
+    }
+
+    let pointer = unsafe { realloc(pointer as *mut u8, old_layout, new_layout.size()) as *mut T };
+    if pointer.is_null() {
+        handle_alloc_error(new_layout);
+    }
+
+    pointer
+}
+
+/// frees a buffer of `len` elements, without dropping them.
+pub(crate) unsafe fn deallocate<T>(pointer: *mut T, len: usize) {
+    let layout = unwrap_alloc(layout::<T>(len));
+    if layout.size() != 0 {
+        unsafe { dealloc(pointer as *mut u8, layout) };
+    }
+}
@@ -0,0 +1,392 @@
+use std::{
+    fmt::Display,
+    marker::PhantomData,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign},
+};
+
+use crate::{
+    fast_matrix::fast_matrix_view::MatrixView,
+    fast_tensor::{fast_tensor::Offsets, fast_tensor_basics::broadcast_strides},
+    FastArray, FastMatrix, FastTensor,
+};
+
+/// ## Info
+/// the error returned when two shapes can't be broadcast together.
+///
+/// two shapes are compatible when, aligning them on their last axis, every pair of axes either has the same size or contains a 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BroadcastError {
+    pub left: Box<[usize]>,
+    pub right: Box<[usize]>,
+}
+
+impl Display for BroadcastError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "cannot broadcast the shapes {:?} and {:?} together",
+            self.left, self.right
+        )
+    }
+}
+
+impl std::error::Error for BroadcastError {}
+
+/// ## Info
+/// computes the shape two operands of the given shapes get broadcast to, following NumPy's rules.
+///
+/// ## Example
+/// ```
+/// use fast_collections::broadcast::broadcast_shapes;
+///
+/// assert_eq!(&*broadcast_shapes(&[4, 1, 3], &[2, 1]).unwrap(), &[4, 2, 3]);
+/// assert!(broadcast_shapes(&[2, 3], &[2]).is_err());
+/// ```
+pub fn broadcast_shapes(left: &[usize], right: &[usize]) -> Result<Box<[usize]>, BroadcastError> {
+    let ndim = left.len().max(right.len());
+    let mut shape = vec![0; ndim].into_boxed_slice();
+
+    for (axis, size) in shape.iter_mut().enumerate() {
+        // the shapes are aligned on their last axis, the missing axes count as 1
+        let left_size = (axis + left.len()).checked_sub(ndim).map_or(1, |axis| left[axis]);
+        let right_size = (axis + right.len()).checked_sub(ndim).map_or(1, |axis| right[axis]);
+
+        *size = if left_size == right_size || right_size == 1 {
+            left_size
+        } else if left_size == 1 {
+            right_size
+        } else {
+            return Err(BroadcastError {
+                left: left.into(),
+                right: right.into(),
+            });
+        };
+    }
+
+    Ok(shape)
+}
+
+/// ## Info
+/// a borrowed, strided description of an operand taking part in broadcasting, created by [`Broadcast::broadcast_view`].
+pub struct BroadcastView<'a, T> {
+    pointer: *const T,
+    shape: Box<[usize]>,
+    strides: Box<[usize]>,
+    marker: PhantomData<&'a T>,
+}
+
+impl<T> BroadcastView<'_, T> {
+    #[inline(always)]
+    /// ## Info
+    /// returns the shape the operand has when broadcasting.
+    pub fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+}
+
+/// ## Info
+/// the collections that can be broadcast against each other in the binary operations.
+///
+/// a [`FastArray`] behaves like a row vector, use [`FastArray::column_vector`] to broadcast it along the rows instead.
+pub trait Broadcast<T> {
+    fn broadcast_view(&self) -> BroadcastView<'_, T>;
+}
+
+/// ## Info
+/// a [`FastArray`] seen as a `len x 1` column vector, created by [`FastArray::column_vector`].
+#[derive(Debug)]
+pub struct ColumnVector<'a, T> {
+    array: &'a FastArray<T>,
+}
+
+impl<T> Clone for ColumnVector<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ColumnVector<'_, T> {}
+
+impl<T> FastArray<T> {
+    #[inline(always)]
+    /// ## Info
+    /// borrows the [`FastArray`] as a column vector, so that in binary operations it gets broadcast along the rows instead of the columns.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::{fast_arr, fast_matrix};
+    ///
+    /// let fast_matrix = fast_matrix!([1, 2, 3], [4, 5, 6]);
+    ///
+    /// assert_eq!(&fast_matrix + &fast_arr!(10, 20, 30), fast_matrix!([11, 22, 33], [14, 25, 36]));
+    /// assert_eq!(&fast_matrix + fast_arr!(10, 20).column_vector(), fast_matrix!([11, 12, 13], [24, 25, 26]));
+    /// ```
+    pub fn column_vector(&self) -> ColumnVector<'_, T> {
+        ColumnVector { array: self }
+    }
+}
+
+impl<T> Broadcast<T> for FastArray<T> {
+    fn broadcast_view(&self) -> BroadcastView<'_, T> {
+        BroadcastView {
+            pointer: self.pointer,
+            shape: Box::new([self.size]),
+            strides: Box::new([1]),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Broadcast<T> for ColumnVector<'_, T> {
+    fn broadcast_view(&self) -> BroadcastView<'_, T> {
+        BroadcastView {
+            pointer: self.array.pointer,
+            shape: Box::new([self.array.size, 1]),
+            strides: Box::new([1, 1]),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Broadcast<T> for FastMatrix<T> {
+    fn broadcast_view(&self) -> BroadcastView<'_, T> {
+        let (row_stride, column_stride) = self.strides();
+
+        BroadcastView {
+            pointer: self.pointer,
+            shape: Box::new([self.rows, self.columns]),
+            strides: Box::new([row_stride, column_stride]),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Broadcast<T> for MatrixView<'_, T> {
+    fn broadcast_view(&self) -> BroadcastView<'_, T> {
+        BroadcastView {
+            pointer: self.pointer,
+            shape: Box::new([self.rows, self.columns]),
+            strides: Box::new([self.row_stride, self.column_stride]),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Broadcast<T> for FastTensor<T> {
+    fn broadcast_view(&self) -> BroadcastView<'_, T> {
+        BroadcastView {
+            pointer: self.pointer,
+            shape: self.shape.clone(),
+            strides: self.strides.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T, R: Broadcast<T> + ?Sized> Broadcast<T> for &R {
+    fn broadcast_view(&self) -> BroadcastView<'_, T> {
+        (**self).broadcast_view()
+    }
+}
+
+impl<T> Broadcast<T> for BroadcastView<'_, T> {
+    fn broadcast_view(&self) -> BroadcastView<'_, T> {
+        BroadcastView {
+            pointer: self.pointer,
+            shape: self.shape.clone(),
+            strides: self.strides.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+/// a scalar seen as an operand with no axes, so that it gets broadcast to any shape.
+#[inline(always)]
+fn scalar_view<T>(scalar: &T) -> BroadcastView<'_, T> {
+    BroadcastView {
+        pointer: scalar,
+        shape: Box::new([]),
+        strides: Box::new([]),
+        marker: PhantomData,
+    }
+}
+
+/// applies `func` to every pair of elements of `left` and `right`, after broadcasting them to their common shape.
+fn broadcast_zip<T, U, V, F>(
+    left: &BroadcastView<T>,
+    right: &BroadcastView<U>,
+    max_ndim: usize,
+    mut func: F,
+) -> Result<FastTensor<V>, BroadcastError>
+where
+    F: FnMut(&T, &U) -> V,
+{
+    let shape = broadcast_shapes(&left.shape, &right.shape)?;
+
+    // the result has to fit in the collection it gets returned as
+    if shape.len() > max_ndim {
+        return Err(BroadcastError {
+            left: left.shape.clone(),
+            right: right.shape.clone(),
+        });
+    }
+
+    // both operands are always compatible with the common shape
+    let left_strides = broadcast_strides(&left.shape, &left.strides, &shape).unwrap();
+    let right_strides = broadcast_strides(&right.shape, &right.strides, &shape).unwrap();
+
+    let mut offsets = Offsets::new(&shape, &left_strides).zip(Offsets::new(&shape, &right_strides));
+
+    Ok(FastTensor::new_func(&shape, |_| {
+        let (left_offset, right_offset) = offsets.next().unwrap();
+        unsafe { func(&*left.pointer.add(left_offset), &*right.pointer.add(right_offset)) }
+    }))
+}
+
+/// applies `func` to every element of `target` and the element of `right` it gets paired with, after broadcasting `right` to the shape of `target`.
+///
+/// `target` must come from a mutable borrow.
+unsafe fn broadcast_apply<T, U, F>(
+    target: &BroadcastView<T>,
+    right: &BroadcastView<U>,
+    mut func: F,
+) -> Result<(), BroadcastError>
+where
+    F: FnMut(&mut T, &U),
+{
+    let error = || BroadcastError {
+        left: target.shape.clone(),
+        right: right.shape.clone(),
+    };
+
+    if broadcast_shapes(&target.shape, &right.shape)? != target.shape {
+        return Err(error());
+    }
+
+    let right_strides = broadcast_strides(&right.shape, &right.strides, &target.shape).ok_or_else(error)?;
+
+    let offsets = Offsets::new(&target.shape, &target.strides)
+        .zip(Offsets::new(&target.shape, &right_strides));
+
+    for (target_offset, right_offset) in offsets {
+        unsafe {
+            func(
+                &mut *(target.pointer as *mut T).add(target_offset),
+                &*right.pointer.add(right_offset),
+            )
+        };
+    }
+
+    Ok(())
+}
+
+macro_rules! impl_broadcast_methods {
+    ($collection:ident, $max_ndim:expr, $into:ident) => {
+        impl<T> $collection<T> {
+            /// ## Info
+            /// creates a new collection of the same kind as self, by applying `func` to the elements of self and `rhs` after broadcasting them to their common shape.
+            ///
+            /// the result is always laid out in row-major order.
+            ///
+            /// ## Errors
+            /// if the shapes of self and `rhs` aren't compatible, or if their common shape has more axes than self can hold.
+            pub fn try_zip_broadcast<U, V, F, R>(&self, rhs: &R, func: F) -> Result<$collection<V>, BroadcastError>
+            where
+                R: Broadcast<U> + ?Sized,
+                F: FnMut(&T, &U) -> V,
+            {
+                broadcast_zip(&self.broadcast_view(), &rhs.broadcast_view(), $max_ndim, func)
+                    .map(FastTensor::$into)
+            }
+
+            /// ## Info
+            /// applies `func` to every element of self and the element of `rhs` it gets paired with, after broadcasting `rhs` to the shape of self.
+            ///
+            /// ## Errors
+            /// if `rhs` can't be broadcast to the shape of self; in that case self is left untouched.
+            pub fn try_apply_broadcast<U, F, R>(&mut self, rhs: &R, func: F) -> Result<(), BroadcastError>
+            where
+                R: Broadcast<U> + ?Sized,
+                F: FnMut(&mut T, &U),
+            {
+                unsafe { broadcast_apply(&self.broadcast_view(), &rhs.broadcast_view(), func) }
+            }
+        }
+
+        impl_broadcast_methods!(@try $collection, Add, try_add, add);
+        impl_broadcast_methods!(@try $collection, Sub, try_sub, sub);
+        impl_broadcast_methods!(@try $collection, Mul, try_mul, mul);
+        impl_broadcast_methods!(@try $collection, Div, try_div, div);
+    };
+
+    (@try $collection:ident, $trait:ident, $try_method:ident, $method:ident) => {
+        impl<T: Clone + $trait<Output = T>> $collection<T> {
+            /// ## Info
+            #[doc = concat!("applies `", stringify!($trait), "` element-wise between self and `rhs`, after broadcasting them to their common shape.")]
+            ///
+            /// ## Errors
+            /// if the shapes of self and `rhs` aren't compatible.
+            pub fn $try_method<R: Broadcast<T> + ?Sized>(&self, rhs: &R) -> Result<$collection<T>, BroadcastError> {
+                self.try_zip_broadcast(rhs, |a, b| a.clone().$method(b.clone()))
+            }
+        }
+    };
+}
+
+impl_broadcast_methods!(FastArray, 1, into_fast_array);
+impl_broadcast_methods!(FastMatrix, 2, into_fast_matrix);
+impl_broadcast_methods!(FastTensor, usize::MAX, into_contiguous);
+
+macro_rules! impl_broadcast_ops {
+    ($collection:ident; $($rhs:ty),+) => {
+        impl_broadcast_ops!(@ops $collection; $($rhs),+; Add, add, AddAssign, add_assign);
+        impl_broadcast_ops!(@ops $collection; $($rhs),+; Sub, sub, SubAssign, sub_assign);
+        impl_broadcast_ops!(@ops $collection; $($rhs),+; Mul, mul, MulAssign, mul_assign);
+        impl_broadcast_ops!(@ops $collection; $($rhs),+; Div, div, DivAssign, div_assign);
+    };
+
+    (@ops $collection:ident; $($rhs:ty),+; $trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident) => {
+        $(
+            impl<'b, T: Clone + $trait<Output = T>> $trait<$rhs> for &$collection<T> {
+                type Output = $collection<T>;
+
+                /// ## Panics
+                /// if the shapes of the two operands can't be broadcast together.
+                fn $method(self, rhs: $rhs) -> Self::Output {
+                    self.try_zip_broadcast(&rhs, |a, b| a.clone().$method(b.clone()))
+                        .unwrap_or_else(|error| panic!("{}: {error}!", stringify!($collection)))
+                }
+            }
+
+            impl<'b, T: Clone + $assign_trait> $assign_trait<$rhs> for $collection<T> {
+                /// ## Panics
+                /// if the right-hand side can't be broadcast to the shape of self.
+                fn $assign_method(&mut self, rhs: $rhs) {
+                    self.try_apply_broadcast(&rhs, |a, b| a.$assign_method(b.clone()))
+                        .unwrap_or_else(|error| panic!("{}: {error}!", stringify!($collection)))
+                }
+            }
+        )+
+
+        impl<T: Clone + $trait<Output = T>> $trait<T> for &$collection<T> {
+            type Output = $collection<T>;
+
+            fn $method(self, rhs: T) -> Self::Output {
+                // a scalar can be broadcast to any shape
+                self.try_zip_broadcast(&scalar_view(&rhs), |a, b| a.clone().$method(b.clone()))
+                    .unwrap()
+            }
+        }
+
+        impl<T: Clone + $assign_trait> $assign_trait<T> for $collection<T> {
+            fn $assign_method(&mut self, rhs: T) {
+                self.try_apply_broadcast(&scalar_view(&rhs), |a, b| a.$assign_method(b.clone()))
+                    .unwrap()
+            }
+        }
+    };
+}
+
+impl_broadcast_ops!(FastArray; &'b FastArray<T>);
+impl_broadcast_ops!(FastMatrix; &'b FastMatrix<T>, &'b FastArray<T>, ColumnVector<'b, T>, MatrixView<'b, T>);
+impl_broadcast_ops!(FastTensor; &'b FastTensor<T>, &'b FastMatrix<T>, &'b FastArray<T>, ColumnVector<'b, T>, MatrixView<'b, T>);
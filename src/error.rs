@@ -0,0 +1,158 @@
+use std::{alloc::Layout, error::Error, fmt::Display, io};
+
+use crate::{
+    fast_matrix::{fast_matrix_csv::CsvError, fast_matrix_mtx::MtxError},
+    npy::npy::NpyError,
+};
+
+/// ## Info
+/// everything that can go wrong in the `try_` methods, which return it instead of panicking like their counterparts.
+///
+/// the errors of the file formats ([`CsvError`], [`MtxError`] and [`NpyError`]) convert into it, so `?` works on all of them.
+///
+/// ## Example
+/// ```
+/// use fast_collections::{fast_arr, fast_matrix::fast_matrix_csv::CsvOptions, FastArray, FastError, FastMatrix};
+///
+/// assert!(matches!(FastArray::try_new(0, 1), Err(FastError::ZeroLength)));
+///
+/// let mut fast_arr = FastArray::try_new(3, 1).unwrap();
+/// assert!(matches!(fast_arr.try_swap(0, 5), Err(FastError::OutOfBounds { index: 5, len: 3 })));
+///
+/// fn second_row(csv: &str) -> Result<FastArray<i32>, FastError> {
+///     let fast_matrix = FastMatrix::from_csv(csv.as_bytes(), &CsvOptions::default())?;
+///     fast_matrix.try_get_row(1)
+/// }
+///
+/// assert_eq!(second_row("1,2\n3,4\n").unwrap(), fast_arr!(3, 4));
+/// assert!(matches!(second_row("1,2\n"), Err(FastError::OutOfBounds { index: 1, len: 1 })));
+/// assert!(matches!(second_row("1,x\n"), Err(FastError::Parse(_))));
+/// ```
+#[derive(Debug)]
+pub enum FastError {
+    /// the memory couldn't be allocated, or its size overflows.
+    Alloc,
+    /// a collection was asked to have no elements, which isn't allowed.
+    ZeroLength,
+    /// `index` is out of the bounds of a dimension of size `len`.
+    OutOfBounds { index: usize, len: usize },
+    /// a `(rows, columns)` shape isn't the one that's needed.
+    ShapeMismatch { expected: (usize, usize), found: (usize, usize) },
+    /// `row` doesn't have as many elements as the first one.
+    RaggedRows { row: usize },
+    /// a tensor of shape `from` can't be reshaped into `to`, which doesn't hold as many elements.
+    ReshapeMismatch { from: Box<[usize]>, to: Box<[usize]> },
+    /// `axes` isn't a permutation of the `ndim` axes of a tensor.
+    InvalidAxes { axes: Box<[usize]>, ndim: usize },
+    /// reading or writing failed.
+    Io(io::Error),
+    /// a file couldn't be parsed, with the description of what's wrong with it.
+    Parse(String),
+}
+
+impl Display for FastError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FastError::Alloc => write!(f, "memory allocation failed"),
+            FastError::ZeroLength => write!(f, "the length cannot be 0"),
+            FastError::OutOfBounds { index, len } => write!(f, "index {index} is out of bounds for length {len}"),
+            FastError::ShapeMismatch { expected, found } => {
+                write!(f, "expected a {}x{} shape, found {}x{}", expected.0, expected.1, found.0, found.1)
+            }
+            FastError::RaggedRows { row } => write!(f, "row {row} doesn't have the same length as the first one"),
+            FastError::ReshapeMismatch { from, to } => write!(f, "cannot reshape {from:?} into {to:?}"),
+            FastError::InvalidAxes { axes, ndim } => write!(f, "{axes:?} isn't a permutation of the {ndim} axes"),
+            FastError::Io(error) => write!(f, "{error}"),
+            FastError::Parse(message) => write!(f, "couldn't parse: {message}"),
+        }
+    }
+}
+
+impl Error for FastError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            FastError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for FastError {
+    fn from(value: io::Error) -> Self {
+        FastError::Io(value)
+    }
+}
+
+impl From<CsvError> for FastError {
+    fn from(value: CsvError) -> Self {
+        match value {
+            CsvError::Io(error) => FastError::Io(error),
+            CsvError::Empty => FastError::ZeroLength,
+            error => FastError::Parse(error.to_string()),
+        }
+    }
+}
+
+impl From<MtxError> for FastError {
+    fn from(value: MtxError) -> Self {
+        match value {
+            MtxError::Io(error) => FastError::Io(error),
+            MtxError::Empty => FastError::ZeroLength,
+            error => FastError::Parse(error.to_string()),
+        }
+    }
+}
+
+impl From<NpyError> for FastError {
+    fn from(value: NpyError) -> Self {
+        match value {
+            NpyError::Io(error) => FastError::Io(error),
+            error => FastError::Parse(error.to_string()),
+        }
+    }
+}
+
+impl From<AllocError> for FastError {
+    fn from(value: AllocError) -> Self {
+        match value {
+            AllocError::ZeroLength => FastError::ZeroLength,
+            AllocError::CapacityOverflow | AllocError::AllocFailed { .. } => FastError::Alloc,
+        }
+    }
+}
+
+/// ## Info
+/// why the memory of a collection couldn't be allocated, returned by the `try_with_len` constructors.
+///
+/// it's the equivalent of [`std::collections::TryReserveError`], so the infallible constructors panic on [`AllocError::CapacityOverflow`]
+/// and call [`std::alloc::handle_alloc_error`] on [`AllocError::AllocFailed`], just like [`Vec`] does.
+///
+/// ## Example
+/// ```
+/// use fast_collections::{error::AllocError, FastArray, FastMatrix};
+///
+/// assert_eq!(FastArray::<u64>::try_with_len(usize::MAX).unwrap_err(), AllocError::CapacityOverflow);
+/// assert_eq!(FastMatrix::<u8>::try_with_len(usize::MAX, 2).unwrap_err(), AllocError::CapacityOverflow);
+/// assert_eq!(FastArray::<u8>::try_with_len(0).unwrap_err(), AllocError::ZeroLength);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AllocError {
+    /// a collection was asked to have no elements, which isn't allowed.
+    ZeroLength,
+    /// the size of the memory in bytes doesn't fit in an `isize`.
+    CapacityOverflow,
+    /// the allocator couldn't give back memory for `layout`.
+    AllocFailed { layout: Layout },
+}
+
+impl Display for AllocError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AllocError::ZeroLength => write!(f, "the length cannot be 0"),
+            AllocError::CapacityOverflow => write!(f, "capacity overflow"),
+            AllocError::AllocFailed { layout } => write!(f, "memory allocation of {} bytes failed", layout.size()),
+        }
+    }
+}
+
+impl Error for AllocError {}
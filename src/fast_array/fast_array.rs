@@ -1,12 +1,14 @@
-use std::alloc::{alloc, dealloc, Layout};
 use std::fmt::Display;
 use std::fs::File;
 use std::io::{BufReader, Read};
 use std::ops::{Index, IndexMut};
-use std::os::unix::fs::MetadataExt;
+use std::path::Path;
 use std::ptr;
 // use crate::create_unchecked_doc;
+use crate::alloc;
+use crate::error::{AllocError, FastError};
 use crate::fast_iterator::fast_iterator::FastIterator;
+use crate::pod::Endian;
 
 /// ## Info
 /// this is the core struct of the library.
@@ -14,12 +16,12 @@ use crate::fast_iterator::fast_iterator::FastIterator;
 ///
 /// ## Example
 /// ```
-/// use fast_array::{fast_arr, FastArray};
+/// use fast_collections::{fast_arr, FastArray};
 ///
 /// let array = fast_arr![1, 2, 3];
 /// assert_eq!(array.to_string(), "[1, 2, 3]")
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 #[repr(align(32))]
 // #[repr(align(64))]
 pub struct FastArray<T> {
@@ -33,7 +35,7 @@ impl<T: Default> FastArray<T> {
     ///
     /// ## Example
     /// ```
-    /// use fast_array::FastArray;
+    /// use fast_collections::FastArray;
     ///
     /// let fast_array = FastArray::<usize>::new_default(2);
     ///
@@ -46,15 +48,7 @@ impl<T: Default> FastArray<T> {
     pub fn new_default(len: usize) -> FastArray<T> {
         assert!(len != 0, "len cannot be 0!");
 
-        // let layout = Layout::array::<T>(len).expect("failed to create layout");
-        let layout = Layout::from_size_align(len * std::mem::size_of::<T>(), 32)
-            .expect("failed to create layout");
-
-        let raw_ptr = unsafe { alloc(layout) as *mut T };
-
-        if raw_ptr.is_null() {
-            panic!("Memory alloc failed.")
-        };
+        let raw_ptr = alloc::allocate::<T>(len);
 
         unsafe {
             for i in 0..len {
@@ -73,17 +67,9 @@ impl<T: Default> FastArray<T> {
     /// same as [`FastArray::new_default`], just doesn't check for `len != 0` for performance reasons.
     /// if len == 0, using the method is undefined behavior.
     pub unsafe fn new_default_unchecked(len: usize) -> FastArray<T> {
-        // assert!(len != 0);
-
-        // let layout = Layout::array::<T>(len).expect("failed to create layout");
-        let layout = Layout::from_size_align(len * std::mem::size_of::<T>(), 32)
-            .expect("failed to create layout");
-
-        let raw_ptr = unsafe { alloc(layout) as *mut T };
+        debug_assert!(len != 0, "FastArray::new_default_unchecked: len cannot be 0!");
 
-        if raw_ptr.is_null() {
-            panic!("Memory alloc failed.")
-        };
+        let raw_ptr = alloc::allocate::<T>(len);
 
         unsafe {
             for i in 0..len {
@@ -96,6 +82,55 @@ impl<T: Default> FastArray<T> {
             size: len,
         }
     }
+
+    /// ## Info
+    /// does the same thing as [`FastArray::new_default`], but returns an error instead of panicking.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::{fast_arr, FastArray};
+    ///
+    /// assert_eq!(FastArray::<u8>::try_new_default(2).unwrap(), fast_arr!(0, 0));
+    /// assert!(FastArray::<u8>::try_new_default(0).is_err());
+    /// ```
+    ///
+    /// ## Errors
+    /// - [`FastError::ZeroLength`] if `len == 0`.
+    /// - [`FastError::Alloc`] if the memory couldn't be allocated.
+    pub fn try_new_default(len: usize) -> Result<FastArray<T>, FastError> {
+        FastArray::try_new_func(len, |_| T::default())
+    }
+
+    /// ## Info
+    /// creates a new [`FastArray`] of the given len filled with the [`Default`] value of the type T, like [`FastArray::new_default`],
+    /// but tells why the allocation failed instead of panicking or aborting, like [`Vec::try_reserve`] does.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::{error::AllocError, fast_arr, FastArray};
+    ///
+    /// assert_eq!(FastArray::<u8>::try_with_len(3).unwrap(), fast_arr!(0, 0, 0));
+    /// assert_eq!(FastArray::<u32>::try_with_len(usize::MAX / 2).unwrap_err(), AllocError::CapacityOverflow);
+    /// ```
+    ///
+    /// ## Errors
+    /// - [`AllocError::ZeroLength`] if `len == 0`.
+    /// - [`AllocError::CapacityOverflow`] if the size of the memory in bytes overflows.
+    /// - [`AllocError::AllocFailed`] if the allocator couldn't give back the memory.
+    pub fn try_with_len(len: usize) -> Result<FastArray<T>, AllocError> {
+        let raw_ptr = alloc::try_allocate::<T>(len)?;
+
+        unsafe {
+            for i in 0..len {
+                raw_ptr.add(i).write(T::default());
+            }
+        };
+
+        Ok(FastArray {
+            pointer: raw_ptr,
+            size: len,
+        })
+    }
 }
 
 impl<T: Clone> FastArray<T> {
@@ -104,7 +139,7 @@ impl<T: Clone> FastArray<T> {
     ///
     /// ## Example
     /// ```
-    /// use fast_array::FastArray;
+    /// use fast_collections::FastArray;
     ///
     /// let fast_arr = FastArray::new(3, 5);
     ///
@@ -117,15 +152,7 @@ impl<T: Clone> FastArray<T> {
     pub fn new(len: usize, fill_value: T) -> FastArray<T> {
         assert!(len != 0);
 
-        // let layout = Layout::array::<T>(len).expect("failed to create layout");
-        let layout = Layout::from_size_align(len * std::mem::size_of::<T>(), 32)
-            .expect("Failed to create layout");
-
-        let raw_ptr = unsafe { alloc(layout) as *mut T };
-
-        if raw_ptr.is_null() {
-            panic!("Memory alloc failed.")
-        };
+        let raw_ptr = alloc::allocate::<T>(len);
 
         unsafe {
             for i in 0..len {
@@ -144,17 +171,9 @@ impl<T: Clone> FastArray<T> {
     /// same functionality as [`FastArray::new`], just skips the `len != 0` check for performance reasons.
     /// if `len == 0`, using this method is undefined behavior.
     pub unsafe fn new_unchecked(len: usize, fill_value: T) -> FastArray<T> {
-        // assert!(len != 0);
-
-        // let layout = Layout::array::<T>(len).expect("failed to create layout");
-        let layout = Layout::from_size_align(len * std::mem::size_of::<T>(), 32)
-            .expect("Failed to create layout");
+        debug_assert!(len != 0, "FastArray::new_unchecked: len cannot be 0!");
 
-        let raw_ptr = unsafe { alloc(layout) as *mut T };
-
-        if raw_ptr.is_null() {
-            panic!("Memory alloc failed.")
-        };
+        let raw_ptr = alloc::allocate::<T>(len);
 
         unsafe {
             for i in 0..len {
@@ -167,6 +186,24 @@ impl<T: Clone> FastArray<T> {
             size: len,
         }
     }
+
+    /// ## Info
+    /// does the same thing as [`FastArray::new`], but returns an error instead of panicking.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::{fast_arr, FastArray, FastError};
+    ///
+    /// assert_eq!(FastArray::try_new(3, 5).unwrap(), fast_arr!(5, 5, 5));
+    /// assert!(matches!(FastArray::try_new(0, 5), Err(FastError::ZeroLength)));
+    /// ```
+    ///
+    /// ## Errors
+    /// - [`FastError::ZeroLength`] if `len == 0`.
+    /// - [`FastError::Alloc`] if the memory couldn't be allocated.
+    pub fn try_new(len: usize, fill_value: T) -> Result<FastArray<T>, FastError> {
+        FastArray::try_new_func(len, |_| fill_value.clone())
+    }
 }
 
 impl<T> FastArray<T> {
@@ -175,7 +212,7 @@ impl<T> FastArray<T> {
     ///
     /// ## Example
     /// ```
-    /// use fast_array::fast_arr;
+    /// use fast_collections::fast_arr;
     ///
     /// let fast_arr = fast_arr!(1,2,3,4,5);
     ///
@@ -191,7 +228,7 @@ impl<T> FastArray<T> {
     ///
     /// ## Example
     /// ```
-    /// use fast_array::FastArray;
+    /// use fast_collections::FastArray;
     /// let fast_arr = FastArray::new(5, 3);
     ///
     /// let iter = fast_arr.iter();
@@ -211,7 +248,7 @@ impl<T> FastArray<T> {
     /// ## Info
     /// creates a new [`FastIterator`] that holds mutable references to the elements of the [`FastArray`].
     /// ```
-    /// use fast_array::FastArray;
+    /// use fast_collections::FastArray;
     ///
     /// let mut fast_arr = FastArray::new(5, 3);
     ///
@@ -235,8 +272,8 @@ impl<T> FastArray<T> {
     ///
     /// ## Example
     /// ```
-    /// use fast_array::FastArray;
-    /// use fast_array::fast_arr;
+    /// use fast_collections::FastArray;
+    /// use fast_collections::fast_arr;
     ///
     /// let func = |index| {
     ///     index+1
@@ -254,18 +291,39 @@ impl<T> FastArray<T> {
     {
         assert!(len != 0);
 
-        // let layout = Layout::array::<T>(len).expect("failed to create layout");
-        let layout = Layout::from_size_align(len * std::mem::size_of::<T>(), 32)
-            .expect("Failed to create layout");
+        let raw_ptr = alloc::allocate::<T>(len);
 
-        // let layout = Layout::from_size_align(len * std::mem::size_of::<T>(), 64)
-        //     .expect("Failed to create layout");
+        unsafe {
+            for i in 0..len {
+                raw_ptr.add(i).write(func(i));
+            }
+        };
 
-        let raw_ptr = unsafe { alloc(layout) as *mut T };
+        FastArray {
+            pointer: raw_ptr,
+            size: len,
+        }
+    }
 
-        if raw_ptr.is_null() {
-            panic!("Memory alloc failed.")
-        };
+    /// ## Info
+    /// does the same thing as [`FastArray::new_func`], but returns an error instead of panicking.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::{fast_arr, FastArray, FastError};
+    ///
+    /// assert_eq!(FastArray::try_new_func(3, |index| index * 2).unwrap(), fast_arr!(0, 2, 4));
+    /// assert!(matches!(FastArray::try_new_func(usize::MAX, |index| index), Err(FastError::Alloc)));
+    /// ```
+    ///
+    /// ## Errors
+    /// - [`FastError::ZeroLength`] if `len == 0`.
+    /// - [`FastError::Alloc`] if the memory couldn't be allocated.
+    pub fn try_new_func<F>(len: usize, mut func: F) -> Result<FastArray<T>, FastError>
+    where
+        F: FnMut(usize) -> T,
+    {
+        let raw_ptr = alloc::try_allocate::<T>(len)?;
 
         unsafe {
             for i in 0..len {
@@ -273,10 +331,10 @@ impl<T> FastArray<T> {
             }
         };
 
-        FastArray {
+        Ok(FastArray {
             pointer: raw_ptr,
             size: len,
-        }
+        })
     }
 
     /// ## Info
@@ -286,17 +344,9 @@ impl<T> FastArray<T> {
     where
         F: FnMut(usize) -> T,
     {
-        // assert!(len != 0);
-
-        // let layout = Layout::array::<T>(len).expect("failed to create layout");
-        let layout = Layout::from_size_align(len * std::mem::size_of::<T>(), 32)
-            .expect("Failed to create layout");
+        debug_assert!(len != 0, "FastArray::new_func_unchecked: len cannot be 0!");
 
-        let raw_ptr = unsafe { alloc(layout) as *mut T };
-
-        if raw_ptr.is_null() {
-            panic!("Memory alloc failed.")
-        };
+        let raw_ptr = alloc::allocate::<T>(len);
 
         unsafe {
             for i in 0..len {
@@ -319,13 +369,7 @@ impl<T> FastArray<T> {
     pub unsafe fn new_empty(len: usize) -> FastArray<T> {
         assert!(len != 0);
 
-        let layout = Layout::array::<T>(len).expect("failed to create layout");
-
-        let raw_ptr = unsafe { alloc(layout) as *mut T };
-
-        if raw_ptr.is_null() {
-            panic!("Memory alloc failed.")
-        };
+        let raw_ptr = alloc::allocate::<T>(len);
 
         FastArray {
             pointer: raw_ptr,
@@ -338,15 +382,9 @@ impl<T> FastArray<T> {
     ///
     /// If `len == 0`, using this method becomes undefined behavior
     pub unsafe fn new_empty_unchecked(len: usize) -> FastArray<T> {
-        // assert!(len != 0);
+        debug_assert!(len != 0, "FastArray::new_empty_unchecked: len cannot be 0!");
 
-        let layout = Layout::array::<T>(len).expect("failed to create layout");
-
-        let raw_ptr = unsafe { alloc(layout) as *mut T };
-
-        if raw_ptr.is_null() {
-            panic!("Memory alloc failed.")
-        };
+        let raw_ptr = alloc::allocate::<T>(len);
 
         FastArray {
             pointer: raw_ptr,
@@ -363,7 +401,7 @@ impl<T> FastArray<T> {
     ///
     /// ## Example
     /// ```
-    /// use fast_array::fast_arr;
+    /// use fast_collections::fast_arr;
     ///
     /// let mut fast_arr = fast_arr!(1,2,3,4,5);
     /// fast_arr.swap(1, 4);
@@ -384,12 +422,40 @@ impl<T> FastArray<T> {
         }
     }
 
+    /// ## Info
+    /// does the same thing as [`FastArray::swap`], but returns an error instead of panicking.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::{fast_arr, FastError};
+    ///
+    /// let mut fast_arr = fast_arr!(1, 2, 3);
+    /// fast_arr.try_swap(0, 2).unwrap();
+    ///
+    /// assert_eq!(fast_arr, fast_arr!(3, 2, 1));
+    /// assert!(matches!(fast_arr.try_swap(1, 3), Err(FastError::OutOfBounds { index: 3, len: 3 })));
+    /// ```
+    ///
+    /// ## Errors
+    /// [`FastError::OutOfBounds`] with the first index that's out of bounds.
+    pub fn try_swap(&mut self, index1: usize, index2: usize) -> Result<(), FastError> {
+        for index in [index1, index2] {
+            if index >= self.size {
+                return Err(FastError::OutOfBounds { index, len: self.size });
+            }
+        }
+
+        unsafe { self.swap_unchecked(index1, index2) };
+        Ok(())
+    }
+
     /// ## Info
     /// this method has the same functionality as [`FastArray::swap`], but skips the `self.len() > index1 && self.len() > index2` check for performance reasons.
     ///
     /// If `self.len() > index1 && self.len() > index2` isn't respected, using this method becomes undefined behavior
     pub unsafe fn swap_unchecked(&mut self, index1: usize, index2: usize) {
-        // assert!(self.size>index1 && self.size>index2);
+        self.debug_check_index("swap_unchecked", index1);
+        self.debug_check_index("swap_unchecked", index2);
 
         let pointer1 = self.get_mut_pointer_unchecked(index1);
         let pointer2 = self.get_mut_pointer_unchecked(index2);
@@ -415,7 +481,7 @@ impl<T> FastArray<T> {
     ///
     /// ## Example
     /// ```
-    /// use fast_array::fast_arr;
+    /// use fast_collections::fast_arr;
     ///
     /// let fast_arr = fast_arr!(1,2,3,4,5);
     /// unsafe {
@@ -443,7 +509,8 @@ impl<T> FastArray<T> {
     ///
     /// If `self.len() > index1 && self.len() > index2` isn't respected, using this method becomes undefined behavior
     pub unsafe fn swap_unsafe_unchecked(&self, index1: usize, index2: usize) {
-        // assert!(self.size>index1 && self.size>index2);
+        self.debug_check_index("swap_unsafe_unchecked", index1);
+        self.debug_check_index("swap_unsafe_unchecked", index2);
 
         let pointer1 = self.get_mut_pointer_unsafe_unchecked(index1);
         let pointer2 = self.get_mut_pointer_unsafe_unchecked(index2);
@@ -453,11 +520,97 @@ impl<T> FastArray<T> {
         }
     }
 
+    /// ## Info
+    /// returns a raw pointer to the first element of the [`FastArray`].
+    ///
+    /// the pointer is valid for reads of `self.len()` elements for as long as self is alive and isn't borrowed mutably,
+    /// and it must never be written through: use [`FastArray::as_mut_ptr`] for that.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::FastArray;
+    ///
+    /// let fast_arr = FastArray::from(vec![1, 2, 3]);
+    /// let pointer = fast_arr.as_ptr();
+    ///
+    /// assert_eq!(unsafe { *pointer.add(2) }, 3);
+    /// ```
+    #[inline(always)]
+    pub fn as_ptr(&self) -> *const T {
+        self.pointer
+    }
+
+    /// ## Info
+    /// returns a raw mutable pointer to the first element of the [`FastArray`].
+    ///
+    /// the pointer is valid for reads and writes of `self.len()` elements for as long as self is alive and isn't used in any other way.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::FastArray;
+    ///
+    /// let mut fast_arr = FastArray::from(vec![1, 2, 3]);
+    /// let pointer = fast_arr.as_mut_ptr();
+    ///
+    /// unsafe { *pointer.add(1) = 5 };
+    /// assert_eq!(fast_arr[1], 5);
+    /// ```
+    #[inline(always)]
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.pointer
+    }
+
+    /// ## Info
+    /// returns a reference to the element at `index`, without checking that it's in bounds.
+    ///
+    /// ## Unsafe
+    /// if `index >= self.len()`, using this method is undefined behavior, even if the reference isn't used.
+    /// builds with `debug_assertions` on panic instead.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::FastArray;
+    ///
+    /// let fast_arr = FastArray::from(vec![1, 2, 3]);
+    ///
+    /// assert_eq!(unsafe { fast_arr.get_unchecked(1) }, &2);
+    /// ```
+    #[inline(always)]
+    pub unsafe fn get_unchecked(&self, index: usize) -> &T {
+        self.debug_check_index("get_unchecked", index);
+
+        unsafe { &*self.pointer.add(index) }
+    }
+
+    /// ## Info
+    /// returns a mutable reference to the element at `index`, without checking that it's in bounds.
+    ///
+    /// ## Unsafe
+    /// if `index >= self.len()`, using this method is undefined behavior, even if the reference isn't used.
+    /// builds with `debug_assertions` on panic instead.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::FastArray;
+    ///
+    /// let mut fast_arr = FastArray::from(vec![1, 2, 3]);
+    /// unsafe { *fast_arr.get_unchecked_mut(0) = 7 };
+    ///
+    /// assert_eq!(fast_arr[0], 7);
+    /// ```
+    #[inline(always)]
+    pub unsafe fn get_unchecked_mut(&mut self, index: usize) -> &mut T {
+        self.debug_check_index("get_unchecked_mut", index);
+
+        unsafe { &mut *self.pointer.add(index) }
+    }
+
     /// ## Info
     /// returns the raw pointer to the specified index.
     ///
     /// ## Warning
     /// this method is by itself safe, but it's up to the user to use the pointer correctly and safely.
+    /// like the one of [`FastArray::as_ptr`], the pointer must never be written through.
     ///
     /// ## Panics
     /// if index is out of bounds of the array.
@@ -471,9 +624,11 @@ impl<T> FastArray<T> {
     /// ## Info
     /// this method has the same functionality as [`FastArray::get_pointer`], but skips the `self.len() > index` check for performance reasons.
     ///
-    /// If `self.len() > index` isn't respected, using this method becomes undefined behavior
-    pub fn get_pointer_unchecked(&self, index: usize) -> *const T {
-        // assert!(self.size>index);
+    /// ## Unsafe
+    /// if `index >= self.len()`, using this method is undefined behavior, even if the pointer isn't used.
+    /// builds with `debug_assertions` on panic instead.
+    pub unsafe fn get_pointer_unchecked(&self, index: usize) -> *const T {
+        self.debug_check_index("get_pointer_unchecked", index);
 
         let pointer = unsafe { self.pointer.add(index) };
         pointer
@@ -500,9 +655,13 @@ impl<T> FastArray<T> {
     /// ## Info
     /// this method has the same functionality as [`FastArray::get_mut_pointer`], but skips the `self.len() > index` check for performance reasons.
     ///
-    /// If `self.len() > index` isn't respected, using this method becomes undefined behavior
-    pub fn get_mut_pointer_unchecked(&self, index: usize) -> *mut T {
-        // assert!(self.size>index);
+    /// use [`FastArray::get_mut_pointer_unsafe_unchecked`] to skip the mutable borrow as well.
+    ///
+    /// ## Unsafe
+    /// if `index >= self.len()`, using this method is undefined behavior, even if the pointer isn't used.
+    /// builds with `debug_assertions` on panic instead.
+    pub unsafe fn get_mut_pointer_unchecked(&mut self, index: usize) -> *mut T {
+        self.debug_check_index("get_mut_pointer_unchecked", index);
 
         let pointer = unsafe { self.pointer.add(index) };
         pointer
@@ -532,34 +691,45 @@ impl<T> FastArray<T> {
     ///
     /// If `self.len() <= index`, using this method becomes undefined behavior
     pub unsafe fn get_mut_pointer_unsafe_unchecked(&self, index: usize) -> *mut T {
-        // assert!(self.size>index);
+        self.debug_check_index("get_mut_pointer_unsafe_unchecked", index);
 
         let pointer = unsafe { self.pointer.add(index) };
         pointer
     }
+
+    /// panics if `index` is out of bounds, but only in builds with `debug_assertions` on,
+    /// so misusing an `_unchecked` method gets caught by tests while release builds skip the check.
+    #[inline(always)]
+    #[track_caller]
+    fn debug_check_index(&self, method: &str, index: usize) {
+        debug_assert!(
+            self.size > index,
+            "FastArray::{method}: index {index} is out of bounds for len {}",
+            self.size
+        );
+    }
 }
 
 impl FastArray<u8> {
-    #[inline(always)]
     /// ## Info
-    /// reads a file into the [`FastArray`] as bytes.
-    pub fn read_file(&mut self, path: impl AsRef<str>) -> std::io::Result<()> {
-        let file = File::open(path.as_ref())?;
+    /// fills the [`FastArray`] with the first bytes of the file at the given path.
+    ///
+    /// ## Errors
+    /// if the file can't be opened or read, or is shorter than the [`FastArray`] ([`std::io::ErrorKind::UnexpectedEof`]).
+    pub fn read_file(&mut self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        let file = File::open(path)?;
 
         let mut buf_read = BufReader::new(file);
-        buf_read.read(self.as_mut()).unwrap();
-        Ok(())
+        buf_read.read_exact(self.as_mut())
     }
 
     /// ## Info
     /// creates a new [`FastArray`] containing the bytes of the file at the given path.
-    pub fn new_read_file(path: impl AsRef<str>) -> std::io::Result<FastArray<u8>> {
-        let file = File::open(path.as_ref())?;
-        let size = file.metadata()?.size() as usize;
-        let mut reader = BufReader::new(file);
-        let mut fast_arr = unsafe { FastArray::new_empty(size) };
-        reader.read(fast_arr.as_mut())?;
-        Ok(fast_arr)
+    ///
+    /// ## Errors
+    /// if the file can't be opened or read, or is empty.
+    pub fn new_read_file(path: impl AsRef<Path>) -> std::io::Result<FastArray<u8>> {
+        FastArray::read_exact_file(path, Endian::Native)
     }
 }
 
@@ -600,6 +770,12 @@ impl<T: Display> Display for FastArray<T> {
     }
 }
 
+impl<T: Clone> Clone for FastArray<T> {
+    fn clone(&self) -> Self {
+        FastArray::new_func(self.size, |index| self[index].clone())
+    }
+}
+
 impl<T> Drop for FastArray<T> {
     fn drop(&mut self) {
         if !self.pointer.is_null() {
@@ -607,10 +783,7 @@ impl<T> Drop for FastArray<T> {
                 unsafe { ptr::drop_in_place(self.pointer.add(i)) };
             }
 
-            // let layout = Layout::array::<T>(self.size).expect("Failed to create layout");
-            let layout = Layout::from_size_align(self.size * std::mem::size_of::<T>(), 32)
-                .expect("failed to create layout");
-            unsafe { dealloc(self.pointer as *mut u8, layout) };
+            unsafe { alloc::deallocate(self.pointer, self.size) };
         }
     }
 }
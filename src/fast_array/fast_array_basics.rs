@@ -1,5 +1,8 @@
 use std::cmp::Ordering;
+use std::ops::{Bound, Range, RangeBounds};
+use std::ptr;
 
+use crate::{alloc, FastError};
 use crate::{fast_array::fast_array::FastArray, fast_iterator::fast_iterator::FastIterator};
 // use serde::{de::Visitor, ser::SerializeSeq, Deserialize, Serialize};
 
@@ -10,7 +13,7 @@ impl<T> FastArray<T> {
     /// 
     /// ## Example
     /// ```
-    /// use fast_array::fast_arr;
+    /// use fast_collections::fast_arr;
     ///
     /// let array = fast_arr!(1,2,3,4,5);
     /// 
@@ -32,7 +35,7 @@ impl<T> FastArray<T> {
     /// 
     /// ## Example 
     /// ```
-    /// use fast_array::fast_arr;
+    /// use fast_collections::fast_arr;
     ///
     /// let array = fast_arr!(1,2,3,4,5);
     /// 
@@ -42,21 +45,201 @@ impl<T> FastArray<T> {
         let pointer = self.pointer;
         self.pointer = std::ptr::null_mut(); // Invalidate the pointer
         
-        FastIterator {
-            pointer,
-            len: self.size,
-            current_index: (0, 0),
+        unsafe { FastIterator::from_raw_parts(pointer, self.size) }
+    }
+
+    /// ## Info
+    /// moves the elements in `range` out of self into a [`FastIterator`], without cloning them.
+    /// every element that's moved out gets replaced by the return value of `func`, called with its index, so self stays fully initialized.
+    ///
+    /// an empty range gives back an empty [`FastIterator`], which can't be turned into a [`FastArray`] (see [`FastIterator::try_into_fast_array`]).
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::FastArray;
+    ///
+    /// let mut fast_arr: FastArray<Option<String>> = FastArray::new_func(4, |index| Some(index.to_string()));
+    ///
+    /// let drained: Vec<_> = fast_arr.drain_func(1..3, |_| None).flatten().collect();
+    /// assert_eq!(drained, ["1", "2"]);
+    /// assert_eq!(fast_arr, FastArray::from(vec![Some("0".to_string()), None, None, Some("3".to_string())]));
+    /// ```
+    ///
+    /// ## Panics
+    /// if `range` is out of bounds, or if its start is greater than its end.
+    pub fn drain_func<F>(&mut self, range: impl RangeBounds<usize>, mut func: F) -> FastIterator<T>
+    where
+        F: FnMut(usize) -> T,
+    {
+        let range = resolve_range("drain_func", range, self.size);
+        let len = range.len();
+
+        if len == 0 {
+            return FastIterator::empty();
+        }
+
+        let mut drained = unsafe { FastIterator::from_raw_parts(alloc::allocate::<T>(len), len) };
+
+        // the iterator only owns the elements moved so far, and every replacement is made before an element is moved out,
+        // so if `func` panics both self and the iterator are left whole
+        unsafe { drained.set_range(0..0) };
+        for (offset, index) in range.enumerate() {
+            let replacement = func(index);
+            unsafe {
+                drained.buffer.add(offset).write(ptr::replace(self.pointer.add(index), replacement));
+                drained.set_range(0..offset + 1);
+            }
+        }
+
+        drained
+    }
+
+    /// ## Info
+    /// turns self into a [`FastIterator`] over the elements in `range`, dropping the ones outside of it.
+    /// the buffer of self is reused, so nothing gets allocated or copied.
+    ///
+    /// an empty range gives back an empty [`FastIterator`], which can't be turned into a [`FastArray`] (see [`FastIterator::try_into_fast_array`]).
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::fast_arr;
+    ///
+    /// let fast_arr = fast_arr!(1, 2, 3, 4, 5);
+    ///
+    /// assert_eq!(fast_arr.into_iter_range(1..=3).collect::<Vec<_>>(), [2, 3, 4]);
+    /// ```
+    ///
+    /// ## Panics
+    /// if `range` is out of bounds, or if its start is greater than its end.
+    pub fn into_iter_range(self, range: impl RangeBounds<usize>) -> FastIterator<T> {
+        let range = resolve_range("into_iter_range", range, self.size);
+        let len = self.size;
+
+        let mut fast_iter = self.into_fast_iterator();
+        let buffer = fast_iter.buffer;
+
+        // the elements outside of `range` are taken out of the iterator first, so a panicking drop can only leak them
+        unsafe {
+            fast_iter.set_range(range.clone());
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(buffer, range.start));
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(buffer.add(range.end), len - range.end));
+        }
+
+        fast_iter
+    }
+
+    /// ## Info
+    /// splits self into two [`FastArray`]s: the elements before `at`, and the ones from `at` onwards.
+    ///
+    /// the elements are moved, not cloned: only the tail gets a new buffer, while the head keeps the buffer of self, shrunk in place when the allocator allows it.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::fast_arr;
+    ///
+    /// let fast_arr = fast_arr!(1, 2, 3, 4, 5);
+    /// let (head, tail) = fast_arr.split_off_into(2);
+    ///
+    /// assert_eq!(head, fast_arr!(1, 2));
+    /// assert_eq!(tail, fast_arr!(3, 4, 5));
+    /// ```
+    ///
+    /// ## Panics
+    /// if `at == 0` or `at >= len`, since a [`FastArray`] can't be empty.
+    pub fn split_off_into(mut self, at: usize) -> (FastArray<T>, FastArray<T>) {
+        assert!(
+            at != 0 && at < self.size,
+            "FastArray::split_off_into: can't split at {at} an array of len {}, both halves need at least one element!",
+            self.size
+        );
+
+        let tail_len = self.size - at;
+        let tail = alloc::allocate::<T>(tail_len);
+
+        let pointer = self.pointer;
+        self.pointer = ptr::null_mut();
+
+        unsafe {
+            ptr::copy_nonoverlapping(pointer.add(at), tail, tail_len);
+            let head = alloc::reallocate(pointer, self.size, at);
+
+            (FastArray { pointer: head, size: at }, FastArray { pointer: tail, size: tail_len })
         }
     }
 }
 
+impl<T: Clone> FastArray<T> {
+    /// ## Info
+    /// does the same thing as [`FastArray::drain_func`], replacing every element that's moved out with a clone of `fill_value`.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::fast_arr;
+    ///
+    /// let mut fast_arr = fast_arr!(1, 2, 3, 4);
+    ///
+    /// assert_eq!(fast_arr.drain(2.., 0).collect::<Vec<_>>(), [3, 4]);
+    /// assert_eq!(fast_arr, fast_arr!(1, 2, 0, 0));
+    /// ```
+    ///
+    /// ## Panics
+    /// if `range` is out of bounds, or if its start is greater than its end.
+    pub fn drain(&mut self, range: impl RangeBounds<usize>, fill_value: T) -> FastIterator<T> {
+        self.drain_func(range, |_| fill_value.clone())
+    }
+}
+
+impl<T: Default> FastArray<T> {
+    /// ## Info
+    /// does the same thing as [`FastArray::drain_func`], replacing every element that's moved out with the [`Default`] value of T,
+    /// like [`std::mem::take`] does.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::FastArray;
+    ///
+    /// let mut fast_arr = FastArray::new_func(3, |index| vec![index]);
+    ///
+    /// assert_eq!(fast_arr.drain_default(..2).collect::<Vec<_>>(), [vec![0], vec![1]]);
+    /// assert_eq!(fast_arr, FastArray::from(vec![vec![], vec![], vec![2]]));
+    /// ```
+    ///
+    /// ## Panics
+    /// if `range` is out of bounds, or if its start is greater than its end.
+    pub fn drain_default(&mut self, range: impl RangeBounds<usize>) -> FastIterator<T> {
+        self.drain_func(range, |_| T::default())
+    }
+}
+
+/// turns `range` into a possibly empty `start..end` range inside of `0..len`.
+fn resolve_range(method: &str, range: impl RangeBounds<usize>, len: usize) -> Range<usize> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start + 1,
+        Bound::Unbounded => 0,
+    };
+
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end + 1,
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+
+    assert!(
+        start <= end && end <= len,
+        "FastArray::{method}: range {start}..{end} is out of bounds for len {len}"
+    );
+
+    start..end
+}
+
 impl<T: Ord> FastArray<T> {
     /// ## Info
     /// uses the quicksort sorting algorithm to sort the array.
     /// 
     /// ## Example
     /// ```
-    /// use fast_array::fast_arr;
+    /// use fast_collections::fast_arr;
     ///
     /// let mut array = fast_arr!(3,5,1,4,2);
     /// array.sort();
@@ -73,7 +256,7 @@ impl<T> FastArray<T> {
     /// 
     /// ## Example
     /// ```
-    /// use fast_array::fast_arr;
+    /// use fast_collections::fast_arr;
     ///
     /// let mut array = fast_arr!(3,5,1,4,2);
     /// array.sort_by(|a, b| b.cmp(&a)); // sort the array in reverse order
@@ -129,7 +312,7 @@ impl<T: ToString> FastArray<T> {
     /// 
     /// ## Example
     /// ```
-    /// use fast_array::fast_arr;
+    /// use fast_collections::fast_arr;
     /// 
     /// let array = fast_arr!("Hello", ", ", "World", "!");
     /// 
@@ -150,7 +333,7 @@ impl<T: ToString> FastArray<T> {
     /// 
     /// ## Example
     /// ```
-    /// use fast_array::fast_arr;
+    /// use fast_collections::fast_arr;
     /// 
     /// let array = fast_arr!("Hello", "World", "!");
     /// 
@@ -200,11 +383,11 @@ impl<U> FromIterator<U> for FastArray<U> {
         let size = items.len();
 
         // Create the FastArray with the exact size
-        let mut array = unsafe { FastArray::new_empty(size) };
+        let mut array: FastArray<U> = unsafe { FastArray::new_empty(size) };
 
         // Move the items from the Vec into the FastArray
         for (index, item) in items.into_iter().enumerate() {
-            array[index] = item;
+            unsafe { array.as_mut_ptr().add(index).write(item) };
         }
 
         array
@@ -229,79 +412,36 @@ impl<T, I: ExactSizeIterator<Item = T>, >  IntoFastArray<T> for I {
     }
 }
 
-impl<T> From<Vec<T>> for FastArray<T> {
-    fn from(value: Vec<T>) -> Self {
+impl<T> FastArray<T> {
+    /// ## Info
+    /// creates a [`FastArray`] from a [`Vec`], moving the elements instead of cloning them.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::{fast_arr, FastArray, FastError};
+    ///
+    /// assert_eq!(FastArray::try_from_vec(vec![1, 2, 3]).unwrap(), fast_arr!(1, 2, 3));
+    /// assert!(matches!(FastArray::<i32>::try_from_vec(Vec::new()), Err(FastError::ZeroLength)));
+    /// ```
+    ///
+    /// ## Errors
+    /// - [`FastError::ZeroLength`] if the [`Vec`] is empty.
+    /// - [`FastError::Alloc`] if the memory couldn't be allocated.
+    pub fn try_from_vec(value: Vec<T>) -> Result<FastArray<T>, FastError> {
         let len = value.len();
         let mut iter = value.into_iter();
-        let func = |_| iter.next().unwrap();
-        Self::new_func(len, func)
-    }
-}
-
-#[cfg(feature = "serde")]
-use std::marker::PhantomData;
-#[cfg(feature = "serde")]
-impl<T: serde::Serialize> serde::Serialize for FastArray<T> {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: serde::Serializer 
-    {
-        let mut state = serializer.serialize_seq(Some(self.size))?;
-        for item in self.iter() {
-            serde::ser::SerializeSeq::serialize_element(&mut state, &item)?;
-        }
-        serde::ser::SerializeSeq::end(state)
+        FastArray::try_new_func(len, |_| iter.next().unwrap())
     }
 }
 
-#[cfg(feature = "serde")]
-impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for FastArray<T> {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: serde::Deserializer<'de> 
-    {
-        struct FastArrayVisitor<T> {
-            phant: PhantomData<T>
-        }
-
-        impl<'de, U: serde::Deserialize<'de>> serde::de::Visitor<'de> for FastArrayVisitor<U> {
-            type Value = FastArray<U>;
-        
-            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-                formatter.write_str("expecting an array")
-            }
-
-            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
-            where
-                A: serde::de::SeqAccess<'de>, 
-            {
-                let _len = seq.size_hint().unwrap_or({
-                    let mut vec = Vec::new();
-
-                    while let Some(item) = seq.next_element()? {
-                        vec.push(item);
-                    }
-
-                    return Ok(vec.into())
-                });
-
-                if _len == 1 {
-                    let mut vec = Vec::new();
-
-                    while let Some(item) = seq.next_element()? {
-                        vec.push(item);
-                    }
-
-                    return Ok(vec.into())
-                }
-
-                let iter = |_| seq.next_element::<U>();
-
-                FastArray::new_func(_len, iter);
-            }
+impl<T> From<Vec<T>> for FastArray<T> {
+    /// ## Panics
+    /// in the same cases [`FastArray::try_from_vec`] returns an error.
+    fn from(value: Vec<T>) -> Self {
+        match FastArray::try_from_vec(value) {
+            Ok(fast_arr) => fast_arr,
+            Err(error) => panic!("FastArray: {error}"),
         }
-
-        deserializer.deserialize_seq(FastArrayVisitor::<T> { phant: PhantomData::<T>})
     }
 }
 
@@ -0,0 +1,65 @@
+use std::{mem::ManuallyDrop, ptr};
+
+use crate::{alloc, FastArray};
+
+/// ## Info
+/// a [`FastArray`] filled one element at a time, for when its final len isn't known upfront.
+///
+/// the elements pushed so far are dropped if the builder is dropped before being finished.
+pub(crate) struct FastArrayBuilder<T> {
+    pointer: *mut T,
+    capacity: usize,
+    len: usize,
+}
+
+impl<T> FastArrayBuilder<T> {
+    /// creates a builder with room for `capacity` elements, without allocating if `capacity == 0`.
+    pub(crate) fn with_capacity(capacity: usize) -> FastArrayBuilder<T> {
+        let mut builder = FastArrayBuilder { pointer: ptr::null_mut(), capacity: 0, len: 0 };
+        if capacity != 0 {
+            builder.reallocate(capacity);
+        }
+        builder
+    }
+
+    /// moves the allocation to one with room for `capacity` elements, which must be at least `len`.
+    fn reallocate(&mut self, capacity: usize) {
+        self.pointer = unsafe { alloc::reallocate(self.pointer, self.capacity, capacity) };
+        self.capacity = capacity;
+    }
+
+    /// appends an element, doubling the capacity when it's full.
+    pub(crate) fn push(&mut self, value: T) {
+        if self.len == self.capacity {
+            self.reallocate((self.capacity * 2).max(4));
+        }
+
+        unsafe { self.pointer.add(self.len).write(value) };
+        self.len += 1;
+    }
+
+    /// turns the builder into a [`FastArray`] of exactly the pushed elements, or returns `None` if there are none.
+    pub(crate) fn finish(mut self) -> Option<FastArray<T>> {
+        if self.len == 0 {
+            return None;
+        }
+        // FastArray deallocates with a layout computed from its len, so the allocation must match it
+        if self.len != self.capacity {
+            self.reallocate(self.len);
+        }
+
+        let builder = ManuallyDrop::new(self);
+        Some(FastArray { pointer: builder.pointer, size: builder.len })
+    }
+}
+
+impl<T> Drop for FastArrayBuilder<T> {
+    fn drop(&mut self) {
+        if !self.pointer.is_null() {
+            unsafe {
+                ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.pointer, self.len));
+                alloc::deallocate(self.pointer, self.capacity);
+            }
+        }
+    }
+}
@@ -0,0 +1,161 @@
+use std::{
+    fs::File,
+    io::{self, Read, Write},
+    path::Path,
+};
+
+use crate::{
+    alloc,
+    pod::{Endian, Pod},
+    FastArray,
+};
+
+/// how many elements get byte-swapped at once when writing in a foreign [`Endian`].
+const SWAP_CHUNK: usize = 1024;
+
+impl<T: Pod> FastArray<T> {
+    /// ## Info
+    /// creates a new [`FastArray`] with every byte set to zero, which is a valid value for any [`Pod`] type.
+    ///
+    /// ## Panics
+    /// if `len == 0`.
+    pub(crate) fn new_zeroed(len: usize) -> FastArray<T> {
+        assert!(len != 0, "len cannot be 0!");
+
+        let pointer = alloc::allocate_zeroed::<T>(len);
+
+        FastArray { pointer, size: len }
+    }
+
+    #[inline(always)]
+    /// ## Info
+    /// borrows the elements as their raw bytes, in the native byte order.
+    pub fn as_bytes(&self) -> &[u8] {
+        unsafe { std::slice::from_raw_parts(self.pointer as *const u8, self.size * std::mem::size_of::<T>()) }
+    }
+
+    #[inline(always)]
+    /// ## Info
+    /// borrows the elements as their raw bytes mutably, in the native byte order.
+    ///
+    /// any bytes written are valid, since every bit pattern is a valid [`Pod`] value.
+    pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+        unsafe { std::slice::from_raw_parts_mut(self.pointer as *mut u8, self.size * std::mem::size_of::<T>()) }
+    }
+
+    /// ## Info
+    /// writes every element to `writer` as raw bytes, in the `endian` byte order.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::fast_arr;
+    /// use fast_collections::pod::Endian;
+    ///
+    /// let mut bytes = Vec::new();
+    /// fast_arr!(1_u16, 2).write_to(&mut bytes, Endian::Big).unwrap();
+    ///
+    /// assert_eq!(bytes, vec![0, 1, 0, 2]);
+    /// ```
+    ///
+    /// ## Errors
+    /// if writing to `writer` fails.
+    pub fn write_to(&self, mut writer: impl Write, endian: Endian) -> io::Result<()> {
+        if endian.is_native() {
+            return writer.write_all(self.as_bytes());
+        }
+
+        let elements = unsafe { std::slice::from_raw_parts(self.pointer, self.size) };
+        let mut swapped = Vec::with_capacity(SWAP_CHUNK.min(self.size));
+
+        for chunk in elements.chunks(SWAP_CHUNK) {
+            swapped.clear();
+            swapped.extend(chunk.iter().map(|element| element.swap_bytes()));
+
+            let bytes = unsafe {
+                std::slice::from_raw_parts(swapped.as_ptr() as *const u8, std::mem::size_of_val(swapped.as_slice()))
+            };
+            writer.write_all(bytes)?;
+        }
+
+        Ok(())
+    }
+
+    /// ## Info
+    /// reads exactly `len` elements from `reader`, stored as raw bytes in the `endian` byte order.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::{fast_arr, FastArray};
+    /// use fast_collections::pod::Endian;
+    ///
+    /// let bytes: &[u8] = &[1, 0, 0, 0, 2, 0, 0, 0];
+    /// let fast_arr = FastArray::<u32>::read_from(bytes, 2, Endian::Little).unwrap();
+    ///
+    /// assert_eq!(fast_arr, fast_arr!(1, 2));
+    ///
+    /// // not enough bytes for 3 elements
+    /// assert!(FastArray::<u32>::read_from(bytes, 3, Endian::Little).is_err());
+    /// ```
+    ///
+    /// ## Errors
+    /// - [`io::ErrorKind::InvalidInput`] if `len == 0`.
+    /// - [`io::ErrorKind::UnexpectedEof`] if `reader` ends before `len` elements were read.
+    /// - any error returned by `reader`.
+    pub fn read_from(mut reader: impl Read, len: usize, endian: Endian) -> io::Result<FastArray<T>> {
+        if len == 0 {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "len cannot be 0"));
+        }
+        if len.checked_mul(std::mem::size_of::<T>()).is_none_or(|size| size > isize::MAX as usize) {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "len is too big"));
+        }
+
+        let mut fast_arr: FastArray<T> = FastArray::new_zeroed(len);
+        // read_exact keeps reading after short reads, and fails on a premature end of file
+        reader.read_exact(fast_arr.as_bytes_mut())?;
+
+        if !endian.is_native() {
+            for element in unsafe { std::slice::from_raw_parts_mut(fast_arr.pointer, len) } {
+                *element = element.swap_bytes();
+            }
+        }
+
+        Ok(fast_arr)
+    }
+
+    /// ## Info
+    /// reads the whole file at `path` as elements stored in the `endian` byte order.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::{fast_arr, FastArray};
+    /// use fast_collections::pod::Endian;
+    ///
+    /// let path = std::env::temp_dir().join("fast_collections_read_exact_file.bin");
+    ///
+    /// fast_arr!(1.5_f64, -2.0).write_to(std::fs::File::create(&path).unwrap(), Endian::Big).unwrap();
+    /// let fast_arr = FastArray::<f64>::read_exact_file(&path, Endian::Big).unwrap();
+    ///
+    /// assert_eq!(fast_arr, fast_arr!(1.5, -2.0));
+    /// # std::fs::remove_file(path).unwrap();
+    /// ```
+    ///
+    /// ## Errors
+    /// - [`io::ErrorKind::InvalidData`] if the file is empty, or if its size isn't a multiple of the size of `T`.
+    /// - any error from opening or reading the file.
+    pub fn read_exact_file(path: impl AsRef<Path>, endian: Endian) -> io::Result<FastArray<T>> {
+        let file = File::open(path)?;
+        let size = std::mem::size_of::<T>();
+
+        let file_len = usize::try_from(file.metadata()?.len())
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "the file is too big to be read"))?;
+
+        if size == 0 || file_len == 0 || !file_len.is_multiple_of(size) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("the file size ({file_len}) isn't a non-zero multiple of the size of the elements ({size})"),
+            ));
+        }
+
+        FastArray::read_from(file, file_len / size, endian)
+    }
+}
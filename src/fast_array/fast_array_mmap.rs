@@ -0,0 +1,324 @@
+#![cfg(all(unix, feature = "mmap"))]
+
+use std::{
+    fs::OpenOptions,
+    io,
+    ops::{Index, IndexMut},
+    os::fd::AsRawFd,
+    path::Path,
+    ptr,
+};
+
+use crate::{pod::Pod, FastArray};
+
+/// ## Info
+/// a file mapped in memory with `mmap`, unmapped on drop.
+#[derive(Debug)]
+pub(crate) struct Mapping {
+    pointer: *mut u8,
+    len: usize,
+}
+
+impl Mapping {
+    /// maps the whole file at `path`, shared with the file when `writable` is true.
+    pub(crate) fn open(path: &Path, writable: bool) -> io::Result<Mapping> {
+        let file = OpenOptions::new().read(true).write(writable).open(path)?;
+
+        let len = usize::try_from(file.metadata()?.len())
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "the file is too big to be mapped"))?;
+        if len == 0 {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "cannot map an empty file"));
+        }
+
+        let protection = if writable {
+            libc::PROT_READ | libc::PROT_WRITE
+        } else {
+            libc::PROT_READ
+        };
+
+        // the mapping stays valid after the file is closed
+        let pointer = unsafe {
+            libc::mmap(ptr::null_mut(), len, protection, libc::MAP_SHARED, file.as_raw_fd(), 0)
+        };
+        if pointer == libc::MAP_FAILED {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(Mapping { pointer: pointer as *mut u8, len })
+    }
+
+    /// checks that the mapping holds a whole number of correctly aligned `T`s, and returns how many.
+    pub(crate) fn element_count<T: Pod>(&self) -> io::Result<usize> {
+        let size = std::mem::size_of::<T>();
+
+        if size == 0 {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "cannot map zero-sized types"));
+        }
+        if !self.len.is_multiple_of(size) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("the file size ({}) isn't a multiple of the size of the elements ({size})", self.len),
+            ));
+        }
+        if !(self.pointer as usize).is_multiple_of(std::mem::align_of::<T>()) {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "the mapping isn't aligned for the elements"));
+        }
+
+        Ok(self.len / size)
+    }
+
+    /// writes the changes back to the file.
+    pub(crate) fn flush(&self) -> io::Result<()> {
+        if unsafe { libc::msync(self.pointer as *mut libc::c_void, self.len, libc::MS_SYNC) } != 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(())
+    }
+}
+
+impl Drop for Mapping {
+    fn drop(&mut self) {
+        unsafe { libc::munmap(self.pointer as *mut libc::c_void, self.len) };
+    }
+}
+
+/// ## Info
+/// a read-only [`FastArray`] backed by a memory-mapped file, created by [`FastArray::map_file`].
+///
+/// the file is only read from disk when its pages are accessed, and the mapping is removed on drop.
+#[derive(Debug)]
+pub struct MappedFastArray<T> {
+    // only kept around to be unmapped on drop
+    #[allow(dead_code)]
+    pub(crate) mapping: Mapping,
+    pub(crate) pointer: *mut T,
+    pub(crate) size: usize,
+}
+
+/// ## Info
+/// a [`FastArray`] backed by a memory-mapped file, created by [`FastArray::map_file_mut`].
+///
+/// writes go straight to the file (through the page cache), use [`MappedFastArrayMut::flush`] to make sure they reached the disk.
+#[derive(Debug)]
+pub struct MappedFastArrayMut<T> {
+    pub(crate) mapping: Mapping,
+    pub(crate) pointer: *mut T,
+    pub(crate) size: usize,
+}
+
+unsafe impl<T: Sync> Send for MappedFastArray<T> {}
+unsafe impl<T: Sync> Sync for MappedFastArray<T> {}
+unsafe impl<T: Send> Send for MappedFastArrayMut<T> {}
+unsafe impl<T: Sync> Sync for MappedFastArrayMut<T> {}
+
+impl<T: Pod> FastArray<T> {
+    /// ## Info
+    /// maps the file at `path` in memory, read-only, as an array of `T`.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::FastArray;
+    ///
+    /// let path = std::env::temp_dir().join("fast_collections_map_file.bin");
+    /// std::fs::write(&path, [1, 2, 3, 4]).unwrap();
+    ///
+    /// // nothing else touches the file while it's mapped
+    /// let mapped = unsafe { FastArray::<u8>::map_file(&path) }.unwrap();
+    ///
+    /// assert_eq!(mapped.len(), 4);
+    /// assert_eq!(mapped[2], 3);
+    /// assert_eq!(mapped.iter().sum::<u8>(), 10);
+    /// # std::fs::remove_file(path).unwrap();
+    /// ```
+    ///
+    /// ## Errors
+    /// if the file can't be opened or mapped, is empty, or if its size isn't a multiple of the size of `T`.
+    ///
+    /// ## Unsafe
+    /// the returned array hands out `&T`s that point straight into the file, so the file must not be changed or truncated while it's mapped.
+    /// that includes other processes writing to it, a [`FastArray::map_file_mut`] of the same file, and truncating it (which makes accessing the missing pages raise `SIGBUS`).
+    pub unsafe fn map_file(path: impl AsRef<Path>) -> io::Result<MappedFastArray<T>> {
+        let mapping = Mapping::open(path.as_ref(), false)?;
+        let size = mapping.element_count::<T>()?;
+
+        Ok(MappedFastArray {
+            pointer: mapping.pointer as *mut T,
+            mapping,
+            size,
+        })
+    }
+
+    /// ## Info
+    /// maps the file at `path` in memory as an array of `T`, so that writing to the array writes to the file.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::FastArray;
+    ///
+    /// let path = std::env::temp_dir().join("fast_collections_map_file_mut.bin");
+    /// std::fs::write(&path, 1.5_f32.to_ne_bytes()).unwrap();
+    ///
+    /// // nothing else touches the file while it's mapped
+    /// let mut mapped = unsafe { FastArray::<f32>::map_file_mut(&path) }.unwrap();
+    /// mapped[0] *= 2.0;
+    /// mapped.flush().unwrap();
+    /// drop(mapped);
+    ///
+    /// assert_eq!(std::fs::read(&path).unwrap(), 3.0_f32.to_ne_bytes());
+    /// # std::fs::remove_file(path).unwrap();
+    /// ```
+    ///
+    /// ## Errors
+    /// if the file can't be opened for writing or mapped, is empty, or if its size isn't a multiple of the size of `T`.
+    ///
+    /// ## Unsafe
+    /// the returned array hands out `&mut T`s that point straight into the file, so the file must not be changed or truncated while it's mapped.
+    /// that includes other processes writing to it, mapping it a second time (two `map_file_mut` of the same file give aliasing `&mut T`s), and truncating it (which makes accessing the missing pages raise `SIGBUS`).
+    pub unsafe fn map_file_mut(path: impl AsRef<Path>) -> io::Result<MappedFastArrayMut<T>> {
+        let mapping = Mapping::open(path.as_ref(), true)?;
+        let size = mapping.element_count::<T>()?;
+
+        Ok(MappedFastArrayMut {
+            pointer: mapping.pointer as *mut T,
+            mapping,
+            size,
+        })
+    }
+}
+
+macro_rules! impl_mapped_common {
+    ($type:ident) => {
+        impl<T: Pod> $type<T> {
+            #[inline(always)]
+            /// ## Info
+            /// returns the number of elements in the mapped file.
+            pub fn len(&self) -> usize {
+                self.size
+            }
+
+            #[inline(always)]
+            /// ## Info
+            /// always `false`, since empty files can't be mapped.
+            pub fn is_empty(&self) -> bool {
+                self.size == 0
+            }
+
+            #[inline(always)]
+            /// ## Info
+            /// gets an element at a given index, or `None` if the index is out of bounds.
+            pub fn get(&self, index: usize) -> Option<&T> {
+                self.as_slice().get(index)
+            }
+
+            #[inline(always)]
+            /// ## Info
+            /// borrows the mapped elements as a slice.
+            pub fn as_slice(&self) -> &[T] {
+                unsafe { std::slice::from_raw_parts(self.pointer, self.size) }
+            }
+
+            #[inline(always)]
+            /// ## Info
+            /// creates an iterator over the mapped elements.
+            pub fn iter(&self) -> std::slice::Iter<'_, T> {
+                self.as_slice().iter()
+            }
+
+            /// ## Info
+            /// copies the mapped elements into a heap-allocated [`FastArray`].
+            pub fn to_fast_array(&self) -> FastArray<T> {
+                FastArray::from(self.as_slice())
+            }
+        }
+
+        impl<T: Pod> Index<usize> for $type<T> {
+            type Output = T;
+
+            #[inline(always)]
+            fn index(&self, index: usize) -> &Self::Output {
+                &self.as_slice()[index]
+            }
+        }
+
+        impl<T: Pod> AsRef<[T]> for $type<T> {
+            fn as_ref(&self) -> &[T] {
+                self.as_slice()
+            }
+        }
+
+        impl<'a, T: Pod> IntoIterator for &'a $type<T> {
+            type Item = &'a T;
+
+            type IntoIter = std::slice::Iter<'a, T>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter()
+            }
+        }
+
+        impl<T: Pod + PartialEq> PartialEq<FastArray<T>> for $type<T> {
+            fn eq(&self, other: &FastArray<T>) -> bool {
+                self.size == other.size && (0..self.size).all(|i| self[i] == other[i])
+            }
+        }
+    };
+}
+
+impl_mapped_common!(MappedFastArray);
+impl_mapped_common!(MappedFastArrayMut);
+
+impl<T: Pod> MappedFastArrayMut<T> {
+    #[inline(always)]
+    /// ## Info
+    /// gets a mutable reference to the element at a given index, or `None` if the index is out of bounds.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.as_mut_slice().get_mut(index)
+    }
+
+    #[inline(always)]
+    /// ## Info
+    /// borrows the mapped elements as a mutable slice.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { std::slice::from_raw_parts_mut(self.pointer, self.size) }
+    }
+
+    #[inline(always)]
+    /// ## Info
+    /// creates an iterator over mutable references to the mapped elements.
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.as_mut_slice().iter_mut()
+    }
+
+    /// ## Info
+    /// blocks until the changes are written back to the file.
+    ///
+    /// ## Errors
+    /// if `msync` fails.
+    pub fn flush(&self) -> io::Result<()> {
+        self.mapping.flush()
+    }
+}
+
+impl<T: Pod> IndexMut<usize> for MappedFastArrayMut<T> {
+    #[inline(always)]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.as_mut_slice()[index]
+    }
+}
+
+impl<T: Pod> AsMut<[T]> for MappedFastArrayMut<T> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<'a, T: Pod> IntoIterator for &'a mut MappedFastArrayMut<T> {
+    type Item = &'a mut T;
+
+    type IntoIter = std::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
@@ -0,0 +1,250 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+    ptr,
+};
+
+use crate::{alloc, FastArray};
+
+/// ## Info
+/// a [`FastArray`] whose length is known at compile time, so it lives on the stack instead of the heap.
+///
+/// ## Example
+/// ```
+/// use fast_collections::fast_arr;
+///
+/// let array = fast_arr!(const 1, 2, 3);
+///
+/// assert_eq!(array.len(), 3);
+/// assert_eq!(array.to_string(), "[1, 2, 3]");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(align(32))]
+pub struct FastArrayN<T, const N: usize> {
+    pub(crate) data: [T; N],
+}
+
+impl<T, const N: usize> FastArrayN<T, N> {
+    #[inline(always)]
+    /// ## Info
+    /// creates a new [`FastArrayN`] from a closure or function that accepts the index being set.
+    pub fn new_func<F: FnMut(usize) -> T>(func: F) -> FastArrayN<T, N> {
+        FastArrayN {
+            data: std::array::from_fn(func),
+        }
+    }
+
+    #[inline(always)]
+    /// ## Info
+    /// returns the length of the [`FastArrayN`], aka `N`.
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    #[inline(always)]
+    /// ## Info
+    /// returns `true` if `N == 0`.
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    #[inline(always)]
+    /// ## Info
+    /// gets an element at a given index, or `None` if the index is out of bounds.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.data.get(index)
+    }
+
+    #[inline(always)]
+    /// ## Info
+    /// swaps the elements at the two indices.
+    ///
+    /// ## Panics
+    /// if either index is out of bounds.
+    pub fn swap(&mut self, index1: usize, index2: usize) {
+        self.data.swap(index1, index2);
+    }
+
+    #[inline(always)]
+    /// ## Info
+    /// creates an iterator over the elements of the [`FastArrayN`].
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.data.iter()
+    }
+
+    #[inline(always)]
+    /// ## Info
+    /// creates an iterator over mutable references to the elements of the [`FastArrayN`].
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.data.iter_mut()
+    }
+
+    #[inline(always)]
+    /// ## Info
+    /// borrows the elements as a slice.
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    #[inline(always)]
+    /// ## Info
+    /// borrows the elements as a mutable slice.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    #[inline(always)]
+    /// ## Info
+    /// returns the underlying array.
+    pub fn into_inner(self) -> [T; N] {
+        self.data
+    }
+
+    /// ## Info
+    /// moves the elements into a heap-allocated [`FastArray`].
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::fast_arr;
+    ///
+    /// assert_eq!(fast_arr!(const 1, 2, 3).into_fast_array(), fast_arr!(1, 2, 3));
+    /// ```
+    ///
+    /// ## Panics
+    /// if `N == 0`.
+    pub fn into_fast_array(self) -> FastArray<T> {
+        let mut elements = self.data.into_iter();
+        FastArray::new_func(N, |_| elements.next().unwrap())
+    }
+}
+
+impl<T: Clone, const N: usize> FastArrayN<T, N> {
+    #[inline(always)]
+    /// ## Info
+    /// creates a new [`FastArrayN`] filled with `fill_value`.
+    pub fn new(fill_value: T) -> FastArrayN<T, N> {
+        FastArrayN::new_func(|_| fill_value.clone())
+    }
+}
+
+impl<T: Default, const N: usize> FastArrayN<T, N> {
+    #[inline(always)]
+    /// ## Info
+    /// creates a new [`FastArrayN`] filled with the [`Default`] value of T.
+    pub fn new_default() -> FastArrayN<T, N> {
+        FastArrayN::new_func(|_| T::default())
+    }
+}
+
+impl<T: Default, const N: usize> Default for FastArrayN<T, N> {
+    fn default() -> Self {
+        FastArrayN::new_default()
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for FastArrayN<T, N> {
+    #[inline(always)]
+    fn from(value: [T; N]) -> Self {
+        FastArrayN { data: value }
+    }
+}
+
+impl<T, const N: usize> From<FastArrayN<T, N>> for FastArray<T> {
+    /// ## Panics
+    /// if `N == 0`.
+    fn from(value: FastArrayN<T, N>) -> Self {
+        value.into_fast_array()
+    }
+}
+
+impl<T, const N: usize> TryFrom<FastArray<T>> for FastArrayN<T, N> {
+    /// the [`FastArray`] is given back if its length isn't `N`.
+    type Error = FastArray<T>;
+
+    /// ## Info
+    /// moves the elements of a [`FastArray`] of length `N` on the stack.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::fast_arr;
+    /// use fast_collections::fast_array::fast_array_n::FastArrayN;
+    ///
+    /// let array: FastArrayN<i32, 3> = fast_arr!(1, 2, 3).try_into().unwrap();
+    /// assert_eq!(array, fast_arr!(const 1, 2, 3));
+    ///
+    /// assert!(FastArrayN::<i32, 2>::try_from(fast_arr!(1, 2, 3)).is_err());
+    /// ```
+    fn try_from(mut value: FastArray<T>) -> Result<Self, Self::Error> {
+        if value.size != N {
+            return Err(value);
+        }
+
+        let data = std::array::from_fn(|i| unsafe { ptr::read(value.pointer.add(i)) });
+
+        // the elements have been moved out, so only the buffer is left to free
+        unsafe { alloc::deallocate(value.pointer, value.size) };
+        value.pointer = ptr::null_mut();
+
+        Ok(FastArrayN { data })
+    }
+}
+
+impl<T, const N: usize> Index<usize> for FastArrayN<T, N> {
+    type Output = T;
+
+    #[inline(always)]
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.data[index]
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for FastArrayN<T, N> {
+    #[inline(always)]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.data[index]
+    }
+}
+
+impl<T, const N: usize> IntoIterator for FastArrayN<T, N> {
+    type Item = T;
+
+    type IntoIter = std::array::IntoIter<T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a FastArrayN<T, N> {
+    type Item = &'a T;
+
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: Display, const N: usize> Display for FastArrayN<T, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[")?;
+
+        let mut first = true;
+        for item in self.iter() {
+            if first {
+                first = false;
+            } else {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", item)?;
+        }
+
+        write!(f, "]")
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq<FastArray<T>> for FastArrayN<T, N> {
+    fn eq(&self, other: &FastArray<T>) -> bool {
+        other.size == N && (0..N).all(|i| self.data[i] == other[i])
+    }
+}
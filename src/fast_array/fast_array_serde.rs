@@ -0,0 +1,203 @@
+#![cfg(feature = "serde")]
+
+//! ## Info
+//! the `serde` representations of [`FastArray`].
+//!
+//! by default, a [`FastArray`] is serialized as a sequence of its elements.
+//! for byte buffers and other [`Pod`] arrays, [`bytes`] and [`AsBytes`] write a single blob of bytes instead, which binary formats handle much faster.
+
+use std::{fmt::Formatter, marker::PhantomData};
+
+use serde::{
+    de::{self, SeqAccess, Visitor},
+    ser::SerializeSeq,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{
+    pod::{Endian, Pod},
+    FastArray,
+};
+
+use super::fast_array_builder::FastArrayBuilder;
+
+impl<T: Serialize> Serialize for FastArray<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_seq(Some(self.size))?;
+        for element in unsafe { std::slice::from_raw_parts(self.pointer, self.size) } {
+            state.serialize_element(element)?;
+        }
+        state.end()
+    }
+}
+
+/// the most memory reserved upfront from a size hint, since hints can come from untrusted data.
+const MAX_PREALLOCATION: usize = 1024 * 1024;
+
+struct FastArrayVisitor<T> {
+    phant: PhantomData<T>,
+}
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for FastArrayVisitor<T> {
+    type Value = FastArray<T>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("a non-empty sequence")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let element_size = std::mem::size_of::<T>().max(1);
+        let capacity = seq.size_hint().unwrap_or(0).min(MAX_PREALLOCATION / element_size);
+
+        // if an element fails, the builder drops the ones before it
+        let mut builder = FastArrayBuilder::with_capacity(capacity);
+        while let Some(element) = seq.next_element()? {
+            builder.push(element);
+        }
+
+        builder.finish().ok_or_else(|| de::Error::invalid_length(0, &self))
+    }
+
+    // self-describing formats hand over byte blobs (like the ones written by `bytes`) as bytes, not as a sequence
+    fn visit_bytes<E: de::Error>(self, bytes: &[u8]) -> Result<Self::Value, E> {
+        let mut builder = FastArrayBuilder::with_capacity(bytes.len());
+        for &byte in bytes {
+            builder.push(T::deserialize(de::value::U8Deserializer::<E>::new(byte))?);
+        }
+
+        builder.finish().ok_or_else(|| de::Error::invalid_length(0, &self))
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for FastArray<T> {
+    /// ## Info
+    /// deserializes a [`FastArray`] from a sequence, element by element.
+    ///
+    /// the array is allocated once when the format knows the len of the sequence upfront, and grows as needed otherwise.
+    /// an empty sequence is an error, since a [`FastArray`] can't be empty.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(FastArrayVisitor { phant: PhantomData })
+    }
+}
+
+/// ## Info
+/// represents a [`FastArray<u8>`] as a single blob of bytes, instead of a sequence of numbers.
+///
+/// meant to be used with `#[serde(with = "fast_collections::fast_array::fast_array_serde::bytes")]`.
+/// formats without a bytes type (like json) still get a sequence of numbers, and it can be read back from either.
+///
+/// ## Example
+/// ```ignore
+/// #[derive(Serialize, Deserialize)]
+/// struct Packet {
+///     #[serde(with = "fast_collections::fast_array::fast_array_serde::bytes")]
+///     payload: FastArray<u8>,
+/// }
+/// ```
+pub mod bytes {
+    use std::marker::PhantomData;
+
+    use serde::{Deserializer, Serializer};
+
+    use super::FastArrayVisitor;
+    use crate::FastArray;
+
+    pub fn serialize<S: Serializer>(fast_arr: &FastArray<u8>, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(fast_arr.as_bytes())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<FastArray<u8>, D::Error> {
+        deserializer.deserialize_bytes(FastArrayVisitor { phant: PhantomData })
+    }
+}
+
+/// ## Info
+/// serializes a [`FastArray`] of [`Pod`] elements as a single blob of raw little-endian bytes, instead of a sequence of elements.
+///
+/// both `AsBytes<FastArray<T>>` and `AsBytes<&FastArray<T>>` can be serialized, but only the first one can be deserialized.
+///
+/// ## Example
+/// ```ignore
+/// #[derive(Serialize, Deserialize)]
+/// struct Reading {
+///     samples: AsBytes<FastArray<f32>>,
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct AsBytes<A>(pub A);
+
+impl<A> AsBytes<A> {
+    #[inline(always)]
+    /// ## Info
+    /// returns the wrapped value.
+    pub fn into_inner(self) -> A {
+        self.0
+    }
+}
+
+/// writes the elements as little-endian bytes, without copying them on little-endian targets.
+fn serialize_pod<T: Pod, S: Serializer>(fast_arr: &FastArray<T>, serializer: S) -> Result<S::Ok, S::Error> {
+    if Endian::Little.is_native() {
+        return serializer.serialize_bytes(fast_arr.as_bytes());
+    }
+
+    let mut bytes = Vec::with_capacity(fast_arr.as_bytes().len());
+    fast_arr.write_to(&mut bytes, Endian::Little).map_err(serde::ser::Error::custom)?;
+    serializer.serialize_bytes(&bytes)
+}
+
+impl<T: Pod> Serialize for AsBytes<FastArray<T>> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_pod(&self.0, serializer)
+    }
+}
+
+impl<T: Pod> Serialize for AsBytes<&FastArray<T>> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_pod(self.0, serializer)
+    }
+}
+
+struct PodVisitor<T> {
+    phant: PhantomData<T>,
+}
+
+impl<T: Pod> PodVisitor<T> {
+    /// reads the elements back from their little-endian bytes.
+    fn read_bytes<E: de::Error>(&self, bytes: &[u8]) -> Result<FastArray<T>, E> {
+        let size = std::mem::size_of::<T>();
+        if size == 0 || bytes.is_empty() || !bytes.len().is_multiple_of(size) {
+            return Err(E::invalid_length(bytes.len(), self));
+        }
+
+        FastArray::read_from(bytes, bytes.len() / size, Endian::Little).map_err(E::custom)
+    }
+}
+
+impl<'de, T: Pod> Visitor<'de> for PodVisitor<T> {
+    type Value = FastArray<T>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        write!(formatter, "a non-empty multiple of {} bytes", std::mem::size_of::<T>())
+    }
+
+    fn visit_bytes<E: de::Error>(self, bytes: &[u8]) -> Result<Self::Value, E> {
+        self.read_bytes(bytes)
+    }
+
+    // formats without a bytes type write them as a sequence of numbers
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(MAX_PREALLOCATION));
+        while let Some(byte) = seq.next_element::<u8>()? {
+            bytes.push(byte);
+        }
+
+        self.read_bytes(&bytes)
+    }
+}
+
+impl<'de, T: Pod> Deserialize<'de> for AsBytes<FastArray<T>> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_bytes(PodVisitor { phant: PhantomData }).map(AsBytes)
+    }
+}
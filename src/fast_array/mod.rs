@@ -1,3 +1,8 @@
 pub mod fast_array_basics;
 pub mod fast_array;
+pub(crate) mod fast_array_builder;
+pub mod fast_array_io;
+pub mod fast_array_mmap;
+pub mod fast_array_n;
+pub mod fast_array_serde;
 pub mod nightly;
\ No newline at end of file
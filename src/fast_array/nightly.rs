@@ -1,8 +1,7 @@
 #![cfg(feature = "nightly")]
 // use std::iter::Step;
-use std::cmp::Ordering;
 
-use crate::FastArray;
+use crate::{FastArray, FastError};
 use std::iter::Step;
 
 impl<T: Step + std::fmt::Debug + Copy> FastArray<T> {
@@ -14,7 +13,9 @@ impl<T: Step + std::fmt::Debug + Copy> FastArray<T> {
     ///
     /// ## Example
     /// ```
-    /// let fast_arr = (0..10).into().as_fast_array(); // use into() for best performance
+    /// use fast_collections::FastArray;
+    ///
+    /// let fast_arr: FastArray<i32> = (0..10).into(); // use into() for best performance
     /// let fast_arr_range = FastArray::new_range(0, 10);
     ///
     /// assert_eq!(fast_arr, fast_arr_range);
@@ -31,7 +32,7 @@ impl<T: Step + std::fmt::Debug + Copy> FastArray<T> {
         let mut value = start;
         let mut index = 0;
         while &value < &end {
-            empty_arr[index] = value;
+            unsafe { empty_arr.as_mut_ptr().add(index).write(value) };
             value = T::forward(value, 1);
             index += 1;
         }
@@ -39,11 +40,43 @@ impl<T: Step + std::fmt::Debug + Copy> FastArray<T> {
         empty_arr
     }
 
+    /// ## Info
+    /// does the same thing as [`FastArray::new_range`], but returns an error instead of panicking.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::{fast_arr, FastArray, FastError};
+    ///
+    /// assert_eq!(FastArray::try_new_range(2, 5).unwrap(), fast_arr!(2, 3, 4));
+    /// assert!(matches!(FastArray::try_new_range(5, 5), Err(FastError::ZeroLength)));
+    /// assert!(matches!(FastArray::try_new_range(5, 2), Err(FastError::ZeroLength)));
+    /// ```
+    ///
+    /// ## Errors
+    /// - [`FastError::ZeroLength`] if `start >= end`.
+    /// - [`FastError::Alloc`] if the number of steps from `start` to `end` doesn't fit in a `usize`, or if the memory couldn't be allocated.
+    pub fn try_new_range(start: T, end: T) -> Result<FastArray<T>, FastError> {
+        if start >= end {
+            return Err(FastError::ZeroLength);
+        }
+
+        let (_, Some(len)) = T::steps_between(&start, &end) else {
+            return Err(FastError::Alloc);
+        };
+
+        let mut value = start;
+        FastArray::try_new_func(len, |_| {
+            let current = value;
+            value = T::forward(value, 1);
+            current
+        })
+    }
+
     /// ## Info
     /// same functionality as [`FastArray::new_range`], just skips the `start != end` check for performance reasons.
     /// if `start == end`, this function ends up being undefined behavior.
     pub unsafe fn new_range_unchecked(start: T, end: T) -> FastArray<T> {
-        // assert_ne!(start, end, "Start and end must not be equal!");
+        debug_assert_ne!(start, end, "FastArray::new_range_unchecked: start and end must not be equal!");
 
         let (_, Some(len)) = T::steps_between(&start, &end) else {
             panic!("only known steps in-between are allowed")
@@ -53,8 +86,8 @@ impl<T: Step + std::fmt::Debug + Copy> FastArray<T> {
 
         let mut value = start;
         let mut index = 0;
-        while &value < &end {
-            empty_arr[index] = value;
+        while value < end {
+            unsafe { empty_arr.as_mut_ptr().add(index).write(value) };
             value = T::forward(value, 1);
             index += 1;
         }
@@ -68,7 +101,7 @@ pub mod simd {
     use crate::FastArray;
     use std::arch::x86_64::{_mm_prefetch, _MM_HINT_T0};
     use std::ops::{Add, Mul};
-    use std::simd::{LaneCount, Simd, SimdElement, SupportedLaneCount};
+    use std::simd::{Simd, SimdElement};
 
     impl<T: Copy + Default + Add<Output = T> + Mul<Output = T> + std::iter::Sum + SimdElement>
         FastArray<T>
@@ -270,7 +303,6 @@ pub mod simd {
 
         fn simd_add_generic<const N: usize>(&mut self, other: T)
         where
-            LaneCount<N>: SupportedLaneCount,
             Simd<T, N>: Add<Output = Simd<T, N>>,
         {
             // assert!(self.pointer as usize % 32 == 0, "Memory not properly aligned!");
@@ -305,7 +337,6 @@ pub mod simd {
 
         fn simd_add_array_generic<const N: usize>(&mut self, other: &FastArray<T>)
         where
-            LaneCount<N>: SupportedLaneCount,
             Simd<T, N>: Add<Output = Simd<T, N>>, // ✅ Explicit per-lane Add support
         {
             // assert!(self.pointer as usize % 32 == 0, "Memory not properly aligned!");
@@ -341,7 +372,6 @@ pub mod simd {
 
         fn simd_mul_generic<const N: usize>(&mut self, other: T)
         where
-            LaneCount<N>: SupportedLaneCount,
             Simd<T, N>: Mul<Output = Simd<T, N>>,
         {
             // type WideSimd<T, const N: usize> = Simd<T, N>;
@@ -374,7 +404,6 @@ pub mod simd {
 
         fn simd_mul_array_generic<const N: usize>(&mut self, other: &FastArray<T>)
         where
-            LaneCount<N>: SupportedLaneCount,
             Simd<T, N>: Mul<Output = Simd<T, N>>,
         {
             assert_eq!(self.size, other.size, "the two FastArrays don't have the same length!");
@@ -409,7 +438,6 @@ pub mod simd {
 
         fn simd_dot_generic<const N: usize>(&self, other: &FastArray<T>) -> T
         where
-            LaneCount<N>: SupportedLaneCount,
             Simd<T, N>: Mul<Output = Simd<T, N>> + Add<Output = Simd<T, N>>,
         {
             // type WideSimd<T, const N: usize> = Simd<T, N>;
@@ -1,18 +1,95 @@
-use std::{
-    alloc::{alloc, dealloc, Layout},
-    ptr,
-};
+use std::ops::Range;
+use std::ptr;
 
-#[derive(Debug, Clone)]
+use crate::{alloc, FastError};
+
+#[derive(Debug)]
 #[repr(align(32))]
 /// ## Info
 /// a very fast and bare-bones iterator.
+///
+/// it owns a buffer, and yields the elements between `start` and `end` from both sides.
+/// the elements before `start` and from `end` onwards have already been moved out, so they're never read or dropped again.
+///
+/// it's [`Send`] and [`Sync`] only when `T` is, since it owns its elements:
+/// ```compile_fail
+/// use std::rc::Rc;
+/// use fast_collections::FastIterator;
+///
+/// fn send<T: Send>(_: T) {}
+/// send(FastIterator::new_func(2, || Rc::new(0)));
+/// ```
 pub struct FastIterator<T> {
-    pub(crate) pointer: *mut T,
-    pub(crate) len: usize,
-    pub(crate) current_index: (usize, usize),
+    /// the start of the allocation, which is freed with the same layout it was allocated with.
+    pub(crate) buffer: *mut T,
+    /// the number of elements the buffer was allocated for.
+    pub(crate) capacity: usize,
+    /// the next element yielded by `next`.
+    pub(crate) start: *mut T,
+    /// one past the next element yielded by `next_back`.
+    pub(crate) end: *mut T,
+}
+
+const fn is_zst<T>() -> bool {
+    std::mem::size_of::<T>() == 0
 }
+
 impl<T> FastIterator<T> {
+    /// ## Info
+    /// takes ownership of a buffer of `capacity` initialized elements, allocated through [`crate::alloc`].
+    ///
+    /// zero-sized types don't move the pointers, so `start` and `end` are used as a counter of the remaining elements instead.
+    #[inline(always)]
+    pub(crate) unsafe fn from_raw_parts(buffer: *mut T, capacity: usize) -> FastIterator<T> {
+        let end = if is_zst::<T>() {
+            buffer.wrapping_byte_add(capacity)
+        } else {
+            unsafe { buffer.add(capacity) }
+        };
+
+        FastIterator { buffer, capacity, start: buffer, end }
+    }
+
+    /// ## Info
+    /// an iterator without any element, which owns no memory.
+    #[inline(always)]
+    pub(crate) fn empty() -> FastIterator<T> {
+        unsafe { FastIterator::from_raw_parts(alloc::empty(), 0) }
+    }
+
+    /// ## Info
+    /// restricts the elements that are still to be yielded to the ones at `range` inside of the buffer.
+    ///
+    /// the elements left out aren't dropped, that's up to the caller.
+    #[inline(always)]
+    pub(crate) unsafe fn set_range(&mut self, range: Range<usize>) {
+        if is_zst::<T>() {
+            self.start = self.buffer.wrapping_byte_add(range.start);
+            self.end = self.buffer.wrapping_byte_add(range.end);
+        } else {
+            self.start = unsafe { self.buffer.add(range.start) };
+            self.end = unsafe { self.buffer.add(range.end) };
+        }
+    }
+
+    /// ## Info
+    /// the number of elements that are still to be yielded.
+    #[inline(always)]
+    pub(crate) fn remaining(&self) -> usize {
+        if is_zst::<T>() {
+            self.end as usize - self.start as usize
+        } else {
+            unsafe { self.end.offset_from(self.start) as usize }
+        }
+    }
+
+    /// ## Info
+    /// whether all the elements of the buffer are still there, so the buffer can be handed over as is.
+    #[inline(always)]
+    pub(crate) fn is_untouched(&self) -> bool {
+        self.start == self.buffer && self.remaining() == self.capacity
+    }
+
     /// ## Info
     /// **ONLY** allocates the memory required by the iterator, but doesn't actually fill it with any values.
     /// ## Warning
@@ -20,21 +97,9 @@ impl<T> FastIterator<T> {
     pub unsafe fn allocate_mem(len: usize) -> FastIterator<T> {
         assert!(len != 0);
 
-        // let layout = Layout::array::<T>(len).expect("failed to create layout");
-        let layout = Layout::from_size_align(len * std::mem::size_of::<T>(), 32)
-            .expect("failed to create layout");
+        let raw_ptr = alloc::allocate::<T>(len);
 
-        let raw_ptr = unsafe { alloc(layout) as *mut T };
-
-        if raw_ptr.is_null() {
-            panic!("Memory alloc failed.")
-        };
-
-        FastIterator {
-            pointer: raw_ptr,
-            len: len,
-            current_index: (0, 0),
-        }
+        unsafe { FastIterator::from_raw_parts(raw_ptr, len) }
     }
 
     /// ## Info
@@ -42,23 +107,11 @@ impl<T> FastIterator<T> {
     ///
     /// If `len == 0`, using this method becomes undefined behavior
     pub unsafe fn allocate_mem_unchecked(len: usize) -> FastIterator<T> {
-        // assert!(len != 0);
-
-        // let layout = Layout::array::<T>(len).expect("failed to create layout");
-        let layout = Layout::from_size_align(len * std::mem::size_of::<T>(), 32)
-            .expect("failed to create layout");
-
-        let raw_ptr = unsafe { alloc(layout) as *mut T };
+        debug_assert!(len != 0, "FastIterator::allocate_mem_unchecked: len cannot be 0!");
 
-        if raw_ptr.is_null() {
-            panic!("Memory alloc failed.")
-        };
+        let raw_ptr = alloc::allocate::<T>(len);
 
-        FastIterator {
-            pointer: raw_ptr,
-            len: len,
-            current_index: (0, 0),
-        }
+        unsafe { FastIterator::from_raw_parts(raw_ptr, len) }
     }
 
     /// ## Info
@@ -66,7 +119,7 @@ impl<T> FastIterator<T> {
     ///
     /// ## Example
     /// ```
-    /// use fast_array::{FastArray, FastIterator};
+    /// use fast_collections::FastIterator;
     ///
     /// let mut val = 0;
     /// let func = || {
@@ -75,7 +128,7 @@ impl<T> FastIterator<T> {
     /// };
     ///
     /// let fast_iter = FastIterator::new_func(5, func);
-    /// let fast_arr = fast_iter.as_fast_array();
+    /// let fast_arr = fast_iter.into_fast_array();
     /// assert_eq!(fast_arr.to_string(), "[1, 2, 3, 4, 5]");
     /// ```
     ///
@@ -87,25 +140,42 @@ impl<T> FastIterator<T> {
     {
         assert!(len != 0);
 
-        // let layout = Layout::array::<T>(len).expect("failed to create layout");
-        let layout = Layout::from_size_align(len * std::mem::size_of::<T>(), 32)
-            .expect("failed to create layout");
+        let raw_ptr = alloc::allocate::<T>(len);
+
+        for x in 0..len {
+            unsafe { raw_ptr.add(x).write(func()) };
+        }
 
-        let raw_ptr = unsafe { alloc(layout) as *mut T };
+        unsafe { FastIterator::from_raw_parts(raw_ptr, len) }
+    }
 
-        if raw_ptr.is_null() {
-            panic!("Memory alloc failed.")
-        };
+    /// ## Info
+    /// does the same thing as [`FastIterator::new_func`], but returns an error instead of panicking.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::{FastError, FastIterator};
+    ///
+    /// let fast_iter = FastIterator::try_new_func(3, || 'a').unwrap();
+    /// assert_eq!(fast_iter.collect::<String>(), "aaa");
+    ///
+    /// assert!(matches!(FastIterator::try_new_func(0, || 'a'), Err(FastError::ZeroLength)));
+    /// ```
+    ///
+    /// ## Errors
+    /// - [`FastError::ZeroLength`] if `len == 0`.
+    /// - [`FastError::Alloc`] if the memory couldn't be allocated.
+    pub fn try_new_func<F>(len: usize, mut func: F) -> Result<FastIterator<T>, FastError>
+    where
+        F: FnMut() -> T,
+    {
+        let raw_ptr = alloc::try_allocate::<T>(len)?;
 
         for x in 0..len {
             unsafe { raw_ptr.add(x).write(func()) };
         }
 
-        FastIterator {
-            pointer: raw_ptr,
-            len: len,
-            current_index: (0, 0),
-        }
+        Ok(unsafe { FastIterator::from_raw_parts(raw_ptr, len) })
     }
 
     /// ## Info
@@ -115,32 +185,21 @@ impl<T> FastIterator<T> {
     where
         F: FnMut() -> T,
     {
-        // assert!(len != 0);
+        debug_assert!(len != 0, "FastIterator::new_func_unchecked: len cannot be 0!");
 
-        // let layout = Layout::array::<T>(len).expect("failed to create layout");
-        let layout = Layout::from_size_align(len * std::mem::size_of::<T>(), 32)
-            .expect("failed to create layout");
-
-        let raw_ptr = unsafe { alloc(layout) as *mut T };
-
-        if raw_ptr.is_null() {
-            panic!("Memory alloc failed.")
-        };
+        let raw_ptr = alloc::allocate::<T>(len);
 
         for x in 0..len {
             unsafe { raw_ptr.add(x).write(func()) };
         }
 
-        FastIterator {
-            pointer: raw_ptr,
-            len: len,
-            current_index: (0, 0),
-        }
+        unsafe { FastIterator::from_raw_parts(raw_ptr, len) }
     }
 }
 
-unsafe impl<T> Send for FastIterator<T> {}
-// unsafe impl<T> Sync for FastIterator<T> {}
+// it owns its elements, like a `Vec<T>`
+unsafe impl<T: Send> Send for FastIterator<T> {}
+unsafe impl<T: Sync> Sync for FastIterator<T> {}
 
 // #[cfg(not(feature = "rayon"))]
 mod iter {
@@ -149,56 +208,52 @@ mod iter {
         type Item = T;
 
         fn next(&mut self) -> Option<Self::Item> {
-            if self.current_index.0 + self.current_index.1 >= self.len {
+            if self.start == self.end {
                 None
+            } else if is_zst::<T>() {
+                self.start = self.start.wrapping_byte_add(1);
+                // any aligned pointer can be read for a zero-sized type
+                Some(unsafe { ptr::read(self.buffer) })
             } else {
-                let read_ptr = unsafe { ptr::read(self.pointer) };
-                // println!("*{}", unsafe{ (*self.pointer).clone() });
-                self.pointer = unsafe { self.pointer.add(1) };
-                self.current_index.0 += 1;
-                Some(read_ptr)
+                let element = unsafe { ptr::read(self.start) };
+                self.start = unsafe { self.start.add(1) };
+                Some(element)
             }
         }
 
         fn size_hint(&self) -> (usize, Option<usize>) {
-            (self.len, Some(self.len))
+            let remaining = self.remaining();
+            (remaining, Some(remaining))
         }
     }
 }
 
 impl<T> DoubleEndedIterator for FastIterator<T> {
     fn next_back(&mut self) -> Option<Self::Item> {
-        // println!("{}", 3);
-        if self.current_index.0 + self.current_index.1 >= self.len {
+        if self.start == self.end {
             None
+        } else if is_zst::<T>() {
+            self.end = self.end.wrapping_byte_sub(1);
+            Some(unsafe { ptr::read(self.buffer) })
         } else {
-            let read_ptr = unsafe { ptr::read(self.pointer.add(self.len -1 -self.current_index.0)) };
-            // println!("*{}", unsafe{ (*self.pointer).clone() });
-            // self.pointer = unsafe { self.pointer.add(1) };
-            self.current_index.1 += 1;
-            Some(read_ptr)
+            self.end = unsafe { self.end.sub(1) };
+            Some(unsafe { ptr::read(self.end) })
         }
     }
 }
 
 impl<T> Drop for FastIterator<T> {
     fn drop(&mut self) {
-        let len = (self.len - self.current_index.0) - self.current_index.1;
-
-        for i in 0..len {
-            // println!("{}", i);
-            unsafe {
-                ptr::drop_in_place(self.pointer.add(i));
-            }
+        // the buffer has been handed over to another collection
+        if self.buffer.is_null() {
+            return;
         }
 
-        unsafe {
-            // Calculate the original pointer from the current pointer and index
-            let original_pointer = self.pointer.sub(self.current_index.0);
+        let remaining = if is_zst::<T>() { self.buffer } else { self.start };
 
-            let layout = Layout::array::<T>(self.len).expect("Failed to create layout");
-
-            dealloc(original_pointer as *mut u8, layout); // Deallocate memory
+        unsafe {
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(remaining, self.remaining()));
+            alloc::deallocate(self.buffer, self.capacity);
         }
     }
 }
@@ -206,6 +261,25 @@ impl<T> Drop for FastIterator<T> {
 impl<T> ExactSizeIterator for FastIterator<T> {
     #[inline(always)]
     fn len(&self) -> usize {
-        self.len
+        self.remaining()
+    }
+}
+
+impl<T: Clone> Clone for FastIterator<T> {
+    /// ## Info
+    /// clones the elements that are still to be yielded into a new buffer.
+    fn clone(&self) -> Self {
+        let len = self.remaining();
+        if len == 0 {
+            return FastIterator::empty();
+        }
+
+        let raw_ptr = alloc::allocate::<T>(len);
+        for i in 0..len {
+            let element = if is_zst::<T>() { self.buffer } else { unsafe { self.start.add(i) } };
+            unsafe { raw_ptr.add(i).write((*element).clone()) };
+        }
+
+        unsafe { FastIterator::from_raw_parts(raw_ptr, len) }
     }
 }
@@ -1,13 +1,33 @@
 use super::fast_iterator::FastIterator;
-use crate::{fast_array::fast_array::FastArray, FastMatrix};
+use crate::{fast_array::fast_array::FastArray, fast_matrix::fast_matrix::StorageOrder, FastError, FastMatrix};
 
 impl<T> FastIterator<T> {
+    /// ## Info
+    /// turns the elements left in the [`FastIterator`] into a [`FastArray`], reusing its buffer if nothing has been taken out of it yet.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::fast_arr;
+    ///
+    /// let mut fast_iter = fast_arr!(1, 2, 3).into_fast_iterator();
+    /// fast_iter.next();
+    ///
+    /// assert_eq!(fast_iter.into_fast_array(), fast_arr!(2, 3));
+    /// ```
+    ///
+    /// ## Panics
+    /// if the iterator has no elements left, since a [`FastArray`] can't be empty.
     pub fn into_fast_array(mut self) -> FastArray<T> {
-        let size = (self.len-self.current_index.0) - self.current_index.1;
-        let pointer = self.pointer;
+        let size = self.remaining();
+        assert!(
+            size != 0,
+            "FastIterator::into_fast_array: the iterator has no elements left, and a FastArray can't be empty!"
+        );
 
-        if self.current_index.0 == 0 && self.current_index.1 == 0 {
-            self.pointer = std::ptr::null_mut();
+        let pointer = self.buffer;
+
+        if self.is_untouched() {
+            self.buffer = std::ptr::null_mut();
             FastArray {
                 pointer,
                 size: size,
@@ -22,28 +42,144 @@ impl<T> FastIterator<T> {
         }
     }
 
-    pub fn into_fast_matrix(mut self, rows: usize, columns: usize) -> FastMatrix<T> {
-        let size = (self.len-self.current_index.0) - self.current_index.1;
+    /// ## Info
+    /// does the same thing as [`FastIterator::into_fast_array`], but returns an error instead of panicking.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::{fast_arr, FastError};
+    ///
+    /// let fast_iter = fast_arr!(1, 2, 3).into_iter_range(1..1);
+    /// assert!(matches!(fast_iter.try_into_fast_array(), Err(FastError::ZeroLength)));
+    /// ```
+    ///
+    /// ## Errors
+    /// [`FastError::ZeroLength`] if the iterator has no elements left.
+    pub fn try_into_fast_array(self) -> Result<FastArray<T>, FastError> {
+        if self.remaining() == 0 {
+            return Err(FastError::ZeroLength);
+        }
+
+        Ok(self.into_fast_array())
+    }
+
+    /// ## Info
+    /// turns the [`FastIterator`] into a row-major [`FastMatrix`], see [`FastIterator::into_fast_matrix_with_order`].
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::{fast_arr, fast_matrix};
+    ///
+    /// let fast_matrix = fast_arr!(1, 2, 3, 4, 5, 6).into_fast_iterator().into_fast_matrix(2, 3);
+    /// assert_eq!(fast_matrix, fast_matrix!([1, 2, 3], [4, 5, 6]));
+    /// ```
+    ///
+    /// ## Panics
+    /// if `rows * columns` isn't equal to the length of the iterator, or if the iterator has no elements left.
+    pub fn into_fast_matrix(self, rows: usize, columns: usize) -> FastMatrix<T> {
+        self.into_fast_matrix_with_order(rows, columns, StorageOrder::RowMajor)
+    }
+
+    /// ## Info
+    /// does the same thing as [`FastIterator::into_fast_matrix`], but returns an error instead of panicking.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::{fast_arr, FastError};
+    ///
+    /// let fast_iter = fast_arr!(1, 2, 3, 4, 5, 6).into_fast_iterator();
+    /// let error = fast_iter.try_into_fast_matrix(4, 2).unwrap_err();
+    /// assert!(matches!(error, FastError::ShapeMismatch { expected: (4, 2), found: (1, 6) }));
+    /// ```
+    ///
+    /// ## Errors
+    /// see [`FastIterator::try_into_fast_matrix_with_order`].
+    pub fn try_into_fast_matrix(self, rows: usize, columns: usize) -> Result<FastMatrix<T>, FastError> {
+        self.try_into_fast_matrix_with_order(rows, columns, StorageOrder::RowMajor)
+    }
+
+    /// ## Info
+    /// does the same thing as [`FastIterator::into_fast_matrix_with_order`], but returns an error instead of panicking.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::{fast_arr, fast_matrix, fast_matrix::fast_matrix::StorageOrder, FastError};
+    ///
+    /// let fast_iter = fast_arr!(1, 4, 2, 5, 3, 6).into_fast_iterator();
+    /// let fast_matrix = fast_iter.try_into_fast_matrix_with_order(2, 3, StorageOrder::ColumnMajor).unwrap();
+    /// assert_eq!(fast_matrix, fast_matrix!([1, 2, 3], [4, 5, 6]));
+    ///
+    /// let fast_iter = fast_arr!(1, 2).into_iter_range(1..1);
+    /// let error = fast_iter.try_into_fast_matrix_with_order(1, 1, StorageOrder::RowMajor).unwrap_err();
+    /// assert!(matches!(error, FastError::ZeroLength));
+    /// ```
+    ///
+    /// ## Errors
+    /// - [`FastError::ZeroLength`] if the iterator has no elements left.
+    /// - [`FastError::ShapeMismatch`] if `rows * columns` isn't equal to the length of the iterator, including when it overflows.
+    ///   the elements of the iterator are reported as a single row, so `found` is `(1, len)`.
+    pub fn try_into_fast_matrix_with_order(
+        self,
+        rows: usize,
+        columns: usize,
+        order: StorageOrder,
+    ) -> Result<FastMatrix<T>, FastError> {
+        let size = self.remaining();
+
+        if size == 0 {
+            return Err(FastError::ZeroLength);
+        }
+
+        if rows.checked_mul(columns) != Some(size) {
+            return Err(FastError::ShapeMismatch { expected: (rows, columns), found: (1, size) });
+        }
+
+        Ok(self.into_fast_matrix_with_order(rows, columns, order))
+    }
+
+    /// ## Info
+    /// turns the [`FastIterator`] into a [`FastMatrix`] whose elements are laid out in the given [`StorageOrder`],
+    /// so the elements of the iterator are taken as consecutive rows for [`StorageOrder::RowMajor`] and as consecutive columns for [`StorageOrder::ColumnMajor`].
+    ///
+    /// ## Panics
+    /// if `rows * columns` isn't equal to the length of the iterator, or if the iterator has no elements left.
+    pub fn into_fast_matrix_with_order(mut self, rows: usize, columns: usize, order: StorageOrder) -> FastMatrix<T> {
+        let size = self.remaining();
 
-        assert_eq!(size, rows*columns, "height*width mut be equal to the length of the iterator!");
+        // an overflowing `rows * columns` can't be the length of the iterator, so it must not wrap around to it
+        assert!(
+            rows.checked_mul(columns) == Some(size),
+            "FastIterator::into_fast_matrix: a {rows}x{columns} matrix can't hold the {size} elements of the iterator!"
+        );
+        assert!(
+            size != 0,
+            "FastIterator::into_fast_matrix: the iterator has no elements left, and a FastMatrix can't be empty!"
+        );
 
-        let pointer = self.pointer;
+        let pointer = self.buffer;
 
-        if self.current_index.0 == 0 && self.current_index.1 == 0 {
-            self.pointer = std::ptr::null_mut();
+        if self.is_untouched() {
+            self.buffer = std::ptr::null_mut();
 
             FastMatrix {
                 pointer,
                 rows, 
-                columns
+                columns,
+                order,
             }
         } else {
             let func = |_| {
                 self.next().unwrap()
             };
-            let fast_arr = FastMatrix::new_func(rows, columns, func);
+            let mut fast_matrix = match order {
+                StorageOrder::RowMajor => FastMatrix::new_func(rows, columns, func),
+                StorageOrder::ColumnMajor => FastMatrix::new_func(columns, rows, func),
+            };
+            fast_matrix.rows = rows;
+            fast_matrix.columns = columns;
+            fast_matrix.order = order;
             drop(self);
-            fast_arr
+            fast_matrix
         }
     }
 }
@@ -15,7 +15,7 @@ impl<T: Send + Sync> ParallelIterator for FastIterator<T> {
     }
 
     fn opt_len(&self) -> Option<usize> {
-        Some(self.len)
+        Some(self.remaining())
     }
 }
 
@@ -62,7 +62,7 @@ impl<T: Send + Sync> IndexedParallelIterator for FastIterator<T> {
     }
 
     fn len(&self) -> usize {
-        self.len
+        self.remaining()
     }
 }
 
@@ -78,7 +78,14 @@ impl<T: Send + Sync> Producer for FastIterator<T> {
     }
 
     fn split_at(mut self, index: usize) -> (Self, Self) {
-        let len = self.len;
+        let len = self.remaining();
+
+        // a side without elements doesn't need a buffer, and the other one can keep self as is
+        if index == 0 {
+            return (FastIterator::empty(), self);
+        } else if index == len {
+            return (self, FastIterator::empty());
+        }
         
         let mut func = || {
             self.next().unwrap()
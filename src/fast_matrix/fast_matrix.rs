@@ -1,8 +1,8 @@
-use std::{alloc::{alloc, alloc_zeroed, dealloc, handle_alloc_error, Layout}, ops::{Index, IndexMut}, ptr};
+use std::{ops::{Index, IndexMut}, ptr};
 
-use crate::{prelude::FastIterator, FastArray};
+use crate::{alloc, error::AllocError, prelude::FastIterator, FastArray, FastError};
 
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 #[repr(align(32))]
 /// ## Info
 /// A matrix, aka a 2d array with same the same width for each row.
@@ -13,17 +13,17 @@ use crate::{prelude::FastIterator, FastArray};
 /// 
 /// ## Example
 /// ```
-/// use fast_arr::FastMatrix;
-/// use fast_arr::fast_matrix;
+/// use fast_collections::FastMatrix;
+/// use fast_collections::fast_matrix;
 /// 
 /// // [
 /// //     [1,2,3],
 /// //     [4,5,6],
 /// //     [7,8,9]
 /// // ]
-/// let fast_matrix = fast_matrix!([1,2,3], [4,5,6], [7,8,9])
+/// let fast_matrix: FastMatrix<i32> = fast_matrix!([1,2,3], [4,5,6], [7,8,9]);
 /// 
-/// let element = fast_matrix[(1, 2)] // FastMatrix is indexed using a tuple of (row, column).
+/// let element = fast_matrix[(1, 2)]; // FastMatrix is indexed using a tuple of (row, column).
 /// 
 /// assert_eq!(element, 6)
 /// ```
@@ -31,6 +31,55 @@ pub struct FastMatrix<T> {
     pub(crate) pointer: *mut T,
     pub rows: usize,
     pub columns: usize,
+    pub(crate) order: StorageOrder,
+}
+
+/// ## Info
+/// the order in which the elements of a [`FastMatrix`] are laid out in memory.
+///
+/// every constructor makes row-major matrices, use [`FastMatrix::into_order`] or [`FastMatrix::to_order`] to switch between the two.
+///
+/// ## Example
+/// ```
+/// use fast_collections::fast_matrix;
+/// use fast_collections::fast_matrix::fast_matrix::StorageOrder;
+///
+/// let column_major = fast_matrix!([1, 2, 3], [4, 5, 6]).into_order(StorageOrder::ColumnMajor);
+///
+/// // same matrix, but the columns are stored one after the other
+/// assert_eq!(column_major, fast_matrix!([1, 2, 3], [4, 5, 6]));
+/// assert_eq!(column_major.iter().copied().collect::<Vec<_>>(), vec![1, 4, 2, 5, 3, 6]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StorageOrder {
+    /// the rows are stored one after the other, so the element at `(row, column)` is at `row * columns + column`.
+    #[default]
+    RowMajor,
+    /// the columns are stored one after the other, so the element at `(row, column)` is at `column * rows + row`.
+    ColumnMajor,
+}
+
+/// the number of elements of a `rows` x `columns` matrix, without letting it overflow.
+#[inline(always)]
+fn try_len(rows: usize, columns: usize) -> Result<usize, AllocError> {
+    rows.checked_mul(columns).ok_or(AllocError::CapacityOverflow)
+}
+
+/// same as [`try_len`], but panics on overflow.
+#[inline(always)]
+fn len(rows: usize, columns: usize) -> usize {
+    alloc::unwrap_alloc(try_len(rows, columns))
+}
+
+/// panics if `rows` or `columns` is 0, but only in builds with `debug_assertions` on,
+/// so misusing an `_unchecked` constructor gets caught by tests while release builds skip the check.
+#[inline(always)]
+#[track_caller]
+fn debug_check_shape(method: &str, rows: usize, columns: usize) {
+    debug_assert!(
+        rows != 0 && columns != 0,
+        "FastMatrix::{method}: rows and columns cannot be 0, found {rows}x{columns}!"
+    );
 }
 
 impl<T> FastMatrix<T> {
@@ -47,17 +96,9 @@ impl<T> FastMatrix<T> {
         assert_ne!(rows, 0, "FastMatrix: rows cannot be 0!");
         assert_ne!(columns, 0, "FastMatrix: columns cannot be 0!");
 
-        let layout = Layout::from_size_align(rows*columns * std::mem::size_of::<T>(), 32).expect("failed to create layout");
-
-        let pointer = unsafe {
-            alloc_zeroed(layout) as *mut T
-        };
-
-        if pointer.is_null() {
-            handle_alloc_error(layout);
-        }
+        let pointer = alloc::allocate_zeroed::<T>(len(rows, columns));
 
-        FastMatrix { pointer, rows, columns }
+        FastMatrix { pointer, rows, columns, order: StorageOrder::RowMajor }
     }
 
     /// ## Info
@@ -66,20 +107,11 @@ impl<T> FastMatrix<T> {
     /// if either `row == 0` or `columns == 0`, using this method becomes undefined behavior
     #[inline(always)]
     pub unsafe fn new_empty_unchecked(rows: usize, columns: usize) -> FastMatrix<T> {
-        // assert_ne!(rows, 0, "FastMatrix: rows cannot be 0!");
-        // assert_ne!(columns, 0, "FastMatrix: columns cannot be 0!");
+        debug_check_shape("new_empty_unchecked", rows, columns);
 
-        let layout = Layout::from_size_align(rows*columns * std::mem::size_of::<T>(), 32).expect("failed to create layout");
+        let pointer = alloc::allocate_zeroed::<T>(len(rows, columns));
 
-        let pointer = unsafe {
-            alloc_zeroed(layout) as *mut T
-        };
-
-        if pointer.is_null() {
-            handle_alloc_error(layout);
-        }
-
-        FastMatrix { pointer, rows, columns }
+        FastMatrix { pointer, rows, columns, order: StorageOrder::RowMajor }
     }
 
     #[inline]
@@ -88,8 +120,8 @@ impl<T> FastMatrix<T> {
     /// 
     /// ## Example
     /// ```
-    /// use fast_array::FastMatrix;
-    /// use fast_array::fast_matrix;
+    /// use fast_collections::FastMatrix;
+    /// use fast_collections::fast_matrix;
     /// 
     /// let func = |(_, column)| {
     ///     column
@@ -103,25 +135,53 @@ impl<T> FastMatrix<T> {
         F: FnMut((usize, usize)) -> T
     {
         assert_ne!(rows, 0, "FastMatrix: rows cannot be 0!");
-        assert_ne!(rows, 0, "FastMatrix: columns cannot be 0!");
+        assert_ne!(columns, 0, "FastMatrix: columns cannot be 0!");
 
-        let layout = Layout::from_size_align(rows*columns * std::mem::size_of::<T>(), 32).expect("failed to create layout");
+        let pointer = alloc::allocate_zeroed::<T>(len(rows, columns));
 
-        let pointer = unsafe {
-            alloc_zeroed(layout) as *mut T
-        };
+        for row_i in 0..rows {
+            for col_i in 0..columns {
+                unsafe { pointer.add(row_i * columns + col_i).write(func((row_i, col_i))) }
+            }
+        }
 
-        if pointer.is_null() {
-            handle_alloc_error(layout);
+        FastMatrix { pointer, rows, columns, order: StorageOrder::RowMajor }
+    }
+
+    /// ## Info
+    /// does the same thing as [`FastMatrix::new_func`], but returns an error instead of panicking.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::{fast_matrix, FastError, FastMatrix};
+    ///
+    /// let fast_matrix = FastMatrix::try_new_func(2, 2, |(row, column)| row * 2 + column).unwrap();
+    /// assert_eq!(fast_matrix, fast_matrix!([0, 1], [2, 3]));
+    ///
+    /// assert!(matches!(FastMatrix::try_new_func(0, 2, |_| 0), Err(FastError::ZeroLength)));
+    /// assert!(matches!(FastMatrix::try_new_func(usize::MAX, 2, |_| 0), Err(FastError::Alloc)));
+    /// ```
+    ///
+    /// ## Errors
+    /// - [`FastError::ZeroLength`] if `rows == 0` or `columns == 0`.
+    /// - [`FastError::Alloc`] if `rows * columns` overflows, or if the memory couldn't be allocated.
+    pub fn try_new_func<F>(rows: usize, columns: usize, mut func: F) -> Result<FastMatrix<T>, FastError>
+    where
+        F: FnMut((usize, usize)) -> T
+    {
+        if rows == 0 || columns == 0 {
+            return Err(FastError::ZeroLength);
         }
 
+        let pointer = alloc::try_allocate::<T>(try_len(rows, columns)?)?;
+
         for row_i in 0..rows {
             for col_i in 0..columns {
-                unsafe { *pointer.add(row_i * columns + col_i) = func((row_i, col_i)) }
+                unsafe { pointer.add(row_i * columns + col_i).write(func((row_i, col_i))) }
             }
         }
 
-        FastMatrix { pointer, rows, columns }
+        Ok(FastMatrix { pointer, rows, columns, order: StorageOrder::RowMajor })
     }
 
     #[inline]
@@ -133,52 +193,43 @@ impl<T> FastMatrix<T> {
     where 
         F: FnMut((usize, usize)) -> T
     {
-        // assert_ne!(rows, 0, "FastMatrix: rows cannot be 0!");
-        // assert_ne!(rows, 0, "FastMatrix: columns cannot be 0!");
+        debug_check_shape("new_func_unchecked", rows, columns);
 
-        let layout = Layout::from_size_align(rows*columns * std::mem::size_of::<T>(), 32).expect("failed to create layout");
-
-        let pointer = unsafe {
-            alloc_zeroed(layout) as *mut T
-        };
-
-        if pointer.is_null() {
-            handle_alloc_error(layout);
-        }
+        let pointer = alloc::allocate_zeroed::<T>(len(rows, columns));
 
         for row_i in 0..rows {
             for col_i in 0..columns {
-                unsafe { *pointer.add(row_i * columns + col_i) = func((row_i, col_i)) }
+                unsafe { pointer.add(row_i * columns + col_i).write(func((row_i, col_i))) }
             }
         }
 
-        FastMatrix { pointer, rows, columns }
+        FastMatrix { pointer, rows, columns, order: StorageOrder::RowMajor }
     }
     
     #[inline(always)]
     /// ## Info
     /// makes an extremely cheap conversion from [`FastMatrix`] to [`FastIterator`], consuming self.
-    /// the rows in [`FastMatrix`] get concatenated one after the other in [`FastIterator`].
+    /// the elements are yielded in storage order: for a row-major [`FastMatrix`] the rows get concatenated one after the other in [`FastIterator`], for a column-major one the columns do.
     /// 
     /// ## Example 
     /// ```
-    /// use fast_arr::fast_matrix;
+    /// use fast_collections::{fast_arr, fast_matrix};
     /// 
     /// let fast_matrix = fast_matrix!([1,2,3], [4,5,6], [7,8,9]);
     /// 
-    /// let iter = fast_matrix.into_fast_iterator();
+    /// let iter = fast_matrix.into_fast_iter();
     /// let iter2 = fast_arr!(1,2,3,4,5,6,7,8,9).into_fast_iterator();
     /// 
-    /// assert_eq!(iter, iter2);
+    /// assert!(iter.eq(iter2));
     /// ```
     pub fn into_fast_iter(mut self) -> FastIterator<T> {
         let pointer = self.pointer;
         self.pointer = ptr::null_mut();
-        FastIterator { pointer, len: self.columns*self.rows, current_index: (0, 0) }
+        unsafe { FastIterator::from_raw_parts(pointer, self.columns*self.rows) }
     }
 
     /// ## Info 
-    /// creates a new [`FastIterator`] holding a reference to each element of [`FastMatrix`] in order, from left to right and from top to bottom.
+    /// creates a new [`FastIterator`] holding a reference to each element of [`FastMatrix`] in storage order (for a row-major matrix, from left to right and from top to bottom).
     pub fn iter(&self) -> FastIterator<&T> {
         let mut index = 0;
         let func = || {
@@ -189,7 +240,7 @@ impl<T> FastMatrix<T> {
     }
 
         /// ## Info 
-    /// creates a new [`FastIterator`] holding a mutable reference to each element of [`FastMatrix`] in order, from left to right and from top to bottom.
+    /// creates a new [`FastIterator`] holding a mutable reference to each element of [`FastMatrix`] in storage order (for a row-major matrix, from left to right and from top to bottom).
     pub fn iter_mut(&mut self) -> FastIterator<&mut T> {
         let mut index = 0;
         let func = || {
@@ -208,15 +259,7 @@ impl<T: Clone> FastMatrix<T> {
         assert_ne!(rows, 0, "FastMatrix: rows cannot be 0!");
         assert_ne!(columns, 0, "FastMatrix: columns cannot be 0!");
     
-        let layout = Layout::from_size_align(rows*columns * std::mem::size_of::<T>(), 32).expect("failed to create layout");
-    
-        let pointer = unsafe {
-            alloc_zeroed(layout) as *mut T
-        };
-    
-        if pointer.is_null() {
-            panic!("FastMatrix allocation failed!");
-        }
+        let pointer = alloc::allocate_zeroed::<T>(len(rows, columns));
 
         // println!("Allocating FastMatrix at {:p} (size: {})", pointer, rows*columns);
     
@@ -224,43 +267,54 @@ impl<T: Clone> FastMatrix<T> {
             unsafe { pointer.add(i).write(fill_value.clone()) }
         }
         // println!("mnmn");
-        FastMatrix { pointer, rows, columns }
+        FastMatrix { pointer, rows, columns, order: StorageOrder::RowMajor }
+    }
+
+    /// ## Info
+    /// does the same thing as [`FastMatrix::new`], but returns an error instead of panicking.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::{fast_matrix, FastMatrix};
+    ///
+    /// assert_eq!(FastMatrix::try_new(2, 1, 7).unwrap(), fast_matrix!([7], [7]));
+    /// assert!(FastMatrix::try_new(2, 0, 7).is_err());
+    /// ```
+    ///
+    /// ## Errors
+    /// - [`FastError::ZeroLength`] if `rows == 0` or `columns == 0`.
+    /// - [`FastError::Alloc`] if `rows * columns` overflows, or if the memory couldn't be allocated.
+    pub fn try_new(rows: usize, columns: usize, fill_value: T) -> Result<FastMatrix<T>, FastError> {
+        FastMatrix::try_new_func(rows, columns, |_| fill_value.clone())
     }
 
     #[inline(always)]
     /// ## Info
     /// does the same thing as [`FastMatrix::new`], but skips the checks on `rows` and `columns` for performance reasons.
-    /// 
-    /// if either one of `rows` or `columns` is 0, using the method becomes undefined behavior.
-    pub fn new_unchecked(rows: usize, columns: usize, fill_value: T) -> FastMatrix<T> {
-        // assert_ne!(rows, 0, "FastMatrix: rows cannot be 0!");
-        // assert_ne!(columns, 0, "FastMatrix: columns cannot be 0!");
-    
-        let layout = Layout::from_size_align(rows*columns * std::mem::size_of::<T>(), 32).expect("failed to create layout");
-    
-        let pointer = unsafe {
-            alloc_zeroed(layout) as *mut T
-        };
+    ///
+    /// ## Unsafe
+    /// if either one of `rows` or `columns` is 0, using this method is undefined behavior.
+    /// builds with `debug_assertions` on panic instead.
+    pub unsafe fn new_unchecked(rows: usize, columns: usize, fill_value: T) -> FastMatrix<T> {
+        debug_check_shape("new_unchecked", rows, columns);
     
-        if pointer.is_null() {
-            handle_alloc_error(layout);
-        }
+        let pointer = alloc::allocate_zeroed::<T>(len(rows, columns));
     
         for i in 0..rows*columns {
-            unsafe { *pointer.add(i) = fill_value.clone() }
+            unsafe { pointer.add(i).write(fill_value.clone()) }
         }
     
-        FastMatrix { pointer, rows, columns }
+        FastMatrix { pointer, rows, columns, order: StorageOrder::RowMajor }
     }
 
     #[inline(always)]
     /// ## Info 
     /// turns [`FastMatrix`] into nested [`FastArray`]s.
     pub fn into_nested_arrays(self) -> FastArray<FastArray<T>> {
-        let mut fast_arr_outer = unsafe { FastArray::new_empty(self.rows) };
+        let mut fast_arr_outer: FastArray<FastArray<T>> = unsafe { FastArray::new_empty(self.rows) };
         
         for i in 0..self.rows {
-            fast_arr_outer[i] = self.get_row(i);
+            unsafe { fast_arr_outer.as_mut_ptr().add(i).write(self.get_row(i)) };
             // println!("xxx4");
         }
 
@@ -279,8 +333,8 @@ impl<T: Default> FastMatrix<T> {
     /// 
     /// ## Example
     /// ```
-    /// use fast_array::FastMatrix;
-    /// use fast_array::fast_matrix;
+    /// use fast_collections::FastMatrix;
+    /// use fast_collections::fast_matrix;
     /// 
     /// let fast_matrix = FastMatrix::new_default(2, 3);
     /// 
@@ -293,14 +347,7 @@ impl<T: Default> FastMatrix<T> {
     pub fn new_default(rows: usize, columns: usize) -> FastMatrix<T> {
         assert!(rows != 0 && columns != 0, "rows and columns cannot be 0!");
 
-        // let layout = Layout::array::<T>(len).expect("failed to create layout");
-        let layout = Layout::from_size_align(rows*columns * std::mem::size_of::<T>(), 32).expect("failed to create layout");
-
-        let raw_ptr = unsafe {
-            alloc(layout) as *mut T
-        };
-
-        if raw_ptr.is_null() { panic!("Memory alloc failed.") };
+        let raw_ptr = alloc::allocate::<T>(len(rows, columns));
 
         unsafe {
             for i in 0..rows*columns {
@@ -311,26 +358,66 @@ impl<T: Default> FastMatrix<T> {
         FastMatrix {
             pointer: raw_ptr,
             rows,
-            columns
+            columns,
+            order: StorageOrder::RowMajor,
         }
     }
 
+    /// ## Info
+    /// does the same thing as [`FastMatrix::new_default`], but returns an error instead of panicking.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::{fast_matrix, FastMatrix};
+    ///
+    /// assert_eq!(FastMatrix::<i32>::try_new_default(1, 2).unwrap(), fast_matrix!([0, 0]));
+    /// assert!(FastMatrix::<i32>::try_new_default(0, 0).is_err());
+    /// ```
+    ///
+    /// ## Errors
+    /// - [`FastError::ZeroLength`] if `rows == 0` or `columns == 0`.
+    /// - [`FastError::Alloc`] if `rows * columns` overflows, or if the memory couldn't be allocated.
+    pub fn try_new_default(rows: usize, columns: usize) -> Result<FastMatrix<T>, FastError> {
+        FastMatrix::try_new_func(rows, columns, |_| T::default())
+    }
+
+    /// ## Info
+    /// creates a new [`FastMatrix`] of `rows * columns` elements filled with the [`Default`] value of the type T, like [`FastMatrix::new_default`],
+    /// but tells why the allocation failed instead of panicking or aborting, like [`Vec::try_reserve`] does.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::{error::AllocError, fast_matrix, FastMatrix};
+    ///
+    /// assert_eq!(FastMatrix::<u8>::try_with_len(2, 1).unwrap(), fast_matrix!([0], [0]));
+    /// assert_eq!(FastMatrix::<u8>::try_with_len(usize::MAX, 3).unwrap_err(), AllocError::CapacityOverflow);
+    /// ```
+    ///
+    /// ## Errors
+    /// - [`AllocError::ZeroLength`] if `rows == 0` or `columns == 0`.
+    /// - [`AllocError::CapacityOverflow`] if `rows * columns`, or the size of the memory in bytes, overflows.
+    /// - [`AllocError::AllocFailed`] if the allocator couldn't give back the memory.
+    pub fn try_with_len(rows: usize, columns: usize) -> Result<FastMatrix<T>, AllocError> {
+        let pointer = alloc::try_allocate::<T>(try_len(rows, columns)?)?;
+
+        unsafe {
+            for i in 0..rows * columns {
+                pointer.add(i).write(T::default());
+            }
+        };
+
+        Ok(FastMatrix { pointer, rows, columns, order: StorageOrder::RowMajor })
+    }
+
     #[inline]
     /// ## Info 
     /// same as [`FastMatrix::new_default`], just doesn't do checks on `rows` and `columns` for performance reasons.
     /// 
     /// if `rows == 0` or `columns == 0`, using the method is undefined behavior. 
     pub unsafe fn new_default_unchecked(rows: usize, columns: usize) -> FastMatrix<T> {
-        assert!(rows != 0 && columns != 0, "rows and columns cannot be 0!");
-
-        // let layout = Layout::array::<T>(len).expect("failed to create layout");
-        let layout = Layout::from_size_align(rows*columns * std::mem::size_of::<T>(), 32).expect("failed to create layout");
-
-        let raw_ptr = unsafe {
-            alloc(layout) as *mut T
-        };
+        debug_check_shape("new_default_unchecked", rows, columns);
 
-        if raw_ptr.is_null() { panic!("Memory alloc failed.") };
+        let raw_ptr = alloc::allocate::<T>(len(rows, columns));
 
         unsafe {
             for i in 0..rows*columns {
@@ -341,26 +428,40 @@ impl<T: Default> FastMatrix<T> {
         FastMatrix {
             pointer: raw_ptr,
             rows,
-            columns
+            columns,
+            order: StorageOrder::RowMajor,
         }
     }
 }
 
 
+impl<T: Clone> Clone for FastMatrix<T> {
+    fn clone(&self) -> Self {
+        let size = self.rows * self.columns;
+        let pointer = alloc::allocate::<T>(size);
+
+        for i in 0..size {
+            unsafe { pointer.add(i).write((*self.pointer.add(i)).clone()) };
+        }
+
+        FastMatrix { pointer, rows: self.rows, columns: self.columns, order: self.order }
+    }
+}
+
 impl<T> Index<(usize, usize)> for FastMatrix<T> {
     type Output = T;
 
     fn index(&self, index: (usize, usize)) -> &Self::Output {
         // println!("xxx6");
         assert!(index.0 < self.rows && index.1 < self.columns);
-        unsafe { &*self.pointer.add(index.0 * self.columns + index.1) }
+        unsafe { &*self.pointer.add(self.offset(index)) }
     }
 }
 
 impl<T> IndexMut<(usize, usize)> for FastMatrix<T> {
     fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
         assert!(index.0 < self.rows && index.1 < self.columns);
-        unsafe { &mut *self.pointer.add(index.0 * self.columns + index.1) }
+        unsafe { &mut *self.pointer.add(self.offset(index)) }
     }
 }
 
@@ -380,11 +481,11 @@ impl<T> Drop for FastMatrix<T> {
         // println!("Dropping FastMatrix at {:p} (size: {} x {})", self.pointer, self.rows, self.columns);
         if !self.pointer.is_null() {
             let size = self.rows * self.columns;
-            let layout = Layout::array::<T>(size).expect("Failed to create layout");
 
             unsafe {
+                ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.pointer, size));
                 // println!("Deallocating memory at {:p} (size: {})", self.pointer, size);
-                std::alloc::dealloc(self.pointer as *mut u8, layout);
+                alloc::deallocate(self.pointer, size);
                 self.pointer = std::ptr::null_mut(); // Prevent double free
             }
         }
@@ -1,8 +1,8 @@
-use crate::FastArray;
+use crate::{FastArray, FastError};
 use std::fmt::Display;
 use std::ptr;
 
-use super::fast_matrix::FastMatrix;
+use super::fast_matrix::{FastMatrix, StorageOrder};
 
 impl<T: Display> Display for FastMatrix<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -38,7 +38,17 @@ impl<T: Display> Display for FastMatrix<T> {
 
 impl<T: PartialEq> PartialEq for FastMatrix<T> {
     fn eq(&self, other: &Self) -> bool {
-        self.iter().zip(other.iter()).all(|(a, b)| a == b)
+        if self.rows != other.rows || self.columns != other.columns {
+            return false;
+        }
+
+        if self.order == other.order {
+            self.iter().zip(other.iter()).all(|(a, b)| a == b)
+        } else {
+            (0..self.rows).all(|row| {
+                (0..self.columns).all(|column| self[(row, column)] == other[(row, column)])
+            })
+        }
     }
 
     fn ne(&self, other: &Self) -> bool {
@@ -55,7 +65,7 @@ impl<T> FastMatrix<T> {
     ///
     /// ## Example
     /// ```
-    /// use fast_array::fast_matrix;
+    /// use fast_collections::fast_matrix;
     ///
     /// let mut fast_matrix = fast_matrix!([1,2,3], [4,5,6]);
     /// fast_matrix.swap((0,0), (1,2));
@@ -80,13 +90,61 @@ impl<T> FastMatrix<T> {
         };
     }
 
+    /// ## Info
+    /// does the same thing as [`FastMatrix::swap`], but returns an error instead of panicking.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::{fast_matrix, FastError};
+    ///
+    /// let mut fast_matrix = fast_matrix!([1, 2], [3, 4]);
+    /// fast_matrix.try_swap((0, 0), (1, 1)).unwrap();
+    ///
+    /// assert_eq!(fast_matrix, fast_matrix!([4, 2], [3, 1]));
+    /// assert!(matches!(fast_matrix.try_swap((0, 0), (0, 2)), Err(FastError::OutOfBounds { index: 2, len: 2 })));
+    /// ```
+    ///
+    /// ## Errors
+    /// [`FastError::OutOfBounds`] with the first row or column that's out of bounds.
+    pub fn try_swap(&mut self, index1: (usize, usize), index2: (usize, usize)) -> Result<(), FastError> {
+        self.check_index(index1)?;
+        self.check_index(index2)?;
+
+        unsafe { self.swap_unchecked(index1, index2) };
+        Ok(())
+    }
+
+    /// panics if `index` is out of bounds, but only in builds with `debug_assertions` on,
+    /// so misusing an `_unchecked` method gets caught by tests while release builds skip the check.
+    #[inline(always)]
+    #[track_caller]
+    pub(crate) fn debug_check_index(&self, method: &str, index: (usize, usize)) {
+        debug_assert!(
+            self.rows > index.0 && self.columns > index.1,
+            "FastMatrix::{method}: index {index:?} is out of bounds for a {}x{} matrix",
+            self.rows,
+            self.columns
+        );
+    }
+
+    /// returns [`FastError::OutOfBounds`] if either the row or the column of `index` is out of bounds.
+    pub(crate) fn check_index(&self, index: (usize, usize)) -> Result<(), FastError> {
+        if index.0 >= self.rows {
+            return Err(FastError::OutOfBounds { index: index.0, len: self.rows });
+        }
+        if index.1 >= self.columns {
+            return Err(FastError::OutOfBounds { index: index.1, len: self.columns });
+        }
+        Ok(())
+    }
+
     #[inline(always)]
     /// does the same thing as [`FastMatrix::swap`], but doesn't make out of bounds condition checks on `index1` and `index2`
     ///
     /// if either one of `index1` or `index2` are out of bounds, using this function is undefined behavior.
     pub unsafe fn swap_unchecked(&mut self, index1: (usize, usize), index2: (usize, usize)) {
-        // assert!(self.rows>index1.0 && self.rows>index2.0, "FastMatrix: swap: tried to index out of bounds.");
-        // assert!(self.columns>index1.1 && self.columns>index2.1, "FastMatrix: swap: tried to index out of bounds.");
+        self.debug_check_index("swap_unchecked", index1);
+        self.debug_check_index("swap_unchecked", index2);
 
         unsafe {
             ptr::swap(
@@ -131,8 +189,8 @@ impl<T> FastMatrix<T> {
     ///
     /// if any other operation gets carried out on [`FastMatrix`] while this method is running or if either one of `index1` or `index2` are out of bounds, using this function is undefined behavior.
     pub unsafe fn swap_unchecked_unsafe(&self, index1: (usize, usize), index2: (usize, usize)) {
-        // assert!(self.rows>index1.0 && self.rows>index2.0, "FastMatrix: swap: tried to index out of bounds.");
-        // assert!(self.columns>index1.1 && self.columns>index2.1, "FastMatrix: swap: tried to index out of bounds.");
+        self.debug_check_index("swap_unchecked_unsafe", index1);
+        self.debug_check_index("swap_unchecked_unsafe", index2);
 
         unsafe {
             ptr::swap(
@@ -144,6 +202,90 @@ impl<T> FastMatrix<T> {
 }
 
 impl<T> FastMatrix<T> {
+    /// ## Info
+    /// returns a raw pointer to the first element of the [`FastMatrix`] in memory.
+    ///
+    /// the elements are laid out following [`FastMatrix::order`], and the pointer is valid for reads of `rows * columns` elements
+    /// for as long as self is alive and isn't borrowed mutably. it must never be written through: use [`FastMatrix::as_mut_ptr`] for that.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::FastMatrix;
+    ///
+    /// let fast_matrix = FastMatrix::new_func(2, 2, |(row, column)| row * 2 + column);
+    ///
+    /// assert_eq!(unsafe { *fast_matrix.as_ptr().add(3) }, 3);
+    /// ```
+    #[inline(always)]
+    pub fn as_ptr(&self) -> *const T {
+        self.pointer
+    }
+
+    /// ## Info
+    /// returns a raw mutable pointer to the first element of the [`FastMatrix`] in memory.
+    ///
+    /// the elements are laid out following [`FastMatrix::order`], and the pointer is valid for reads and writes of `rows * columns` elements
+    /// for as long as self is alive and isn't used in any other way.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::FastMatrix;
+    ///
+    /// let mut fast_matrix = FastMatrix::new(2, 2, 0);
+    /// unsafe { *fast_matrix.as_mut_ptr().add(1) = 4 };
+    ///
+    /// assert_eq!(fast_matrix[(0, 1)], 4);
+    /// ```
+    #[inline(always)]
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.pointer
+    }
+
+    /// ## Info
+    /// returns a reference to the element at `index`, without checking that it's in bounds.
+    ///
+    /// ## Unsafe
+    /// if either the row or the column of `index` is out of bounds, using this method is undefined behavior, even if the reference isn't used.
+    /// builds with `debug_assertions` on panic instead.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::FastMatrix;
+    ///
+    /// let fast_matrix = FastMatrix::new_func(2, 3, |(row, column)| row * 3 + column);
+    ///
+    /// assert_eq!(unsafe { fast_matrix.get_unchecked((1, 2)) }, &5);
+    /// ```
+    #[inline(always)]
+    pub unsafe fn get_unchecked(&self, index: (usize, usize)) -> &T {
+        self.debug_check_index("get_unchecked", index);
+
+        unsafe { &*self.pointer.add(self.offset(index)) }
+    }
+
+    /// ## Info
+    /// returns a mutable reference to the element at `index`, without checking that it's in bounds.
+    ///
+    /// ## Unsafe
+    /// if either the row or the column of `index` is out of bounds, using this method is undefined behavior, even if the reference isn't used.
+    /// builds with `debug_assertions` on panic instead.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::FastMatrix;
+    ///
+    /// let mut fast_matrix = FastMatrix::new(2, 2, 0);
+    /// unsafe { *fast_matrix.get_unchecked_mut((1, 0)) = 9 };
+    ///
+    /// assert_eq!(fast_matrix[(1, 0)], 9);
+    /// ```
+    #[inline(always)]
+    pub unsafe fn get_unchecked_mut(&mut self, index: (usize, usize)) -> &mut T {
+        self.debug_check_index("get_unchecked_mut", index);
+
+        unsafe { &mut *self.pointer.add(self.offset(index)) }
+    }
+
     #[inline(always)]
     /// ## Info
     /// gets the pointer to the given `index`.
@@ -157,16 +299,20 @@ impl<T> FastMatrix<T> {
             "FastMatrix: tried to index out of bounds."
         );
 
-        unsafe { self.pointer.add(index.calc_index(self.columns)) }
+        unsafe { self.pointer.add(self.offset(index)) }
     }
 
     #[inline(always)]
     /// ## Info
     /// does the same thing as [`FastMatrix::get_pointer`], but doesn't check for out of bounds conditions.
     ///
-    /// if `index` is out of bounds, using this method is undefined behavior.
+    /// ## Unsafe
+    /// if either the row or the column of `index` is out of bounds, using this method is undefined behavior, even if the pointer isn't used.
+    /// builds with `debug_assertions` on panic instead.
     pub unsafe fn get_pointer_unchecked(&self, index: (usize, usize)) -> *const T {
-        unsafe { self.pointer.add(index.calc_index(self.columns)) }
+        self.debug_check_index("get_pointer_unchecked", index);
+
+        unsafe { self.pointer.add(self.offset(index)) }
     }
 
     #[inline(always)]
@@ -182,16 +328,20 @@ impl<T> FastMatrix<T> {
             "FastMatrix: tried to index out of bounds."
         );
 
-        unsafe { self.pointer.add(index.calc_index(self.columns)) }
+        unsafe { self.pointer.add(self.offset(index)) }
     }
 
     #[inline(always)]
     /// ## Info
     /// does the same thing as [`FastMatrix::get_pointer_mut`], but doesn't check for out of bounds conditions.
     ///
-    /// if `index` is out of bounds, using this method is undefined behavior.
+    /// ## Unsafe
+    /// if either the row or the column of `index` is out of bounds, using this method is undefined behavior, even if the pointer isn't used.
+    /// builds with `debug_assertions` on panic instead.
     pub unsafe fn get_pointer_mut_unchecked(&mut self, index: (usize, usize)) -> *mut T {
-        unsafe { self.pointer.add(index.calc_index(self.columns)) }
+        self.debug_check_index("get_pointer_mut_unchecked", index);
+
+        unsafe { self.pointer.add(self.offset(index)) }
     }
 
     /// ## Warning
@@ -210,7 +360,7 @@ impl<T> FastMatrix<T> {
             "FastMatrix: tried to index out of bounds."
         );
 
-        unsafe { self.pointer.add(index.calc_index(self.columns)) }
+        unsafe { self.pointer.add(self.offset(index)) }
     }
 
     #[inline(always)]
@@ -220,9 +370,13 @@ impl<T> FastMatrix<T> {
     /// ## Info
     /// does the same thing as [`FastMatrix::get_pointer_mut`], but borrows self as immutable even if it returns a mutable pointer and skips the out of bounds condition checks on `index`.
     ///
-    /// if `index` is out of bounds, using this method is undefined behavior.
+    /// ## Unsafe
+    /// if either the row or the column of `index` is out of bounds, using this method is undefined behavior, even if the pointer isn't used.
+    /// builds with `debug_assertions` on panic instead.
     pub unsafe fn get_pointer_mut_unchecked_unsafe(&self, index: (usize, usize)) -> *mut T {
-        unsafe { self.pointer.add(index.calc_index(self.columns)) }
+        self.debug_check_index("get_pointer_mut_unchecked_unsafe", index);
+
+        unsafe { self.pointer.add(self.offset(index)) }
     }
 }
 
@@ -233,8 +387,8 @@ impl<T: Clone> FastMatrix<T> {
     ///
     /// ## Example
     /// ```
-    /// use fast_array::fast_arr;
-    /// use fast_array::fast_matrix;
+    /// use fast_collections::fast_arr;
+    /// use fast_collections::fast_matrix;
     ///
     /// let fast_matrix = fast_matrix!([1,2,3], [4,5,6], [7,8,9]);
     ///
@@ -247,14 +401,42 @@ impl<T: Clone> FastMatrix<T> {
         unsafe { FastArray::new_func_unchecked(self.columns, func) }
     }
 
+    /// ## Info
+    /// does the same thing as [`FastMatrix::get_row`], but returns an error instead of panicking.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::{fast_arr, fast_matrix, FastError};
+    ///
+    /// let fast_matrix = fast_matrix!([1, 2], [3, 4]);
+    ///
+    /// assert_eq!(fast_matrix.try_get_row(1).unwrap(), fast_arr!(3, 4));
+    /// assert!(matches!(fast_matrix.try_get_row(2), Err(FastError::OutOfBounds { index: 2, len: 2 })));
+    /// ```
+    ///
+    /// ## Errors
+    /// [`FastError::OutOfBounds`] if `row` is out of bounds.
+    pub fn try_get_row(&self, row: usize) -> Result<FastArray<T>, FastError> {
+        if row >= self.rows {
+            return Err(FastError::OutOfBounds { index: row, len: self.rows });
+        }
+        FastArray::try_new_func(self.columns, |column| self[(row, column)].clone())
+    }
+
     #[inline(always)]
     /// ## Info
     /// does the same thing as [`FastMatrix::get_row`], but doesn't make out of bounds checks on `row`.
     ///
-    /// if `row` is out of bounds, using this method is undefined behavior.
+    /// ## Unsafe
+    /// if `row >= self.rows`, using this method is undefined behavior.
+    /// builds with `debug_assertions` on panic instead.
     pub unsafe fn get_row_unchecked(&self, row: usize) -> FastArray<T> {
-        // assert!(self.rows>row, "FastMatrix: tried to index out of bounds.");
-        let func = |index| (&self[(row, index)]).clone();
+        debug_assert!(
+            self.rows > row,
+            "FastMatrix::get_row_unchecked: row {row} is out of bounds for {} rows",
+            self.rows
+        );
+        let func = |column| unsafe { self.get_unchecked((row, column)) }.clone();
         unsafe { FastArray::new_func_unchecked(self.columns, func) }
     }
 
@@ -264,12 +446,12 @@ impl<T: Clone> FastMatrix<T> {
     ///
     /// ## Example
     /// ```
-    /// use fast_array::fast_arr;
-    /// use fast_array::fast_matrix;
+    /// use fast_collections::fast_arr;
+    /// use fast_collections::fast_matrix;
     ///
     /// let fast_matrix = fast_matrix!([1,2,3], [4,5,6], [7,8,9]);
     ///
-    /// assert_eq!(fast_matrix.get_row(0), fast_arr!(1,4,7));
+    /// assert_eq!(fast_matrix.get_column(0), fast_arr!(1,4,7));
     /// ```
     pub fn get_column(&self, column: usize) -> FastArray<T> {
         assert!(
@@ -277,136 +459,179 @@ impl<T: Clone> FastMatrix<T> {
             "FastMatrix: tried to index out of bounds."
         );
         let func = |index| (&self[(index, column)]).clone();
-        unsafe { FastArray::new_func_unchecked(self.columns, func) }
+        unsafe { FastArray::new_func_unchecked(self.rows, func) }
+    }
+
+    /// ## Info
+    /// does the same thing as [`FastMatrix::get_column`], but returns an error instead of panicking.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::{fast_arr, fast_matrix};
+    ///
+    /// let fast_matrix = fast_matrix!([1, 2], [3, 4], [5, 6]);
+    ///
+    /// assert_eq!(fast_matrix.try_get_column(1).unwrap(), fast_arr!(2, 4, 6));
+    /// assert!(fast_matrix.try_get_column(2).is_err());
+    /// ```
+    ///
+    /// ## Errors
+    /// [`FastError::OutOfBounds`] if `column` is out of bounds.
+    pub fn try_get_column(&self, column: usize) -> Result<FastArray<T>, FastError> {
+        if column >= self.columns {
+            return Err(FastError::OutOfBounds { index: column, len: self.columns });
+        }
+        FastArray::try_new_func(self.rows, |row| self[(row, column)].clone())
     }
 
     #[inline(always)]
     /// ## Info
     /// does the same thing as [`FastMatrix::get_column`], but doesn't make out of bounds checks on `column`.
     ///
-    /// if `column` is out of bounds, using this method is undefined behavior.
-    pub fn get_column_unchecked(&self, column: usize) -> FastArray<T> {
-        // assert!(self.rows>row, "FastMatrix: tried to index out of bounds.");
-        let func = |index| (&self[(index, column)]).clone();
-        unsafe { FastArray::new_func_unchecked(self.columns, func) }
+    /// ## Unsafe
+    /// if `column >= self.columns`, using this method is undefined behavior.
+    /// builds with `debug_assertions` on panic instead.
+    pub unsafe fn get_column_unchecked(&self, column: usize) -> FastArray<T> {
+        debug_assert!(
+            self.columns > column,
+            "FastMatrix::get_column_unchecked: column {column} is out of bounds for {} columns",
+            self.columns
+        );
+        let func = |row| unsafe { self.get_unchecked((row, column)) }.clone();
+        unsafe { FastArray::new_func_unchecked(self.rows, func) }
     }
 }
 
 trait CalcIndex {
-    fn calc_index(&self, offset: usize) -> usize;
+    fn calc_index(&self, rows: usize, columns: usize, order: StorageOrder) -> usize;
 }
 
 impl CalcIndex for (usize, usize) {
-    fn calc_index(&self, offset: usize) -> usize {
-        self.0 * offset + self.1
+    #[inline(always)]
+    fn calc_index(&self, rows: usize, columns: usize, order: StorageOrder) -> usize {
+        match order {
+            StorageOrder::RowMajor => self.0 * columns + self.1,
+            StorageOrder::ColumnMajor => self.1 * rows + self.0,
+        }
     }
 }
 
-#[cfg(feature = "serde")]
-impl<T: serde::Serialize + Clone> serde::Serialize for FastMatrix<T> {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: serde::Serializer 
-    {
-        let mut state = serializer.serialize_seq(Some(self.rows*self.columns))?;
-        
-        let mut iter = self.clone().into_fast_iter_arrays();
-        
-        while let Some(row) = iter.next() {
-            serde::ser::SerializeSeq::serialize_element(&mut state, &row)?;
-        }
-
-        serde::ser::SerializeSeq::end(state)
+impl<T> FastMatrix<T> {
+    #[inline(always)]
+    /// ## Info
+    /// returns the [`StorageOrder`] the elements of the [`FastMatrix`] are laid out in.
+    pub fn order(&self) -> StorageOrder {
+        self.order
     }
-}
 
-#[cfg(feature = "serde")]
-use std::marker::PhantomData;
-#[cfg(feature = "serde")]
-impl<'de, T: serde::Deserialize<'de> + Clone> serde::Deserialize<'de> for FastMatrix<T> {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: serde::Deserializer<'de> 
-    {
-        struct FastMatrixVisitor<T> {
-            phant: PhantomData<T>
+    #[inline(always)]
+    /// ## Info
+    /// returns the `(row_stride, column_stride)` of the [`FastMatrix`], aka the distance in elements between two consecutive rows and two consecutive columns in memory.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::fast_matrix;
+    /// use fast_collections::fast_matrix::fast_matrix::StorageOrder;
+    ///
+    /// let fast_matrix = fast_matrix!([1, 2, 3], [4, 5, 6]);
+    /// assert_eq!(fast_matrix.strides(), (3, 1));
+    ///
+    /// let fast_matrix = fast_matrix.into_order(StorageOrder::ColumnMajor);
+    /// assert_eq!(fast_matrix.strides(), (1, 2));
+    /// ```
+    pub fn strides(&self) -> (usize, usize) {
+        match self.order {
+            StorageOrder::RowMajor => (self.columns, 1),
+            StorageOrder::ColumnMajor => (1, self.rows),
         }
+    }
 
-        impl<'de, U: serde::Deserialize<'de> + Clone> serde::de::Visitor<'de> for FastMatrixVisitor<U> {
-            type Value = FastMatrix<U>;
-        
-            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-                formatter.write_str("expecting an array of arrays.")
-            }
-
-            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
-            where
-                A: serde::de::SeqAccess<'de>, 
-            {
-                // let _len = seq.size_hint().unwrap_or({
-                    let mut vec = Vec::new();
-
-                    while let Some(item) = seq.next_element()? {
-                        vec.push(item);
-                    }
-
-                    return Ok(vec.into())
-                // });
-
-                // if _len == 1 {
-                //     let mut vec = Vec::new();
-
-                //     while let Some(item) = seq.next_element()? {
-                //         vec.push(item);
-                //     }
-
-                //     return Ok(vec.into())
-                // }
-
-                // let iter = |_| seq.next_element::<U>();
-
-                // FastMatrix::new_func(_len, iter);
-            }
+    /// the dimensions of the buffer seen as a row-major matrix: `(rows, columns)` for row-major storage, `(columns, rows)` for column-major storage.
+    #[inline(always)]
+    pub(crate) fn storage_shape(&self) -> (usize, usize) {
+        match self.order {
+            StorageOrder::RowMajor => (self.rows, self.columns),
+            StorageOrder::ColumnMajor => (self.columns, self.rows),
         }
+    }
 
-        deserializer.deserialize_seq(FastMatrixVisitor::<T> { phant: PhantomData::<T>})
+    /// the position of `index` in the buffer, according to the storage order.
+    #[inline(always)]
+    pub(crate) fn offset(&self, index: (usize, usize)) -> usize {
+        index.calc_index(self.rows, self.columns, self.order)
     }
 }
 
-impl<T: Clone> From<Vec<Vec<T>>> for FastMatrix<T> {
-    fn from(value: Vec<Vec<T>>) -> Self {
+impl<T> FastMatrix<T> {
+    /// ## Info
+    /// creates a [`FastMatrix`] from a [`Vec`] of rows, moving the elements instead of cloning them.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::{fast_matrix, FastError, FastMatrix};
+    ///
+    /// let fast_matrix = FastMatrix::try_from_nested(vec![vec![1, 2], vec![3, 4]]).unwrap();
+    /// assert_eq!(fast_matrix, fast_matrix!([1, 2], [3, 4]));
+    ///
+    /// let ragged = FastMatrix::try_from_nested(vec![vec![1, 2], vec![3]]);
+    /// assert!(matches!(ragged, Err(FastError::RaggedRows { row: 1 })));
+    /// ```
+    ///
+    /// ## Errors
+    /// - [`FastError::ZeroLength`] if there are no rows, or if the rows are empty.
+    /// - [`FastError::RaggedRows`] with the first row that doesn't have as many elements as the first one.
+    /// - [`FastError::Alloc`] if the memory couldn't be allocated.
+    pub fn try_from_nested(value: Vec<Vec<T>>) -> Result<FastMatrix<T>, FastError> {
         let rows = value.len();
+        let columns = value.first().map_or(0, Vec::len);
 
-        let mut first = true;
-        let mut first_val = 0;
-
-        value.iter().for_each(|x| {
-            if first {
-                first = false;
-                first_val = x.len();
-            } else {
-                assert_eq!(first_val, x.len(), "FastMatrix: the inner vectors aren't all the same size");
-            }
-        });
-
-        let columns = first_val;
+        if let Some(row) = value.iter().position(|row| row.len() != columns) {
+            return Err(FastError::RaggedRows { row });
+        }
 
-        let mut fast_matrix: FastMatrix<T> = unsafe {
-            FastMatrix::new_empty(rows, columns)
-        };
+        let mut elements = value.into_iter().flatten();
+        FastMatrix::try_new_func(rows, columns, |_| elements.next().unwrap())
+    }
+}
 
-        for x in 0..rows {
-            for y in 0..columns {
-                fast_matrix[(x,y)] = value.clone()[x][y].clone()
-            }
+impl<T> From<Vec<Vec<T>>> for FastMatrix<T> {
+    /// ## Panics
+    /// in the same cases [`FastMatrix::try_from_nested`] returns an error.
+    fn from(value: Vec<Vec<T>>) -> Self {
+        match FastMatrix::try_from_nested(value) {
+            Ok(fast_matrix) => fast_matrix,
+            Err(error) => panic!("FastMatrix: {error}"),
         }
-
-        fast_matrix
     }
 }
 
 pub trait IntoFastMatrix<T> {
     fn into_fast_matrix(self, rows: usize, columns: usize) -> FastMatrix<T>;
+
+    /// ## Info
+    /// does the same thing as [`IntoFastMatrix::into_fast_matrix`], but returns an error instead of panicking.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::{fast_arr, fast_matrix, prelude::IntoFastMatrix, FastError};
+    ///
+    /// let rows = [fast_arr!(1, 2), fast_arr!(3, 4)];
+    /// let fast_matrix = rows.clone().into_iter().try_into_fast_matrix(2, 2).unwrap();
+    /// assert_eq!(fast_matrix, fast_matrix!([1, 2], [3, 4]));
+    ///
+    /// let error = rows.into_iter().try_into_fast_matrix(3, 2).unwrap_err();
+    /// assert!(matches!(error, FastError::ShapeMismatch { expected: (3, 2), found: (2, 2) }));
+    ///
+    /// let ragged = [fast_arr!(1, 2), fast_arr!(3)];
+    /// assert!(matches!(ragged.into_iter().try_into_fast_matrix(1, 3), Err(FastError::RaggedRows { row: 1 })));
+    /// ```
+    ///
+    /// ## Errors
+    /// - [`FastError::ZeroLength`] if there are no rows.
+    /// - [`FastError::RaggedRows`] with the first row that doesn't have as many elements as the first one.
+    /// - [`FastError::ShapeMismatch`] if `rows * columns` isn't the number of elements, including when it overflows.
+    /// - [`FastError::Alloc`] if the memory couldn't be allocated.
+    fn try_into_fast_matrix(self, rows: usize, columns: usize) -> Result<FastMatrix<T>, FastError>;
 }
 
 impl<T, I: ExactSizeIterator<Item = FastArray<T>>, >  IntoFastMatrix<T> for I {
@@ -445,11 +670,33 @@ impl<T, I: ExactSizeIterator<Item = FastArray<T>>, >  IntoFastMatrix<T> for I {
                 row.into_fast_iterator()
             }));
     
-        let len = out_len * len_inner;
-        assert_eq!(len, rows * columns, "Matrix dimensions mismatch: expected {} elements but got {}!", rows * columns, len);
+        let len = out_len.checked_mul(len_inner);
+        assert!(
+            len.is_some() && rows.checked_mul(columns) == len,
+            "Matrix dimensions mismatch: a {rows}x{columns} matrix can't hold {out_len} rows of {len_inner} elements!"
+        );
     
         let func = |_| flat.next().expect("Iterator ran out of elements unexpectedly!");
     
         unsafe { FastMatrix::new_func_unchecked(rows, columns, func) }
     }
+
+    fn try_into_fast_matrix(self, rows: usize, columns: usize) -> Result<FastMatrix<T>, FastError> {
+        // the rows are gathered first, so that a ragged one is found before any element is moved
+        let arrays: Vec<FastArray<T>> = self.collect();
+        let columns_found = arrays.first().ok_or(FastError::ZeroLength)?.len();
+
+        if let Some(row) = arrays.iter().position(|row| row.len() != columns_found) {
+            return Err(FastError::RaggedRows { row });
+        }
+
+        let found = (arrays.len(), columns_found);
+        let len = found.0.checked_mul(found.1);
+        if len.is_none() || rows.checked_mul(columns) != len {
+            return Err(FastError::ShapeMismatch { expected: (rows, columns), found });
+        }
+
+        let mut elements = arrays.into_iter().flat_map(FastArray::into_fast_iterator);
+        FastMatrix::try_new_func(rows, columns, |_| elements.next().unwrap())
+    }
 }
\ No newline at end of file
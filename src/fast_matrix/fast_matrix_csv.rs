@@ -0,0 +1,454 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    io::{self, BufRead, Write},
+    str::FromStr,
+};
+
+use crate::{fast_array::fast_array_builder::FastArrayBuilder, FastMatrix};
+
+/// ## Info
+/// what to do with empty cells (and cells listed in [`CsvOptions::missing`]) when reading a csv file.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum EmptyCells<T> {
+    /// fail with [`CsvError::EmptyCell`].
+    #[default]
+    Error,
+    /// use the given value instead, like `f64::NAN`.
+    Fill(T),
+}
+
+/// ## Info
+/// the options used to read and write csv (or any other delimited text) files.
+///
+/// ## Example
+/// ```
+/// use fast_collections::fast_matrix::fast_matrix_csv::{CsvOptions, EmptyCells};
+///
+/// // tab separated, with a header row, and missing values read as NaN
+/// let options = CsvOptions {
+///     delimiter: b'\t',
+///     has_header: true,
+///     empty_cells: EmptyCells::Fill(f64::NAN),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsvOptions<T> {
+    /// the byte separating the cells of a row, `b','` by default. it must be ascii.
+    pub delimiter: u8,
+    /// the byte used to quote cells containing delimiters, quotes or new lines, `b'"'` by default. it must be ascii.
+    /// inside a quoted cell, the quote is escaped by doubling it.
+    pub quote: u8,
+    /// if the first row holds the column names instead of values, `false` by default.
+    pub has_header: bool,
+    /// if the whitespace around unquoted cells is ignored, `true` by default.
+    pub trim: bool,
+    /// what to do with empty cells, [`EmptyCells::Error`] by default.
+    pub empty_cells: EmptyCells<T>,
+    /// the cells that are considered empty when reading, like `"NA"`, none by default.
+    pub missing: Vec<String>,
+    /// the text written for elements that aren't equal to themselves (aka NaN), `"NaN"` by default.
+    pub nan: String,
+}
+
+impl<T> Default for CsvOptions<T> {
+    fn default() -> Self {
+        CsvOptions {
+            delimiter: b',',
+            quote: b'"',
+            has_header: false,
+            trim: true,
+            empty_cells: EmptyCells::Error,
+            missing: Vec::new(),
+            nan: "NaN".to_string(),
+        }
+    }
+}
+
+/// ## Info
+/// everything that can go wrong while reading a csv file.
+///
+/// lines and columns start at 1, and a line is the one where the row starts.
+#[derive(Debug)]
+pub enum CsvError {
+    /// reading failed, or the data isn't valid utf-8.
+    Io(io::Error),
+    /// a cell couldn't be parsed as `T`.
+    Parse { line: usize, column: usize, cell: String },
+    /// a row doesn't have as many cells as the first one.
+    RaggedRow { line: usize, expected: usize, found: usize },
+    /// a cell is empty, and [`CsvOptions::empty_cells`] is [`EmptyCells::Error`].
+    EmptyCell { line: usize, column: usize },
+    /// a quoted cell is never closed.
+    UnterminatedQuote { line: usize, column: usize },
+    /// there are no rows of values.
+    Empty,
+}
+
+impl Display for CsvError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CsvError::Io(error) => write!(f, "csv: {error}"),
+            CsvError::Parse { line, column, cell } => {
+                write!(f, "csv: line {line}, column {column}: couldn't parse '{cell}'")
+            }
+            CsvError::RaggedRow { line, expected, found } => {
+                write!(f, "csv: line {line}: expected {expected} cells, found {found}")
+            }
+            CsvError::EmptyCell { line, column } => write!(f, "csv: line {line}, column {column}: empty cell"),
+            CsvError::UnterminatedQuote { line, column } => {
+                write!(f, "csv: line {line}, column {column}: unterminated quote")
+            }
+            CsvError::Empty => write!(f, "csv: there are no rows of values"),
+        }
+    }
+}
+
+impl Error for CsvError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CsvError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for CsvError {
+    fn from(value: io::Error) -> Self {
+        CsvError::Io(value)
+    }
+}
+
+/// reads the records of a csv file one by one, reusing the same buffers.
+struct Records<R: BufRead> {
+    reader: R,
+    delimiter: u8,
+    quote: u8,
+    trim: bool,
+    /// the line the next record starts on.
+    line: usize,
+    buffer: Vec<u8>,
+}
+
+/// a cell of a record, and whether it was quoted.
+struct Cell {
+    text: String,
+    quoted: bool,
+}
+
+/// checks that the delimiter and the quote are ascii, since any other byte is part of a multi-byte utf-8 character.
+fn check_options<T>(options: &CsvOptions<T>) -> io::Result<()> {
+    if !options.delimiter.is_ascii() || !options.quote.is_ascii() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "csv: the delimiter ({:#04x}) and the quote ({:#04x}) must be ascii",
+                options.delimiter, options.quote
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+impl<R: BufRead> Records<R> {
+    /// reads the next non-blank record into `cells`, and returns the line it starts on, or `None` at the end of the file.
+    fn next(&mut self, cells: &mut Vec<Cell>) -> Result<Option<usize>, CsvError> {
+        loop {
+            cells.clear();
+            self.buffer.clear();
+
+            if self.reader.read_until(b'\n', &mut self.buffer)? == 0 {
+                return Ok(None);
+            }
+
+            let start = self.line;
+            self.line += 1;
+
+            if self.buffer.iter().all(u8::is_ascii_whitespace) {
+                continue;
+            }
+
+            let mut cell = Vec::new();
+            let mut quoted = false;
+            let mut in_quotes = false;
+            let mut position = 0;
+
+            loop {
+                // quoted cells can span several lines
+                if position == self.buffer.len() {
+                    if !in_quotes {
+                        break;
+                    }
+
+                    if self.reader.read_until(b'\n', &mut self.buffer)? == 0 {
+                        return Err(CsvError::UnterminatedQuote { line: start, column: cells.len() + 1 });
+                    }
+                    self.line += 1;
+                    continue;
+                }
+
+                let byte = self.buffer[position];
+                position += 1;
+
+                if in_quotes {
+                    if byte != self.quote {
+                        cell.push(byte);
+                    } else if self.buffer.get(position) == Some(&self.quote) {
+                        cell.push(byte);
+                        position += 1;
+                    } else {
+                        in_quotes = false;
+                    }
+                } else if byte == self.delimiter {
+                    cells.push(self.cell(std::mem::take(&mut cell), quoted)?);
+                    quoted = false;
+                } else if byte == self.quote && !quoted && cell.iter().all(u8::is_ascii_whitespace) {
+                    cell.clear();
+                    quoted = true;
+                    in_quotes = true;
+                } else if !(quoted && byte.is_ascii_whitespace()) {
+                    // the whitespace after a closing quote (including the line ending) is ignored
+                    cell.push(byte);
+                }
+            }
+
+            // the line ending isn't part of the last cell
+            if !quoted {
+                while cell.last().is_some_and(|&byte| byte == b'\n' || byte == b'\r') {
+                    cell.pop();
+                }
+            }
+            cells.push(self.cell(cell, quoted)?);
+
+            return Ok(Some(start));
+        }
+    }
+
+    fn cell(&self, bytes: Vec<u8>, quoted: bool) -> Result<Cell, CsvError> {
+        let text = String::from_utf8(bytes)
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, format!("line {}: invalid utf-8", self.line)))?;
+
+        let text = match self.trim && !quoted {
+            true => text.trim().to_string(),
+            false => text,
+        };
+
+        Ok(Cell { text, quoted })
+    }
+}
+
+impl<T: FromStr + Clone> FastMatrix<T> {
+    /// ## Info
+    /// reads a csv file into a [`FastMatrix`], see [`CsvOptions`] for the available options.
+    ///
+    /// the file is read row by row into a buffer that grows as needed, which then becomes the matrix without being copied.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::{fast_matrix, FastMatrix};
+    /// use fast_collections::fast_matrix::fast_matrix_csv::{CsvError, CsvOptions};
+    ///
+    /// let csv = "x,y\n1,2\n3,4\n";
+    /// let options = CsvOptions { has_header: true, ..Default::default() };
+    ///
+    /// let fast_matrix = FastMatrix::<i32>::from_csv(csv.as_bytes(), &options).unwrap();
+    /// assert_eq!(fast_matrix, fast_matrix!([1, 2], [3, 4]));
+    ///
+    /// // the second row has a missing cell
+    /// let error = FastMatrix::<i32>::from_csv("1,2\n3\n".as_bytes(), &CsvOptions::default()).unwrap_err();
+    /// assert!(matches!(error, CsvError::RaggedRow { line: 2, expected: 2, found: 1 }));
+    ///
+    /// // 0xa7 isn't ascii, it would split the utf-8 characters of the file
+    /// let options = CsvOptions { delimiter: 0xa7, ..Default::default() };
+    /// let error = FastMatrix::<i32>::from_csv("1\u{a7}2\n".as_bytes(), &options).unwrap_err();
+    /// assert!(matches!(error, CsvError::Io(error) if error.kind() == std::io::ErrorKind::InvalidInput));
+    /// ```
+    ///
+    /// ## Errors
+    /// see [`CsvError`]. a delimiter or a quote that isn't ascii is a [`CsvError::Io`] of kind [`io::ErrorKind::InvalidInput`].
+    pub fn from_csv(reader: impl BufRead, options: &CsvOptions<T>) -> Result<FastMatrix<T>, CsvError> {
+        FastMatrix::from_csv_with_header(reader, options).map(|(_, fast_matrix)| fast_matrix)
+    }
+
+    /// ## Info
+    /// does the same as [`FastMatrix::from_csv`], but also returns the header row when [`CsvOptions::has_header`] is set.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::{fast_matrix, FastMatrix};
+    /// use fast_collections::fast_matrix::fast_matrix_csv::{CsvOptions, EmptyCells};
+    ///
+    /// let csv = "\"name, with comma\";b\n1.5;\n;NA\n";
+    /// let options = CsvOptions {
+    ///     delimiter: b';',
+    ///     has_header: true,
+    ///     empty_cells: EmptyCells::Fill(-1.0),
+    ///     missing: vec!["NA".to_string()],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let (header, fast_matrix) = FastMatrix::<f64>::from_csv_with_header(csv.as_bytes(), &options).unwrap();
+    ///
+    /// assert_eq!(header, Some(vec!["name, with comma".to_string(), "b".to_string()]));
+    /// assert_eq!(fast_matrix, fast_matrix!([1.5, -1.0], [-1.0, -1.0]));
+    /// ```
+    ///
+    /// ## Errors
+    /// see [`CsvError`].
+    pub fn from_csv_with_header(
+        reader: impl BufRead,
+        options: &CsvOptions<T>,
+    ) -> Result<(Option<Vec<String>>, FastMatrix<T>), CsvError> {
+        check_options(options)?;
+
+        let mut records = Records {
+            reader,
+            delimiter: options.delimiter,
+            quote: options.quote,
+            trim: options.trim,
+            line: 1,
+            buffer: Vec::new(),
+        };
+        let mut cells = Vec::new();
+
+        let header = match options.has_header {
+            true => records
+                .next(&mut cells)?
+                .map(|_| cells.drain(..).map(|cell| cell.text).collect::<Vec<_>>()),
+            false => None,
+        };
+
+        // if a cell fails, the builder drops the elements before it
+        let mut elements = FastArrayBuilder::with_capacity(0);
+        let mut rows = 0;
+        let mut columns = None;
+
+        while let Some(line) = records.next(&mut cells)? {
+            let expected = *columns.get_or_insert(cells.len());
+            if cells.len() != expected {
+                return Err(CsvError::RaggedRow { line, expected, found: cells.len() });
+            }
+            rows += 1;
+
+            for (index, cell) in cells.iter().enumerate() {
+                let column = index + 1;
+                let empty = (cell.text.is_empty() && !cell.quoted) || options.missing.contains(&cell.text);
+
+                let element = match (empty, &options.empty_cells) {
+                    (true, EmptyCells::Error) => return Err(CsvError::EmptyCell { line, column }),
+                    (true, EmptyCells::Fill(value)) => value.clone(),
+                    (false, _) => cell
+                        .text
+                        .parse()
+                        .map_err(|_| CsvError::Parse { line, column, cell: cell.text.clone() })?,
+                };
+
+                elements.push(element);
+            }
+        }
+
+        let columns = columns.ok_or(CsvError::Empty)?;
+        // the buffer has exactly rows * columns elements, so it becomes the matrix as is
+        let fast_matrix = elements
+            .finish()
+            .ok_or(CsvError::Empty)?
+            .into_fast_iterator()
+            .into_fast_matrix(rows, columns);
+
+        Ok((header, fast_matrix))
+    }
+}
+
+impl<T: Display + PartialEq> FastMatrix<T> {
+    /// ## Info
+    /// writes the [`FastMatrix`] as csv, row by row, preceded by `header` if there's one.
+    ///
+    /// cells containing the delimiter, the quote or new lines (or surrounded by whitespace, when trimming) are quoted, and elements that aren't equal to themselves (aka NaN) are written as [`CsvOptions::nan`].
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::fast_matrix;
+    /// use fast_collections::fast_matrix::fast_matrix_csv::CsvOptions;
+    ///
+    /// let mut csv = Vec::new();
+    /// fast_matrix!([1.5, f64::NAN], [3.0, 4.0])
+    ///     .to_csv(&mut csv, Some(&["a", "b,c"]), &CsvOptions::default())
+    ///     .unwrap();
+    ///
+    /// assert_eq!(String::from_utf8(csv).unwrap(), "a,\"b,c\"\n1.5,NaN\n3,4\n");
+    /// ```
+    ///
+    /// ## Errors
+    /// if writing fails, or if `header` doesn't have one name per column or the delimiter or the quote isn't ascii ([`io::ErrorKind::InvalidInput`]).
+    pub fn to_csv(&self, mut writer: impl Write, header: Option<&[&str]>, options: &CsvOptions<T>) -> io::Result<()> {
+        check_options(options)?;
+
+        let mut line = String::new();
+
+        if let Some(header) = header {
+            if header.len() != self.columns {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("csv: the header has {} names for {} columns", header.len(), self.columns),
+                ));
+            }
+
+            for (column, name) in header.iter().enumerate() {
+                push_cell(&mut line, name, column, options);
+            }
+            line.push('\n');
+            writer.write_all(line.as_bytes())?;
+        }
+
+        for row in 0..self.rows {
+            line.clear();
+
+            for column in 0..self.columns {
+                let element = &self[(row, column)];
+
+                #[allow(clippy::eq_op)]
+                let text = match element != element {
+                    true => options.nan.clone(),
+                    false => element.to_string(),
+                };
+                push_cell(&mut line, &text, column, options);
+            }
+
+            line.push('\n');
+            writer.write_all(line.as_bytes())?;
+        }
+
+        writer.flush()
+    }
+}
+
+/// appends a cell to a csv line, quoting it if needed.
+///
+/// the delimiter and the quote have been checked to be ascii, so they are whole characters.
+fn push_cell<T>(line: &mut String, text: &str, column: usize, options: &CsvOptions<T>) {
+    if column != 0 {
+        line.push(options.delimiter as char);
+    }
+
+    let quote = options.quote as char;
+    let needs_quotes = text
+        .bytes()
+        .any(|byte| byte == options.delimiter || byte == options.quote || byte == b'\n' || byte == b'\r')
+        // the whitespace around unquoted cells would be lost when reading them back
+        || (options.trim && text.trim() != text);
+
+    if needs_quotes {
+        line.push(quote);
+        for character in text.chars() {
+            if character == quote {
+                line.push(quote);
+            }
+            line.push(character);
+        }
+        line.push(quote);
+    } else {
+        line.push_str(text);
+    }
+}
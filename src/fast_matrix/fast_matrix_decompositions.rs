@@ -0,0 +1,415 @@
+use std::{
+    fmt::{Debug, Display},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use crate::{FastArray, FastMatrix};
+
+use super::fast_matrix::StorageOrder;
+
+/// ## Info
+/// floating point types that the decompositions of [`FastMatrix`] can work with.
+///
+/// it's implemented for [`f32`] and [`f64`].
+pub trait Float:
+    Copy
+    + Debug
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const EPSILON: Self;
+    const INFINITY: Self;
+
+    fn sqrt(self) -> Self;
+    fn abs(self) -> Self;
+    fn from_f64(value: f64) -> Self;
+}
+
+macro_rules! impl_float {
+    ($($float:ty),+) => {
+        $(
+            impl Float for $float {
+                const ZERO: Self = 0.0;
+                const ONE: Self = 1.0;
+                const EPSILON: Self = <$float>::EPSILON;
+                const INFINITY: Self = <$float>::INFINITY;
+
+                #[inline(always)]
+                fn sqrt(self) -> Self {
+                    <$float>::sqrt(self)
+                }
+
+                #[inline(always)]
+                fn abs(self) -> Self {
+                    <$float>::abs(self)
+                }
+
+                #[inline(always)]
+                fn from_f64(value: f64) -> Self {
+                    value as $float
+                }
+            }
+        )+
+    };
+}
+
+impl_float!(f32, f64);
+
+/// ## Info
+/// the reasons a decomposition or a solver of [`FastMatrix`] can fail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecompositionError {
+    /// the operation needs a square matrix.
+    NotSquare { rows: usize, columns: usize },
+    /// the matrix isn't symmetric; `row` and `column` are the first mismatching pair found.
+    NotSymmetric { row: usize, column: usize },
+    /// the matrix isn't positive-definite; `pivot` is the diagonal element where this was found.
+    NotPositiveDefinite { pivot: usize },
+    /// the matrix has fewer rows than columns, so the system has no unique least-squares solution.
+    Underdetermined { rows: usize, columns: usize },
+    /// the matrix doesn't have full column rank; `column` is a column that depends on the others.
+    RankDeficient { column: usize },
+    /// the length of the right-hand side doesn't match the number of rows of the matrix.
+    ShapeMismatch { expected: usize, found: usize },
+}
+
+impl Display for DecompositionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DecompositionError::NotSquare { rows, columns } => {
+                write!(f, "FastMatrix: expected a square matrix, found {rows}x{columns}")
+            }
+            DecompositionError::NotSymmetric { row, column } => write!(
+                f,
+                "FastMatrix: the matrix isn't symmetric at ({row}, {column})"
+            ),
+            DecompositionError::NotPositiveDefinite { pivot } => write!(
+                f,
+                "FastMatrix: the matrix isn't positive-definite (pivot {pivot})"
+            ),
+            DecompositionError::Underdetermined { rows, columns } => write!(
+                f,
+                "FastMatrix: a {rows}x{columns} system is underdetermined"
+            ),
+            DecompositionError::RankDeficient { column } => write!(
+                f,
+                "FastMatrix: the matrix is rank deficient (column {column})"
+            ),
+            DecompositionError::ShapeMismatch { expected, found } => write!(
+                f,
+                "FastMatrix: expected a right-hand side of length {expected}, found {found}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for DecompositionError {}
+
+impl<T: Float> FastMatrix<T> {
+    /// ## Info
+    /// computes the QR decomposition of self using Householder reflections, returning `(Q, R)`.
+    ///
+    /// for a `m x n` matrix, `Q` is an orthogonal `m x m` matrix and `R` is an upper triangular `m x n` matrix, so that `Q * R == self`.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::fast_matrix;
+    ///
+    /// let fast_matrix = fast_matrix!([12.0, -51.0, 4.0], [6.0, 167.0, -68.0], [-4.0, 24.0, -41.0]);
+    /// let (q, r) = fast_matrix.qr();
+    ///
+    /// for row in 0..3 {
+    ///     for column in 0..3 {
+    ///         let element: f64 = (0..3).map(|k| q[(row, k)] * r[(k, column)]).sum();
+    ///         assert!((element - fast_matrix[(row, column)]).abs() < 1e-9);
+    ///     }
+    /// }
+    ///
+    /// assert!(r[(1, 0)].abs() < 1e-12 && r[(2, 0)].abs() < 1e-12 && r[(2, 1)].abs() < 1e-12);
+    /// ```
+    pub fn qr(&self) -> (FastMatrix<T>, FastMatrix<T>) {
+        let (q, r, _) = self.householder(false);
+        (q, r)
+    }
+
+    /// computes the QR decomposition of self, returning `(Q, R, permutation)`.
+    ///
+    /// with `pivoting`, the column of `R` with the largest remaining norm is swapped in before each reflection,
+    /// so that `self[.., permutation[k]] == (Q * R)[.., k]` and the diagonal of `R` is decreasing in magnitude.
+    /// without it, the permutation is the identity.
+    fn householder(&self, pivoting: bool) -> (FastMatrix<T>, FastMatrix<T>, FastArray<usize>) {
+        let m = self.rows;
+        let n = self.columns;
+
+        // the reflections below index the buffer as row-major
+        let mut r = self.to_order(StorageOrder::RowMajor);
+        let q = identity::<T>(m);
+        let mut v = FastArray::new(m, T::ZERO);
+        let mut permutation = FastArray::new_func(n, |index| index);
+
+        for k in 0..n.min(m) {
+            if pivoting {
+                let mut pivot = k;
+                let mut largest_norm = T::ZERO;
+                for j in k..n {
+                    let mut norm = T::ZERO;
+                    for i in k..m {
+                        let element = unsafe { *r.pointer.add(i * n + j) };
+                        norm += element * element;
+                    }
+                    if norm > largest_norm {
+                        pivot = j;
+                        largest_norm = norm;
+                    }
+                }
+
+                if pivot != k {
+                    unsafe { r.swap_columns_unchecked(k, pivot) };
+                    permutation.swap(k, pivot);
+                }
+            }
+
+            // the last row has nothing below the diagonal to eliminate
+            if k == m - 1 {
+                break;
+            }
+
+            let mut norm = T::ZERO;
+            for i in k..m {
+                let element = unsafe { *r.pointer.add(i * n + k) };
+                norm += element * element;
+            }
+            let norm = norm.sqrt();
+
+            if norm == T::ZERO {
+                continue;
+            }
+
+            let diagonal = unsafe { *r.pointer.add(k * n + k) };
+            let alpha = if diagonal > T::ZERO { -norm } else { norm };
+
+            // v = x - alpha * e1, stored in v[k..m]
+            let mut v_norm = T::ZERO;
+            for i in k..m {
+                let element = unsafe { *r.pointer.add(i * n + k) };
+                v[i] = if i == k { element - alpha } else { element };
+                v_norm += v[i] * v[i];
+            }
+
+            if v_norm == T::ZERO {
+                continue;
+            }
+
+            let two = T::from_f64(2.0);
+
+            // R = H * R, only the trailing columns are affected
+            for j in k + 1..n {
+                let mut dot = T::ZERO;
+                for i in k..m {
+                    dot += v[i] * unsafe { *r.pointer.add(i * n + j) };
+                }
+                let factor = two * dot / v_norm;
+                for i in k..m {
+                    unsafe { *r.pointer.add(i * n + j) -= factor * v[i] };
+                }
+            }
+
+            unsafe { *r.pointer.add(k * n + k) = alpha };
+            for i in k + 1..m {
+                unsafe { *r.pointer.add(i * n + k) = T::ZERO };
+            }
+
+            // Q = Q * H
+            for row in 0..m {
+                let mut dot = T::ZERO;
+                for i in k..m {
+                    dot += unsafe { *q.pointer.add(row * m + i) } * v[i];
+                }
+                let factor = two * dot / v_norm;
+                for i in k..m {
+                    unsafe { *q.pointer.add(row * m + i) -= factor * v[i] };
+                }
+            }
+        }
+
+        (q, r, permutation)
+    }
+
+    /// ## Info
+    /// solves the least-squares problem `min ||self * x - rhs||` through the QR decomposition of self, returning `x`.
+    ///
+    /// self must have at least as many rows as columns and full column rank, and `rhs` must be as long as the number of rows.
+    /// the columns are pivoted during the decomposition, so that a rank deficient matrix is reliably caught instead of giving a meaningless solution.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::{fast_arr, fast_matrix};
+    ///
+    /// // fit y = a + b * x through (0, 1), (1, 3), (2, 5)
+    /// let fast_matrix = fast_matrix!([1.0, 0.0], [1.0, 1.0], [1.0, 2.0]);
+    /// let solution = fast_matrix.least_squares(&fast_arr!(1.0, 3.0, 5.0)).unwrap();
+    /// let (a, b): (f64, f64) = (solution[0], solution[1]);
+    ///
+    /// assert!((a - 1.0).abs() < 1e-12);
+    /// assert!((b - 2.0).abs() < 1e-12);
+    ///
+    /// // the second column is twice the first one
+    /// let rank_deficient = fast_matrix!([1.0, 2.0], [2.0, 4.0], [3.0, 6.0]);
+    /// assert!(rank_deficient.least_squares(&fast_arr!(1.0, 2.0, 3.0)).is_err());
+    /// ```
+    pub fn least_squares(&self, rhs: &FastArray<T>) -> Result<FastArray<T>, DecompositionError> {
+        let m = self.rows;
+        let n = self.columns;
+
+        if rhs.len() != m {
+            return Err(DecompositionError::ShapeMismatch {
+                expected: m,
+                found: rhs.len(),
+            });
+        }
+
+        if m < n {
+            return Err(DecompositionError::Underdetermined {
+                rows: m,
+                columns: n,
+            });
+        }
+
+        let (q, r, permutation) = self.householder(true);
+
+        // with pivoting, the diagonal of R is decreasing in magnitude, so the first pivot is the largest one
+        let largest_pivot = unsafe { *r.pointer }.abs();
+        let tolerance = T::EPSILON * T::from_f64(m as f64) * largest_pivot;
+
+        for k in 0..n {
+            let pivot = unsafe { *r.pointer.add(k * n + k) };
+            if pivot.abs() <= tolerance {
+                return Err(DecompositionError::RankDeficient { column: permutation[k] });
+            }
+        }
+
+        // y = R^-1 * Q^T * rhs, solved by back-substitution on the first n rows of R
+        let mut pivoted = FastArray::new(n, T::ZERO);
+        for k in (0..n).rev() {
+            let mut value = T::ZERO;
+            for i in 0..m {
+                value += unsafe { *q.pointer.add(i * m + k) } * rhs[i];
+            }
+            for j in k + 1..n {
+                value -= unsafe { *r.pointer.add(k * n + j) } * pivoted[j];
+            }
+
+            pivoted[k] = value / unsafe { *r.pointer.add(k * n + k) };
+        }
+
+        // x = P * y, undoing the column swaps
+        let mut solution = FastArray::new(n, T::ZERO);
+        for k in 0..n {
+            solution[permutation[k]] = pivoted[k];
+        }
+
+        Ok(solution)
+    }
+
+    /// ## Info
+    /// computes the Cholesky decomposition of self, returning the lower triangular matrix `L` so that `L * L^T == self`.
+    ///
+    /// ## Errors
+    /// if self isn't square, symmetric or positive-definite.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::fast_matrix;
+    ///
+    /// let fast_matrix = fast_matrix!([4.0, 12.0, -16.0], [12.0, 37.0, -43.0], [-16.0, -43.0, 98.0]);
+    /// let lower = fast_matrix.cholesky().unwrap();
+    ///
+    /// assert_eq!(lower, fast_matrix!([2.0, 0.0, 0.0], [6.0, 1.0, 0.0], [-8.0, 5.0, 3.0]));
+    ///
+    /// let not_positive_definite = fast_matrix!([1.0, 2.0], [2.0, 1.0]);
+    /// assert!(not_positive_definite.cholesky().is_err());
+    /// ```
+    pub fn cholesky(&self) -> Result<FastMatrix<T>, DecompositionError> {
+        self.check_symmetric()?;
+
+        let n = self.rows;
+
+        // self is symmetric, so reading its buffer as row-major is fine whatever the storage order is
+        let lower = FastMatrix::new(n, n, T::ZERO);
+
+        for j in 0..n {
+            let mut diagonal = unsafe { *self.pointer.add(j * n + j) };
+            for k in 0..j {
+                let element = unsafe { *lower.pointer.add(j * n + k) };
+                diagonal -= element * element;
+            }
+
+            // also catches NaNs
+            if diagonal.partial_cmp(&T::ZERO) != Some(std::cmp::Ordering::Greater) {
+                return Err(DecompositionError::NotPositiveDefinite { pivot: j });
+            }
+
+            let diagonal = diagonal.sqrt();
+            unsafe { *lower.pointer.add(j * n + j) = diagonal };
+
+            for i in j + 1..n {
+                let mut element = unsafe { *self.pointer.add(i * n + j) };
+                for k in 0..j {
+                    element -= unsafe { *lower.pointer.add(i * n + k) * *lower.pointer.add(j * n + k) };
+                }
+                unsafe { *lower.pointer.add(i * n + j) = element / diagonal };
+            }
+        }
+
+        Ok(lower)
+    }
+
+    /// checks that self is square and symmetric, up to a small relative tolerance.
+    pub(crate) fn check_symmetric(&self) -> Result<(), DecompositionError> {
+        let n = self.rows;
+
+        if n != self.columns {
+            return Err(DecompositionError::NotSquare {
+                rows: n,
+                columns: self.columns,
+            });
+        }
+
+        // comparing the buffer against its own transpose doesn't depend on the storage order
+        let tolerance = T::from_f64(64.0) * T::EPSILON;
+        for row in 0..n {
+            for column in row + 1..n {
+                let upper = unsafe { *self.pointer.add(row * n + column) };
+                let lower = unsafe { *self.pointer.add(column * n + row) };
+
+                let scale = if upper.abs() > lower.abs() { upper.abs() } else { lower.abs() };
+                if (upper - lower).abs() > tolerance * scale {
+                    return Err(DecompositionError::NotSymmetric { row, column });
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[inline]
+pub(crate) fn identity<T: Float>(n: usize) -> FastMatrix<T> {
+    FastMatrix::new_func(n, n, |(row, column)| {
+        if row == column {
+            T::ONE
+        } else {
+            T::ZERO
+        }
+    })
+}
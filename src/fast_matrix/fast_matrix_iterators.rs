@@ -0,0 +1,362 @@
+use std::marker::PhantomData;
+
+use crate::FastMatrix;
+
+use super::fast_matrix_view::{MatrixView, MatrixViewMut};
+
+impl<T> FastMatrix<T> {
+    #[inline(always)]
+    /// ## Info
+    /// creates an iterator over the rows of the [`FastMatrix`], each one borrowed as a `1 x columns` [`MatrixView`].
+    ///
+    /// this doesn't allocate nor clone anything; with row-major storage each row can also be borrowed as a slice through [`MatrixView::as_slice`].
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::fast_matrix;
+    ///
+    /// let fast_matrix = fast_matrix!([1, 2, 3], [4, 5, 6]);
+    /// let mut rows = fast_matrix.rows();
+    ///
+    /// assert_eq!(rows.next().unwrap().as_slice(), Some([1, 2, 3].as_slice()));
+    /// assert_eq!(rows.next().unwrap(), fast_matrix!([4, 5, 6]));
+    /// assert!(rows.next().is_none());
+    /// ```
+    pub fn rows(&self) -> Rows<'_, T> {
+        let (row_stride, column_stride) = self.strides();
+
+        Rows {
+            pointer: self.pointer,
+            rows: 1,
+            columns: self.columns,
+            row_stride,
+            column_stride,
+            step: row_stride,
+            front: 0,
+            back: self.rows,
+            marker: PhantomData,
+        }
+    }
+
+    #[inline(always)]
+    /// ## Info
+    /// creates an iterator over the rows of the [`FastMatrix`], each one borrowed as a `1 x columns` [`MatrixViewMut`].
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::fast_matrix;
+    ///
+    /// let mut fast_matrix = fast_matrix!([1, 2, 3], [4, 5, 6]);
+    /// for mut row in fast_matrix.rows_mut() {
+    ///     row.as_mut_slice().unwrap().reverse();
+    /// }
+    ///
+    /// assert_eq!(fast_matrix, fast_matrix!([3, 2, 1], [6, 5, 4]));
+    /// ```
+    pub fn rows_mut(&mut self) -> RowsMut<'_, T> {
+        let (row_stride, column_stride) = self.strides();
+
+        RowsMut {
+            pointer: self.pointer,
+            rows: 1,
+            columns: self.columns,
+            row_stride,
+            column_stride,
+            step: row_stride,
+            front: 0,
+            back: self.rows,
+            marker: PhantomData,
+        }
+    }
+
+    #[inline(always)]
+    /// ## Info
+    /// creates an iterator over the columns of the [`FastMatrix`], each one borrowed as a `rows x 1` [`MatrixView`].
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::fast_matrix;
+    ///
+    /// let fast_matrix = fast_matrix!([1, 2, 3], [4, 5, 6]);
+    /// let sums: Vec<i32> = fast_matrix.columns().map(|column| column.iter().sum()).collect();
+    ///
+    /// assert_eq!(sums, vec![5, 7, 9]);
+    /// ```
+    pub fn columns(&self) -> Columns<'_, T> {
+        let (row_stride, column_stride) = self.strides();
+
+        Columns {
+            pointer: self.pointer,
+            rows: self.rows,
+            columns: 1,
+            row_stride,
+            column_stride,
+            step: column_stride,
+            front: 0,
+            back: self.columns,
+            marker: PhantomData,
+        }
+    }
+
+    #[inline(always)]
+    /// ## Info
+    /// creates an iterator over the columns of the [`FastMatrix`], each one borrowed as a `rows x 1` [`MatrixViewMut`].
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::fast_matrix;
+    ///
+    /// let mut fast_matrix = fast_matrix!([1, 2, 3], [4, 5, 6]);
+    /// for (index, mut column) in fast_matrix.columns_mut().enumerate() {
+    ///     column[(0, 0)] = index;
+    /// }
+    ///
+    /// assert_eq!(fast_matrix, fast_matrix!([0, 1, 2], [4, 5, 6]));
+    /// ```
+    pub fn columns_mut(&mut self) -> ColumnsMut<'_, T> {
+        let (row_stride, column_stride) = self.strides();
+
+        ColumnsMut {
+            pointer: self.pointer,
+            rows: self.rows,
+            columns: 1,
+            row_stride,
+            column_stride,
+            step: column_stride,
+            front: 0,
+            back: self.columns,
+            marker: PhantomData,
+        }
+    }
+
+    #[inline(always)]
+    /// ## Info
+    /// creates an iterator over groups of `chunk_rows` consecutive rows of the [`FastMatrix`], each one borrowed as a [`MatrixView`].
+    ///
+    /// if the number of rows isn't a multiple of `chunk_rows`, the last view has fewer rows.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::fast_matrix;
+    ///
+    /// let fast_matrix = fast_matrix!([1, 2], [3, 4], [5, 6]);
+    /// let mut chunks = fast_matrix.chunks_rows(2);
+    ///
+    /// assert_eq!(chunks.next().unwrap(), fast_matrix!([1, 2], [3, 4]));
+    /// assert_eq!(chunks.next().unwrap(), fast_matrix!([5, 6]));
+    /// assert!(chunks.next().is_none());
+    /// ```
+    ///
+    /// ## Panics
+    /// if `chunk_rows == 0`.
+    pub fn chunks_rows(&self, chunk_rows: usize) -> RowChunks<'_, T> {
+        assert_ne!(chunk_rows, 0, "FastMatrix: chunk_rows cannot be 0!");
+
+        let (row_stride, column_stride) = self.strides();
+
+        RowChunks {
+            pointer: self.pointer,
+            columns: self.columns,
+            row_stride,
+            column_stride,
+            chunk_rows,
+            front: 0,
+            back: self.rows,
+            marker: PhantomData,
+        }
+    }
+}
+
+/// ## Info
+/// an iterator over the rows of a [`FastMatrix`], created by [`FastMatrix::rows`].
+pub struct Rows<'a, T> {
+    pointer: *const T,
+    rows: usize,
+    columns: usize,
+    row_stride: usize,
+    column_stride: usize,
+    /// the distance in elements between the start of two consecutive lanes.
+    step: usize,
+    front: usize,
+    back: usize,
+    marker: PhantomData<&'a T>,
+}
+
+/// ## Info
+/// an iterator over the mutable rows of a [`FastMatrix`], created by [`FastMatrix::rows_mut`].
+pub struct RowsMut<'a, T> {
+    pointer: *mut T,
+    rows: usize,
+    columns: usize,
+    row_stride: usize,
+    column_stride: usize,
+    /// the distance in elements between the start of two consecutive lanes.
+    step: usize,
+    front: usize,
+    back: usize,
+    marker: PhantomData<&'a mut T>,
+}
+
+/// ## Info
+/// an iterator over the columns of a [`FastMatrix`], created by [`FastMatrix::columns`].
+pub struct Columns<'a, T> {
+    pointer: *const T,
+    rows: usize,
+    columns: usize,
+    row_stride: usize,
+    column_stride: usize,
+    /// the distance in elements between the start of two consecutive lanes.
+    step: usize,
+    front: usize,
+    back: usize,
+    marker: PhantomData<&'a T>,
+}
+
+/// ## Info
+/// an iterator over the mutable columns of a [`FastMatrix`], created by [`FastMatrix::columns_mut`].
+pub struct ColumnsMut<'a, T> {
+    pointer: *mut T,
+    rows: usize,
+    columns: usize,
+    row_stride: usize,
+    column_stride: usize,
+    /// the distance in elements between the start of two consecutive lanes.
+    step: usize,
+    front: usize,
+    back: usize,
+    marker: PhantomData<&'a mut T>,
+}
+
+/// ## Info
+/// an iterator over groups of rows of a [`FastMatrix`], created by [`FastMatrix::chunks_rows`].
+pub struct RowChunks<'a, T> {
+    pointer: *const T,
+    columns: usize,
+    row_stride: usize,
+    column_stride: usize,
+    chunk_rows: usize,
+    front: usize,
+    back: usize,
+    marker: PhantomData<&'a T>,
+}
+
+unsafe impl<T: Sync> Send for Rows<'_, T> {}
+unsafe impl<T: Sync> Sync for Rows<'_, T> {}
+unsafe impl<T: Send> Send for RowsMut<'_, T> {}
+unsafe impl<T: Sync> Sync for RowsMut<'_, T> {}
+unsafe impl<T: Sync> Send for Columns<'_, T> {}
+unsafe impl<T: Sync> Sync for Columns<'_, T> {}
+unsafe impl<T: Send> Send for ColumnsMut<'_, T> {}
+unsafe impl<T: Sync> Sync for ColumnsMut<'_, T> {}
+unsafe impl<T: Sync> Send for RowChunks<'_, T> {}
+unsafe impl<T: Sync> Sync for RowChunks<'_, T> {}
+
+macro_rules! impl_lanes_iter {
+    ($iter:ident, $view:ident) => {
+        impl<'a, T> $iter<'a, T> {
+            #[inline(always)]
+            fn lane_view(&self, lane: usize) -> $view<'a, T> {
+                $view {
+                    pointer: unsafe { self.pointer.add(lane * self.step) },
+                    rows: self.rows,
+                    columns: self.columns,
+                    row_stride: self.row_stride,
+                    column_stride: self.column_stride,
+                    marker: PhantomData,
+                }
+            }
+        }
+
+        impl<'a, T> Iterator for $iter<'a, T> {
+            type Item = $view<'a, T>;
+
+            #[inline(always)]
+            fn next(&mut self) -> Option<Self::Item> {
+                if self.front >= self.back {
+                    None
+                } else {
+                    self.front += 1;
+                    Some(self.lane_view(self.front - 1))
+                }
+            }
+
+            #[inline(always)]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let len = self.back - self.front;
+                (len, Some(len))
+            }
+        }
+
+        impl<'a, T> DoubleEndedIterator for $iter<'a, T> {
+            #[inline(always)]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                if self.front >= self.back {
+                    None
+                } else {
+                    self.back -= 1;
+                    Some(self.lane_view(self.back))
+                }
+            }
+        }
+
+        impl<'a, T> ExactSizeIterator for $iter<'a, T> {}
+    };
+}
+
+impl_lanes_iter!(Rows, MatrixView);
+impl_lanes_iter!(RowsMut, MatrixViewMut);
+impl_lanes_iter!(Columns, MatrixView);
+impl_lanes_iter!(ColumnsMut, MatrixViewMut);
+
+impl<'a, T> RowChunks<'a, T> {
+    #[inline(always)]
+    fn chunk_view(&self, first_row: usize, rows: usize) -> MatrixView<'a, T> {
+        MatrixView {
+            pointer: unsafe { self.pointer.add(first_row * self.row_stride) },
+            rows,
+            columns: self.columns,
+            row_stride: self.row_stride,
+            column_stride: self.column_stride,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T> Iterator for RowChunks<'a, T> {
+    type Item = MatrixView<'a, T>;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            None
+        } else {
+            let rows = self.chunk_rows.min(self.back - self.front);
+            let view = self.chunk_view(self.front, rows);
+            self.front += rows;
+            Some(view)
+        }
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.back - self.front).div_ceil(self.chunk_rows);
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for RowChunks<'a, T> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            None
+        } else {
+            // the last chunk is the only one that can be shorter
+            let remainder = (self.back - self.front) % self.chunk_rows;
+            let rows = if remainder == 0 { self.chunk_rows } else { remainder };
+            self.back -= rows;
+            Some(self.chunk_view(self.back, rows))
+        }
+    }
+}
+
+impl<'a, T> ExactSizeIterator for RowChunks<'a, T> {}
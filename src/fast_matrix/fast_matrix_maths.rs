@@ -1,9 +1,12 @@
 use std::{
+    mem,
     ops::{AddAssign, Div, DivAssign, Mul, SubAssign},
     ptr,
 };
 
-use crate::FastMatrix;
+use crate::{alloc, FastArray, FastError, FastMatrix};
+
+use super::fast_matrix::StorageOrder;
 
 impl<T> FastMatrix<T> {
     // ------- ROWS --------
@@ -12,7 +15,7 @@ impl<T> FastMatrix<T> {
     /// 
     /// ## Example
     /// ```
-    /// use fast_array::fast_matrix;
+    /// use fast_collections::fast_matrix;
     /// 
     /// let mut fast_matrix = fast_matrix!([1,2,3], [4,5,6], [7,8,9]);
     /// fast_matrix.swap_rows(0, 2);
@@ -30,12 +33,43 @@ impl<T> FastMatrix<T> {
         }
     }
     
+    /// ## Info
+    /// does the same thing as [`FastMatrix::swap_rows`], but returns an error instead of panicking.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::{fast_matrix, FastError};
+    ///
+    /// let mut fast_matrix = fast_matrix!([1, 2], [3, 4]);
+    /// fast_matrix.try_swap_rows(0, 1).unwrap();
+    ///
+    /// assert_eq!(fast_matrix, fast_matrix!([3, 4], [1, 2]));
+    /// assert!(matches!(fast_matrix.try_swap_rows(0, 2), Err(FastError::OutOfBounds { index: 2, len: 2 })));
+    /// ```
+    ///
+    /// ## Errors
+    /// [`FastError::OutOfBounds`] with the first row that's out of bounds.
+    pub fn try_swap_rows(&mut self, row1: usize, row2: usize) -> Result<(), FastError> {
+        for row in [row1, row2] {
+            if row >= self.rows {
+                return Err(FastError::OutOfBounds { index: row, len: self.rows });
+            }
+        }
+
+        unsafe { self.swap_rows_unchecked(row1, row2) };
+        Ok(())
+    }
+
     /// ## Info 
     /// same as [`FastMatrix::swap_rows`], but doesn't check for out of bounds conditions for performance reasons.
     /// 
     /// if `row1` or `row2` is out of bounds, using the method is undefined behavior. 
     pub unsafe fn swap_rows_unchecked(&mut self, row1: usize, row2: usize) {
-        // assert!(row1<self.rows && row2 < self.rows, "FastMatrix: tried to index out of bounds.");
+        debug_assert!(
+            row1 < self.rows && row2 < self.rows,
+            "FastMatrix::swap_rows_unchecked: rows {row1} and {row2} must be less than {}",
+            self.rows
+        );
 
         for i in 0..self.columns {
             self.swap_unchecked((row1, i), (row2, i));
@@ -66,7 +100,11 @@ impl<T> FastMatrix<T> {
     /// 
     /// if any other operations are done on the [`FastMatrix`] while the method is running, or if `row1` or `row2` are out of bounds, using the method is undefined behavior. 
     pub unsafe fn swap_rows_unchecked_unsafe(&self, row1: usize, row2: usize) {
-        // assert!(row1<self.rows && row2 < self.rows, "FastMatrix: tried to index out of bounds.");
+        debug_assert!(
+            row1 < self.rows && row2 < self.rows,
+            "FastMatrix::swap_rows_unchecked_unsafe: rows {row1} and {row2} must be less than {}",
+            self.rows
+        );
 
         for i in 0..self.columns {
             self.swap_unchecked_unsafe((row1, i), (row2, i));
@@ -81,7 +119,7 @@ impl<T> FastMatrix<T> {
     /// 
     /// ## Example
     /// ```
-    /// use fast_array::fast_matrix;
+    /// use fast_collections::fast_matrix;
     /// 
     /// let mut fast_matrix = fast_matrix!([1,2,3], [4,5,6], [7,8,9]);
     /// fast_matrix.swap_columns(0, 2);
@@ -90,7 +128,7 @@ impl<T> FastMatrix<T> {
     /// ```
     pub fn swap_columns(&mut self, column1: usize, column2: usize) {
         assert!(
-            column1 < self.columns && column2 < self.rows,
+            column1 < self.columns && column2 < self.columns,
             "FastMatrix: tried to index out of bounds."
         );
 
@@ -99,12 +137,43 @@ impl<T> FastMatrix<T> {
         }
     }
 
+    /// ## Info
+    /// does the same thing as [`FastMatrix::swap_columns`], but returns an error instead of panicking.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::fast_matrix;
+    ///
+    /// let mut fast_matrix = fast_matrix!([1, 2, 3]);
+    /// fast_matrix.try_swap_columns(0, 2).unwrap();
+    ///
+    /// assert_eq!(fast_matrix, fast_matrix!([3, 2, 1]));
+    /// assert!(fast_matrix.try_swap_columns(3, 0).is_err());
+    /// ```
+    ///
+    /// ## Errors
+    /// [`FastError::OutOfBounds`] with the first column that's out of bounds.
+    pub fn try_swap_columns(&mut self, column1: usize, column2: usize) -> Result<(), FastError> {
+        for column in [column1, column2] {
+            if column >= self.columns {
+                return Err(FastError::OutOfBounds { index: column, len: self.columns });
+            }
+        }
+
+        unsafe { self.swap_columns_unchecked(column1, column2) };
+        Ok(())
+    }
+
     /// ## Info 
     /// same as [`FastMatrix::swap_columns`], but doesn't check for out of bounds conditions for performance reasons.
     /// 
     /// if `column1` or `column2` is out of bounds, using the method is undefined behavior. 
     pub unsafe fn swap_columns_unchecked(&mut self, column1: usize, column2: usize) {
-        // assert!(row1<self.rows && row2 < self.rows, "FastMatrix: tried to index out of bounds.");
+        debug_assert!(
+            column1 < self.columns && column2 < self.columns,
+            "FastMatrix::swap_columns_unchecked: columns {column1} and {column2} must be less than {}",
+            self.columns
+        );
 
         for i in 0..self.rows {
             self.swap_unchecked((i, column1), (i, column2));
@@ -119,7 +188,7 @@ impl<T> FastMatrix<T> {
         /// if any other operations are done on the [`FastMatrix`] while the method is running, using the method is undefined behavior. 
     pub unsafe fn swap_columns_unsafe(&self, column1: usize, column2: usize) {
         assert!(
-            column1 < self.columns && column2 < self.rows,
+            column1 < self.columns && column2 < self.columns,
             "FastMatrix: tried to index out of bounds."
         );
 
@@ -135,7 +204,11 @@ impl<T> FastMatrix<T> {
     /// 
     /// if any other operations are done on the [`FastMatrix`] while the method is running, or if `column1` or `column2` are out of bounds, using the method is undefined behavior. 
     pub unsafe fn swap_columns_unchecked_unsafe(&self, column1: usize, column2: usize) {
-        // assert!(row1<self.rows && row2 < self.rows, "FastMatrix: tried to index out of bounds.");
+        debug_assert!(
+            column1 < self.columns && column2 < self.columns,
+            "FastMatrix::swap_columns_unchecked_unsafe: columns {column1} and {column2} must be less than {}",
+            self.columns
+        );
 
         for i in 0..self.rows {
             self.swap_unchecked_unsafe((i, column1), (i, column2));
@@ -144,13 +217,249 @@ impl<T> FastMatrix<T> {
 }
 
 impl<T: Clone> FastMatrix<T> {
-    #[inline(always)]
+    /// ## Info
+    /// returns a new [`FastMatrix`] that is the transpose of self, stored in the same [`StorageOrder`].
+    ///
+    /// the matrix gets split recursively into smaller blocks until they fit in the cache, so that both reading and writing stay mostly sequential even for huge matrices.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::fast_matrix;
+    ///
+    /// let fast_matrix = fast_matrix!([1, 2, 3], [4, 5, 6]);
+    ///
+    /// assert_eq!(fast_matrix.transpose(), fast_matrix!([1, 4], [2, 5], [3, 6]));
+    /// ```
     pub fn transpose(&self) -> FastMatrix<T> {
-        let func = |(row, col)| {
-            self[(col, row)].clone()
+        let mut transposed = self.transposed_storage();
+        transposed.rows = self.columns;
+        transposed.columns = self.rows;
+        transposed.order = self.order;
+
+        transposed
+    }
+
+    /// ## Info
+    /// returns a copy of self laid out in the given [`StorageOrder`].
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::fast_matrix;
+    /// use fast_collections::fast_matrix::fast_matrix::StorageOrder;
+    ///
+    /// let fast_matrix = fast_matrix!([1, 2, 3], [4, 5, 6]);
+    /// let column_major = fast_matrix.to_order(StorageOrder::ColumnMajor);
+    ///
+    /// assert_eq!(column_major, fast_matrix);
+    /// assert_eq!(column_major.into_fast_iter().collect::<Vec<_>>(), vec![1, 4, 2, 5, 3, 6]);
+    /// ```
+    pub fn to_order(&self, order: StorageOrder) -> FastMatrix<T> {
+        if order == self.order {
+            return self.clone();
+        }
+
+        let mut reordered = self.transposed_storage();
+        reordered.rows = self.rows;
+        reordered.columns = self.columns;
+        reordered.order = order;
+
+        reordered
+    }
+
+    /// clones the buffer of self, seen as a row-major `outer x inner` matrix, into the buffer of a new `inner x outer` matrix.
+    ///
+    /// the shape and order of the returned matrix are left for the caller to set.
+    fn transposed_storage(&self) -> FastMatrix<T> {
+        let (outer, inner) = self.storage_shape();
+        // only becomes a matrix once every element is written, so that a panicking `clone` frees the buffer without dropping uninitialized elements
+        let buffer = UninitBuffer { pointer: alloc::allocate::<T>(outer * inner), len: outer * inner };
+
+        unsafe {
+            transpose_block(
+                self.pointer,
+                buffer.pointer,
+                inner,
+                outer,
+                (0, outer),
+                (0, inner),
+            )
         };
 
-        unsafe { FastMatrix::new_func_unchecked(self.columns, self.rows, func) }
+        FastMatrix { pointer: buffer.into_inner(), rows: inner, columns: outer, order: StorageOrder::RowMajor }
+    }
+}
+
+/// a buffer that is being filled, freed without dropping its elements if it's dropped before [`UninitBuffer::into_inner`].
+///
+/// the elements written so far are leaked on a panic, since the blocks don't fill the buffer in order.
+struct UninitBuffer<T> {
+    pointer: *mut T,
+    len: usize,
+}
+
+impl<T> UninitBuffer<T> {
+    /// hands over the buffer, once it's full.
+    fn into_inner(self) -> *mut T {
+        let pointer = self.pointer;
+        mem::forget(self);
+
+        pointer
+    }
+}
+
+impl<T> Drop for UninitBuffer<T> {
+    fn drop(&mut self) {
+        unsafe { alloc::deallocate(self.pointer, self.len) };
+    }
+}
+
+/// the side under which a block gets transposed element by element.
+const TRANSPOSE_BLOCK: usize = 32;
+
+/// clones the block `rows x columns` of `source` into its transposed position in `destination`, splitting it in halves along its longest side until it's small enough.
+///
+/// `source_stride` and `destination_stride` are the numbers of columns of the two buffers, seen as row-major matrices.
+unsafe fn transpose_block<T: Clone>(
+    source: *const T,
+    destination: *mut T,
+    source_stride: usize,
+    destination_stride: usize,
+    rows: (usize, usize),
+    columns: (usize, usize),
+) {
+    let row_count = rows.1 - rows.0;
+    let column_count = columns.1 - columns.0;
+
+    if row_count <= TRANSPOSE_BLOCK && column_count <= TRANSPOSE_BLOCK {
+        for row in rows.0..rows.1 {
+            for column in columns.0..columns.1 {
+                unsafe {
+                    let element = (*source.add(row * source_stride + column)).clone();
+                    destination
+                        .add(column * destination_stride + row)
+                        .write(element);
+                }
+            }
+        }
+    } else if row_count >= column_count {
+        let middle = rows.0 + row_count / 2;
+        unsafe {
+            transpose_block(source, destination, source_stride, destination_stride, (rows.0, middle), columns);
+            transpose_block(source, destination, source_stride, destination_stride, (middle, rows.1), columns);
+        }
+    } else {
+        let middle = columns.0 + column_count / 2;
+        unsafe {
+            transpose_block(source, destination, source_stride, destination_stride, rows, (columns.0, middle));
+            transpose_block(source, destination, source_stride, destination_stride, rows, (middle, columns.1));
+        }
+    }
+}
+
+impl<T> FastMatrix<T> {
+    /// ## Info
+    /// transposes self in place, without allocating a new matrix, also swapping `rows` and `columns`.
+    ///
+    /// square matrices just swap each element with its mirror, while rectangular ones move their elements following the cycles of the transposition.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::fast_matrix;
+    ///
+    /// let mut fast_matrix = fast_matrix!([1, 2, 3], [4, 5, 6]);
+    /// fast_matrix.transpose_in_place();
+    ///
+    /// assert_eq!((fast_matrix.rows, fast_matrix.columns), (3, 2));
+    /// assert_eq!(fast_matrix, fast_matrix!([1, 4], [2, 5], [3, 6]));
+    /// ```
+    pub fn transpose_in_place(&mut self) {
+        self.transpose_storage_in_place();
+        std::mem::swap(&mut self.rows, &mut self.columns);
+    }
+
+    /// ## Info
+    /// lays the elements of self out in the given [`StorageOrder`], moving them in place without allocating a new matrix.
+    ///
+    /// the shape and the elements of self stay the same, only their position in memory changes.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::fast_matrix;
+    /// use fast_collections::fast_matrix::fast_matrix::StorageOrder;
+    ///
+    /// let fast_matrix = fast_matrix!([1, 2, 3], [4, 5, 6]).into_order(StorageOrder::ColumnMajor);
+    ///
+    /// assert_eq!(fast_matrix.order(), StorageOrder::ColumnMajor);
+    /// assert_eq!(fast_matrix[(1, 0)], 4);
+    /// assert_eq!(fast_matrix, fast_matrix!([1, 2, 3], [4, 5, 6]));
+    /// ```
+    pub fn into_order(mut self, order: StorageOrder) -> FastMatrix<T> {
+        if order != self.order {
+            self.transpose_storage_in_place();
+            self.order = order;
+        }
+
+        self
+    }
+
+    /// moves the buffer of self, seen as a row-major `outer x inner` matrix, so that it becomes the buffer of the `inner x outer` transposed matrix.
+    ///
+    /// the shape and order of self are left untouched, it's up to the caller to update them.
+    fn transpose_storage_in_place(&mut self) {
+        let (outer, inner) = self.storage_shape();
+
+        if outer == inner {
+            // swap tile by tile, so that both tiles stay in the cache
+            for row_block in (0..outer).step_by(TRANSPOSE_BLOCK) {
+                for column_block in (row_block..inner).step_by(TRANSPOSE_BLOCK) {
+                    for row in row_block..(row_block + TRANSPOSE_BLOCK).min(outer) {
+                        for column in column_block.max(row + 1)..(column_block + TRANSPOSE_BLOCK).min(inner) {
+                            unsafe {
+                                ptr::swap(
+                                    self.pointer.add(row * inner + column),
+                                    self.pointer.add(column * inner + row),
+                                )
+                            };
+                        }
+                    }
+                }
+            }
+
+            return;
+        }
+
+        let len = outer * inner;
+
+        // the element that ends up at `index` of the transposed buffer
+        let source_of = |index: usize| (index % outer) * inner + index / outer;
+
+        // one bit per element, set once the element has been moved to its final place
+        let mut moved = FastArray::new(len.div_ceil(64), 0u64);
+
+        // the first and the last element never move
+        for start in 1..len - 1 {
+            if moved[start / 64] & (1 << (start % 64)) != 0 {
+                continue;
+            }
+
+            unsafe {
+                let first = ptr::read(self.pointer.add(start));
+
+                let mut current = start;
+                loop {
+                    moved[current / 64] |= 1 << (current % 64);
+
+                    let source = source_of(current);
+                    if source == start {
+                        self.pointer.add(current).write(first);
+                        break;
+                    }
+
+                    ptr::copy_nonoverlapping(self.pointer.add(source), self.pointer.add(current), 1);
+                    current = source;
+                }
+            }
+        }
     }
 }
 
@@ -175,16 +484,43 @@ where
     /// 
     /// ## Example
     /// ```
-    /// use fast_array::fast_matrix;
+    /// use fast_collections::{fast_matrix, FastMatrix};
     /// 
     /// let fast_matrix: FastMatrix<f64> = fast_matrix!([1.0, 4.0], [2.0, 3.0]);
     /// 
     /// assert_eq!(fast_matrix.determinant(), -5.0);
     /// ```
     pub fn determinant(&self) -> T {
-        assert!(self.rows == self.columns, "Matrix must be square");
+        match self.try_determinant() {
+            Ok(det) => det,
+            Err(error) => panic!("FastMatrix: determinant: {error}"),
+        }
+    }
+
+    /// ## Info
+    /// does the same thing as [`FastMatrix::determinant`], but returns an error instead of panicking if self isn't square.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::{fast_matrix, FastError, FastMatrix};
+    ///
+    /// let fast_matrix: FastMatrix<f64> = fast_matrix!([1.0, 2.0, 3.0], [4.0, 5.0, 6.0]);
+    ///
+    /// assert!(matches!(
+    ///     fast_matrix.try_determinant(),
+    ///     Err(FastError::ShapeMismatch { expected: (2, 2), found: (2, 3) })
+    /// ));
+    /// ```
+    ///
+    /// ## Errors
+    /// [`FastError::ShapeMismatch`] if self isn't square.
+    pub fn try_determinant(&self) -> Result<T, FastError> {
+        if self.rows != self.columns {
+            return Err(FastError::ShapeMismatch { expected: (self.rows, self.rows), found: (self.rows, self.columns) });
+        }
         let n = self.rows;
 
+        // det(A) == det(A^T), so the buffer can be walked as row-major whatever the storage order is
         let mat = self.clone();
         let mut det = T::try_from(1.0).expect("FastMatrix: determinant: couldn't convert T to f64");
         let mut sign = T::try_from(1.0).expect("FastMatrix: determinant: couldn't convert T to f64");
@@ -206,21 +542,21 @@ where
                 sign = sign * T::try_from(-1.0).expect("FastMatrix: determinant: couldn't convert T to f64");
             }
 
-            let pivot_value = unsafe { mat.pointer.add(k * n + k).read() };
+            let pivot_value = unsafe { (*mat.pointer.add(k * n + k)).clone() };
             if pivot_value == T::try_from(0.0).expect("FastMatrix: determinant: couldn't convert T to f64") {
-                return T::try_from(0.0).expect("FastMatrix: determinant: couldn't convert T to f64");
+                return Ok(T::try_from(0.0).expect("FastMatrix: determinant: couldn't convert T to f64"));
             }
 
             det = det * pivot_value.clone();
 
             for i in k + 1..n {
-                let factor = unsafe { mat.pointer.add(i * n + k).read() } / pivot_value.clone();
+                let factor = unsafe { (*mat.pointer.add(i * n + k)).clone() } / pivot_value.clone();
                 for j in k..n {
-                    unsafe{*mat.pointer.add(i * n + j) -= factor.clone() * mat.pointer.add(k * n + j).read()};
+                    unsafe{*mat.pointer.add(i * n + j) -= factor.clone() * (*mat.pointer.add(k * n + j)).clone()};
                 }
             }
         }
 
-        det * sign
+        Ok(det * sign)
     }
 }
\ No newline at end of file
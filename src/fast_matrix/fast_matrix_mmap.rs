@@ -0,0 +1,219 @@
+#![cfg(all(unix, feature = "mmap"))]
+
+use std::{
+    io,
+    marker::PhantomData,
+    ops::{Index, IndexMut},
+    path::Path,
+};
+
+use super::fast_matrix_view::{MatrixView, MatrixViewMut};
+use crate::{
+    fast_array::fast_array_mmap::{MappedFastArray, MappedFastArrayMut},
+    pod::Pod,
+    FastArray, FastMatrix,
+};
+
+/// ## Info
+/// a read-only, row-major [`FastMatrix`] backed by a memory-mapped file, created by [`FastMatrix::map_file`].
+#[derive(Debug)]
+pub struct MappedFastMatrix<T> {
+    pub(crate) array: MappedFastArray<T>,
+    pub rows: usize,
+    pub columns: usize,
+}
+
+/// ## Info
+/// a row-major [`FastMatrix`] backed by a memory-mapped file, created by [`FastMatrix::map_file_mut`].
+#[derive(Debug)]
+pub struct MappedFastMatrixMut<T> {
+    pub(crate) array: MappedFastArrayMut<T>,
+    pub rows: usize,
+    pub columns: usize,
+}
+
+/// checks that a mapped file of `len` elements holds exactly a `rows x columns` matrix.
+fn check_shape(len: usize, rows: usize, columns: usize) -> io::Result<()> {
+    if rows.checked_mul(columns) != Some(len) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("the file holds {len} elements, which isn't a {rows}x{columns} matrix"),
+        ));
+    }
+
+    Ok(())
+}
+
+impl<T: Pod> FastMatrix<T> {
+    /// ## Info
+    /// maps the file at `path` in memory, read-only, as a row-major `rows x columns` matrix of `T`.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::{fast_matrix, FastMatrix};
+    ///
+    /// let path = std::env::temp_dir().join("fast_collections_matrix_map_file.bin");
+    /// std::fs::write(&path, [1, 2, 3, 4, 5, 6]).unwrap();
+    ///
+    /// // nothing else touches the file while it's mapped
+    /// let mapped = unsafe { FastMatrix::<u8>::map_file(&path, 2, 3) }.unwrap();
+    ///
+    /// assert_eq!(mapped[(1, 0)], 4);
+    /// assert_eq!(mapped.as_view().transpose()[(2, 1)], 6);
+    /// assert_eq!(mapped.to_fast_matrix(), fast_matrix!([1, 2, 3], [4, 5, 6]));
+    ///
+    /// assert!(unsafe { FastMatrix::<u8>::map_file(&path, 4, 2) }.is_err());
+    /// # std::fs::remove_file(path).unwrap();
+    /// ```
+    ///
+    /// ## Errors
+    /// if the file can't be mapped, or doesn't hold exactly `rows * columns` elements.
+    ///
+    /// ## Unsafe
+    /// same as [`FastArray::map_file`]: the file must not be changed or truncated while it's mapped.
+    pub unsafe fn map_file(path: impl AsRef<Path>, rows: usize, columns: usize) -> io::Result<MappedFastMatrix<T>> {
+        let array = unsafe { FastArray::<T>::map_file(path)? };
+        check_shape(array.len(), rows, columns)?;
+
+        Ok(MappedFastMatrix { array, rows, columns })
+    }
+
+    /// ## Info
+    /// maps the file at `path` in memory as a row-major `rows x columns` matrix of `T`, so that writing to the matrix writes to the file.
+    ///
+    /// ## Errors
+    /// if the file can't be opened for writing or mapped, or doesn't hold exactly `rows * columns` elements.
+    ///
+    /// ## Unsafe
+    /// same as [`FastArray::map_file_mut`]: the file must not be changed, mapped again or truncated while it's mapped.
+    pub unsafe fn map_file_mut(path: impl AsRef<Path>, rows: usize, columns: usize) -> io::Result<MappedFastMatrixMut<T>> {
+        let array = unsafe { FastArray::<T>::map_file_mut(path)? };
+        check_shape(array.len(), rows, columns)?;
+
+        Ok(MappedFastMatrixMut { array, rows, columns })
+    }
+}
+
+macro_rules! impl_mapped_matrix_common {
+    ($type:ident) => {
+        impl<T: Pod> $type<T> {
+            #[inline(always)]
+            /// ## Info
+            /// returns the `(rows, columns)` of the matrix.
+            pub fn shape(&self) -> (usize, usize) {
+                (self.rows, self.columns)
+            }
+
+            #[inline(always)]
+            /// ## Info
+            /// borrows the mapped elements as a slice, row after row.
+            pub fn as_slice(&self) -> &[T] {
+                self.array.as_slice()
+            }
+
+            #[inline(always)]
+            /// ## Info
+            /// creates an iterator over the mapped elements, row after row.
+            pub fn iter(&self) -> std::slice::Iter<'_, T> {
+                self.array.iter()
+            }
+
+            #[inline(always)]
+            /// ## Info
+            /// gets a borrowed view of the whole matrix.
+            pub fn as_view(&self) -> MatrixView<'_, T> {
+                MatrixView {
+                    pointer: self.array.pointer,
+                    rows: self.rows,
+                    columns: self.columns,
+                    row_stride: self.columns,
+                    column_stride: 1,
+                    marker: PhantomData,
+                }
+            }
+
+            /// ## Info
+            /// copies the mapped elements into a heap-allocated, row-major [`FastMatrix`].
+            pub fn to_fast_matrix(&self) -> FastMatrix<T> {
+                let slice = self.as_slice();
+                let columns = self.columns;
+                FastMatrix::new_func(self.rows, columns, |(row, column)| slice[row * columns + column])
+            }
+        }
+
+        impl<T: Pod> Index<(usize, usize)> for $type<T> {
+            type Output = T;
+
+            #[inline(always)]
+            fn index(&self, index: (usize, usize)) -> &Self::Output {
+                assert!(
+                    index.0 < self.rows && index.1 < self.columns,
+                    "FastMatrix: tried to index out of bounds."
+                );
+
+                &self.as_slice()[index.0 * self.columns + index.1]
+            }
+        }
+
+        impl<T: Pod + PartialEq> PartialEq<FastMatrix<T>> for $type<T> {
+            fn eq(&self, other: &FastMatrix<T>) -> bool {
+                self.as_view().eq(other)
+            }
+        }
+    };
+}
+
+impl_mapped_matrix_common!(MappedFastMatrix);
+impl_mapped_matrix_common!(MappedFastMatrixMut);
+
+impl<T: Pod> MappedFastMatrixMut<T> {
+    #[inline(always)]
+    /// ## Info
+    /// borrows the mapped elements as a mutable slice, row after row.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.array.as_mut_slice()
+    }
+
+    #[inline(always)]
+    /// ## Info
+    /// creates an iterator over mutable references to the mapped elements, row after row.
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.array.iter_mut()
+    }
+
+    #[inline(always)]
+    /// ## Info
+    /// gets a mutable view of the whole matrix.
+    pub fn as_view_mut(&mut self) -> MatrixViewMut<'_, T> {
+        MatrixViewMut {
+            pointer: self.array.pointer,
+            rows: self.rows,
+            columns: self.columns,
+            row_stride: self.columns,
+            column_stride: 1,
+            marker: PhantomData,
+        }
+    }
+
+    /// ## Info
+    /// blocks until the changes are written back to the file.
+    ///
+    /// ## Errors
+    /// if `msync` fails.
+    pub fn flush(&self) -> io::Result<()> {
+        self.array.flush()
+    }
+}
+
+impl<T: Pod> IndexMut<(usize, usize)> for MappedFastMatrixMut<T> {
+    #[inline(always)]
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+        assert!(
+            index.0 < self.rows && index.1 < self.columns,
+            "FastMatrix: tried to index out of bounds."
+        );
+
+        let columns = self.columns;
+        &mut self.as_mut_slice()[index.0 * columns + index.1]
+    }
+}
@@ -0,0 +1,505 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    io::{self, BufRead, Write},
+    str::FromStr,
+};
+
+use crate::FastMatrix;
+
+/// ## Info
+/// how the entries of a Matrix Market file are stored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MtxFormat {
+    /// only the non-zero entries, as `row column value` lines (1-based indices).
+    #[default]
+    Coordinate,
+    /// every entry, one value per line, in column-major order.
+    Array,
+}
+
+/// ## Info
+/// the kind of values stored in a Matrix Market file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MtxField {
+    /// floating point values.
+    #[default]
+    Real,
+    /// integer values.
+    Integer,
+    /// no values at all, every listed entry is a one (only with [`MtxFormat::Coordinate`]).
+    Pattern,
+}
+
+/// ## Info
+/// which entries of the matrix are actually stored in a Matrix Market file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MtxSymmetry {
+    /// every entry is stored.
+    #[default]
+    General,
+    /// the matrix is square and equal to its transpose, only the lower triangle (diagonal included) is stored.
+    Symmetric,
+}
+
+/// ## Info
+/// the header of a Matrix Market file, aka the `%%MatrixMarket matrix <format> <field> <symmetry>` line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MtxHeader {
+    pub format: MtxFormat,
+    pub field: MtxField,
+    pub symmetry: MtxSymmetry,
+}
+
+impl Display for MtxHeader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let format = match self.format {
+            MtxFormat::Coordinate => "coordinate",
+            MtxFormat::Array => "array",
+        };
+        let field = match self.field {
+            MtxField::Real => "real",
+            MtxField::Integer => "integer",
+            MtxField::Pattern => "pattern",
+        };
+        let symmetry = match self.symmetry {
+            MtxSymmetry::General => "general",
+            MtxSymmetry::Symmetric => "symmetric",
+        };
+
+        write!(f, "%%MatrixMarket matrix {format} {field} {symmetry}")
+    }
+}
+
+/// ## Info
+/// everything that can go wrong while reading a Matrix Market file.
+///
+/// lines start at 1, and rows and columns are the 1-based indices written in the file.
+#[derive(Debug)]
+pub enum MtxError {
+    /// reading failed, or the data isn't valid utf-8.
+    Io(io::Error),
+    /// the first line isn't a `%%MatrixMarket matrix <format> <field> <symmetry>` header.
+    InvalidHeader(String),
+    /// the header is valid, but describes something that isn't supported, like complex values or skew-symmetric matrices.
+    Unsupported(String),
+    /// the size line or an entry line doesn't have the expected shape.
+    InvalidLine { line: usize, text: String },
+    /// a value couldn't be parsed as `T`.
+    Parse { line: usize, token: String },
+    /// an entry is outside of the matrix.
+    OutOfBounds { line: usize, row: usize, column: usize },
+    /// an entry of a symmetric matrix is above the diagonal.
+    UpperTriangle { line: usize, row: usize, column: usize },
+    /// an entry is listed twice.
+    Duplicate { line: usize, row: usize, column: usize },
+    /// the file doesn't have as many entries as announced.
+    EntryCount { expected: usize, found: usize },
+    /// a symmetric matrix isn't square.
+    NotSquare { rows: usize, columns: usize },
+    /// the matrix has no rows or no columns, which a [`FastMatrix`] can't represent.
+    Empty,
+}
+
+impl Display for MtxError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MtxError::Io(error) => write!(f, "mtx: {error}"),
+            MtxError::InvalidHeader(header) => write!(f, "mtx: invalid header '{header}'"),
+            MtxError::Unsupported(header) => write!(f, "mtx: unsupported matrix '{header}'"),
+            MtxError::InvalidLine { line, text } => write!(f, "mtx: line {line}: invalid line '{text}'"),
+            MtxError::Parse { line, token } => write!(f, "mtx: line {line}: couldn't parse '{token}'"),
+            MtxError::OutOfBounds { line, row, column } => {
+                write!(f, "mtx: line {line}: entry ({row}, {column}) is out of bounds")
+            }
+            MtxError::UpperTriangle { line, row, column } => {
+                write!(f, "mtx: line {line}: entry ({row}, {column}) of a symmetric matrix is above the diagonal")
+            }
+            MtxError::Duplicate { line, row, column } => {
+                write!(f, "mtx: line {line}: entry ({row}, {column}) is listed twice")
+            }
+            MtxError::EntryCount { expected, found } => {
+                write!(f, "mtx: expected {expected} entries, found {found}")
+            }
+            MtxError::NotSquare { rows, columns } => {
+                write!(f, "mtx: a symmetric matrix must be square, found {rows}x{columns}")
+            }
+            MtxError::Empty => write!(f, "mtx: the matrix has no rows or no columns"),
+        }
+    }
+}
+
+impl Error for MtxError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            MtxError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for MtxError {
+    fn from(value: io::Error) -> Self {
+        MtxError::Io(value)
+    }
+}
+
+/// parses the `%%MatrixMarket` line, which is case insensitive.
+fn parse_header(text: &str) -> Result<MtxHeader, MtxError> {
+    let lowercase = text.to_ascii_lowercase();
+    let words: Vec<&str> = lowercase.split_whitespace().collect();
+
+    let [banner, object, format, field, symmetry] = words[..] else {
+        return Err(MtxError::InvalidHeader(text.to_string()));
+    };
+    if banner != "%%matrixmarket" {
+        return Err(MtxError::InvalidHeader(text.to_string()));
+    }
+    if object != "matrix" {
+        return Err(MtxError::Unsupported(text.to_string()));
+    }
+
+    let format = match format {
+        "coordinate" => MtxFormat::Coordinate,
+        "array" => MtxFormat::Array,
+        _ => return Err(MtxError::InvalidHeader(text.to_string())),
+    };
+    let field = match field {
+        "real" | "double" => MtxField::Real,
+        "integer" => MtxField::Integer,
+        "pattern" if format == MtxFormat::Coordinate => MtxField::Pattern,
+        "pattern" | "complex" => return Err(MtxError::Unsupported(text.to_string())),
+        _ => return Err(MtxError::InvalidHeader(text.to_string())),
+    };
+    let symmetry = match symmetry {
+        "general" => MtxSymmetry::General,
+        "symmetric" => MtxSymmetry::Symmetric,
+        "skew-symmetric" | "hermitian" => return Err(MtxError::Unsupported(text.to_string())),
+        _ => return Err(MtxError::InvalidHeader(text.to_string())),
+    };
+
+    Ok(MtxHeader { format, field, symmetry })
+}
+
+/// reads the lines of a Matrix Market file one by one, skipping comments and blank lines.
+struct Lines<R: BufRead> {
+    reader: R,
+    /// the number of the last line read.
+    line: usize,
+    buffer: String,
+}
+
+impl<R: BufRead> Lines<R> {
+    /// reads the next line with content, returning its number.
+    fn next(&mut self) -> Result<Option<usize>, MtxError> {
+        loop {
+            self.buffer.clear();
+            if self.reader.read_line(&mut self.buffer)? == 0 {
+                return Ok(None);
+            }
+            self.line += 1;
+
+            let text = self.buffer.trim();
+            if !text.is_empty() && !text.starts_with('%') {
+                return Ok(Some(self.line));
+            }
+        }
+    }
+
+    fn invalid_line(&self) -> MtxError {
+        MtxError::InvalidLine { line: self.line, text: self.buffer.trim().to_string() }
+    }
+}
+
+/// parses a 1-based index of an entry line.
+fn parse_index<R: BufRead>(lines: &Lines<R>, token: Option<&str>) -> Result<usize, MtxError> {
+    token
+        .and_then(|token| token.parse().ok())
+        .ok_or_else(|| lines.invalid_line())
+}
+
+/// parses a value of an entry line.
+fn parse_value<T: FromStr>(line: usize, token: &str) -> Result<T, MtxError> {
+    token.parse().map_err(|_| MtxError::Parse { line, token: token.to_string() })
+}
+
+impl<T: FromStr + Default + Clone> FastMatrix<T> {
+    /// ## Info
+    /// reads a Matrix Market (`.mtx`) file into a [`FastMatrix`].
+    ///
+    /// both the `coordinate` and `array` formats are supported, with `real`, `integer` or `pattern` values, and `general` or `symmetric` matrices.
+    /// entries missing from a `coordinate` file are `T::default()`, and `pattern` entries are `"1"` parsed as `T`.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::{fast_matrix, FastMatrix};
+    /// use fast_collections::fast_matrix::fast_matrix_mtx::MtxError;
+    ///
+    /// let mtx = "%%MatrixMarket matrix coordinate real symmetric
+    /// % a comment
+    /// 3 3 4
+    /// 1 1 2.0
+    /// 2 1 -1.0
+    /// 2 2 2.0
+    /// 3 3 1.5
+    /// ";
+    ///
+    /// let fast_matrix = FastMatrix::<f64>::from_mtx(mtx.as_bytes()).unwrap();
+    /// assert_eq!(fast_matrix, fast_matrix!([2.0, -1.0, 0.0], [-1.0, 2.0, 0.0], [0.0, 0.0, 1.5]));
+    ///
+    /// // the entry is outside of the 2x2 matrix
+    /// let mtx = "%%MatrixMarket matrix coordinate integer general\n2 2 1\n3 1 7\n";
+    /// let error = FastMatrix::<i32>::from_mtx(mtx.as_bytes()).unwrap_err();
+    /// assert!(matches!(error, MtxError::OutOfBounds { line: 3, row: 3, column: 1 }));
+    /// ```
+    ///
+    /// ## Errors
+    /// see [`MtxError`].
+    pub fn from_mtx(reader: impl BufRead) -> Result<FastMatrix<T>, MtxError> {
+        FastMatrix::from_mtx_with_header(reader).map(|(_, fast_matrix)| fast_matrix)
+    }
+
+    /// ## Info
+    /// does the same as [`FastMatrix::from_mtx`], but also returns the header of the file.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::{fast_matrix, FastMatrix};
+    /// use fast_collections::fast_matrix::fast_matrix_mtx::{MtxFormat, MtxHeader, MtxField, MtxSymmetry};
+    ///
+    /// // array files are stored in column-major order
+    /// let mtx = "%%MatrixMarket matrix array integer general\n2 3\n1\n4\n2\n5\n3\n6\n";
+    /// let (header, fast_matrix) = FastMatrix::<i64>::from_mtx_with_header(mtx.as_bytes()).unwrap();
+    ///
+    /// assert_eq!(header, MtxHeader { format: MtxFormat::Array, field: MtxField::Integer, symmetry: MtxSymmetry::General });
+    /// assert_eq!(fast_matrix, fast_matrix!([1, 2, 3], [4, 5, 6]));
+    /// ```
+    ///
+    /// ## Errors
+    /// see [`MtxError`].
+    pub fn from_mtx_with_header(mut reader: impl BufRead) -> Result<(MtxHeader, FastMatrix<T>), MtxError> {
+        let mut first = String::new();
+        reader.read_line(&mut first)?;
+        let header = parse_header(first.trim())?;
+
+        let mut lines = Lines { reader, line: 1, buffer: String::new() };
+
+        if lines.next()?.is_none() {
+            return Err(MtxError::InvalidLine { line: lines.line + 1, text: String::new() });
+        }
+        let mut sizes = lines.buffer.split_whitespace();
+        let rows = parse_index(&lines, sizes.next())?;
+        let columns = parse_index(&lines, sizes.next())?;
+        let count = match header.format {
+            MtxFormat::Coordinate => Some(parse_index(&lines, sizes.next())?),
+            MtxFormat::Array => None,
+        };
+        if sizes.next().is_some() {
+            return Err(lines.invalid_line());
+        }
+
+        if rows == 0 || columns == 0 {
+            return Err(MtxError::Empty);
+        }
+        if header.symmetry == MtxSymmetry::Symmetric && rows != columns {
+            return Err(MtxError::NotSquare { rows, columns });
+        }
+        let len = rows.checked_mul(columns).ok_or_else(|| lines.invalid_line())?;
+
+        // row-major, like the storage of FastMatrix::new_func
+        let mut elements: Vec<Option<T>> = Vec::new();
+        elements.resize_with(len, || None);
+
+        let found = match count {
+            Some(count) => read_coordinate(&mut lines, header, rows, columns, count, &mut elements)?,
+            None => read_array(&mut lines, header, rows, columns, &mut elements)?,
+        };
+
+        let expected = match (count, header.symmetry) {
+            (Some(count), _) => count,
+            (None, MtxSymmetry::General) => len,
+            (None, MtxSymmetry::Symmetric) => rows * (rows + 1) / 2,
+        };
+        if found != expected {
+            return Err(MtxError::EntryCount { expected, found });
+        }
+
+        let fast_matrix = FastMatrix::new_func(rows, columns, |(row, column)| {
+            elements[row * columns + column].take().unwrap_or_default()
+        });
+
+        Ok((header, fast_matrix))
+    }
+}
+
+/// reads the `row column [value]` lines of a coordinate file, returning how many were found.
+fn read_coordinate<T: FromStr + Clone, R: BufRead>(
+    lines: &mut Lines<R>,
+    header: MtxHeader,
+    rows: usize,
+    columns: usize,
+    count: usize,
+    elements: &mut [Option<T>],
+) -> Result<usize, MtxError> {
+    let mut found = 0;
+
+    while let Some(line) = lines.next()? {
+        found += 1;
+        // keep counting the extra entries, to report how many there are
+        if found > count {
+            continue;
+        }
+
+        let mut tokens = lines.buffer.split_whitespace();
+        let row = parse_index(lines, tokens.next())?;
+        let column = parse_index(lines, tokens.next())?;
+
+        let value: T = match header.field {
+            MtxField::Pattern => parse_value(line, "1")?,
+            _ => parse_value(line, tokens.next().ok_or_else(|| lines.invalid_line())?)?,
+        };
+        if tokens.next().is_some() {
+            return Err(lines.invalid_line());
+        }
+
+        if row == 0 || column == 0 || row > rows || column > columns {
+            return Err(MtxError::OutOfBounds { line, row, column });
+        }
+        if header.symmetry == MtxSymmetry::Symmetric && row < column {
+            return Err(MtxError::UpperTriangle { line, row, column });
+        }
+
+        let index = (row - 1) * columns + column - 1;
+        if elements[index].is_some() {
+            return Err(MtxError::Duplicate { line, row, column });
+        }
+
+        if header.symmetry == MtxSymmetry::Symmetric && row != column {
+            elements[(column - 1) * columns + row - 1] = Some(value.clone());
+        }
+        elements[index] = Some(value);
+    }
+
+    Ok(found)
+}
+
+/// reads the values of an array file in column-major order, returning how many were found.
+fn read_array<T: FromStr + Clone, R: BufRead>(
+    lines: &mut Lines<R>,
+    header: MtxHeader,
+    rows: usize,
+    columns: usize,
+    elements: &mut [Option<T>],
+) -> Result<usize, MtxError> {
+    // the (row, column) of every stored value, in order
+    let mut positions = (0..columns).flat_map(|column| {
+        let first_row = match header.symmetry {
+            MtxSymmetry::General => 0,
+            MtxSymmetry::Symmetric => column,
+        };
+        (first_row..rows).map(move |row| (row, column))
+    });
+    let mut found = 0;
+
+    while let Some(line) = lines.next()? {
+        for token in lines.buffer.split_whitespace() {
+            found += 1;
+
+            // keep counting the extra values, to report how many there are
+            let Some((row, column)) = positions.next() else {
+                continue;
+            };
+
+            let value: T = parse_value(line, token)?;
+            if header.symmetry == MtxSymmetry::Symmetric && row != column {
+                elements[column * columns + row] = Some(value.clone());
+            }
+            elements[row * columns + column] = Some(value);
+        }
+    }
+
+    Ok(found)
+}
+
+impl<T: Display + Default + PartialEq> FastMatrix<T> {
+    /// ## Info
+    /// writes the [`FastMatrix`] as a Matrix Market (`.mtx`) file, in the layout described by `header`.
+    ///
+    /// the `coordinate` format only writes the elements that aren't `T::default()`, and the `pattern` field only writes their positions.
+    /// the `symmetric` symmetry only writes the lower triangle.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::{fast_matrix, FastMatrix};
+    /// use fast_collections::fast_matrix::fast_matrix_mtx::{MtxHeader, MtxSymmetry};
+    ///
+    /// let fast_matrix = fast_matrix!([4.0, 0.0], [0.0, 2.5]);
+    /// let header = MtxHeader { symmetry: MtxSymmetry::Symmetric, ..Default::default() };
+    ///
+    /// let mut mtx = Vec::new();
+    /// fast_matrix.to_mtx(&mut mtx, header).unwrap();
+    ///
+    /// assert_eq!(
+    ///     String::from_utf8(mtx.clone()).unwrap(),
+    ///     "%%MatrixMarket matrix coordinate real symmetric\n2 2 2\n1 1 4\n2 2 2.5\n"
+    /// );
+    /// assert_eq!(FastMatrix::<f64>::from_mtx(mtx.as_slice()).unwrap(), fast_matrix);
+    /// ```
+    ///
+    /// ## Errors
+    /// - [`io::ErrorKind::InvalidInput`] if `header` asks for the `array` format with the `pattern` field, or for a `symmetric` matrix while self isn't symmetric.
+    /// - any error returned by `writer`.
+    pub fn to_mtx(&self, mut writer: impl Write, header: MtxHeader) -> io::Result<()> {
+        if header.format == MtxFormat::Array && header.field == MtxField::Pattern {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "mtx: the array format can't be used with the pattern field",
+            ));
+        }
+
+        let symmetric = header.symmetry == MtxSymmetry::Symmetric;
+        if symmetric && !self.is_symmetric() {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "mtx: the matrix isn't symmetric"));
+        }
+
+        // column by column, only the lower triangle for symmetric matrices
+        let positions = || {
+            (0..self.columns).flat_map(move |column| {
+                let first_row = if symmetric { column } else { 0 };
+                (first_row..self.rows).map(move |row| (row, column))
+            })
+        };
+
+        writeln!(writer, "{header}")?;
+
+        match header.format {
+            MtxFormat::Coordinate => {
+                let zero = T::default();
+                let count = positions().filter(|&position| self[position] != zero).count();
+                writeln!(writer, "{} {} {count}", self.rows, self.columns)?;
+
+                for (row, column) in positions().filter(|&position| self[position] != zero) {
+                    match header.field {
+                        MtxField::Pattern => writeln!(writer, "{} {}", row + 1, column + 1)?,
+                        _ => writeln!(writer, "{} {} {}", row + 1, column + 1, self[(row, column)])?,
+                    }
+                }
+            }
+            MtxFormat::Array => {
+                writeln!(writer, "{} {}", self.rows, self.columns)?;
+
+                for position in positions() {
+                    writeln!(writer, "{}", self[position])?;
+                }
+            }
+        }
+
+        writer.flush()
+    }
+
+    /// returns `true` if self is square and equal to its transpose.
+    fn is_symmetric(&self) -> bool {
+        self.rows == self.columns
+            && (0..self.rows).all(|row| (0..row).all(|column| self[(row, column)] == self[(column, row)]))
+    }
+}
@@ -0,0 +1,404 @@
+use std::{
+    fmt::Display,
+    ops::{AddAssign, Div, DivAssign, Index, IndexMut, Mul, SubAssign},
+    ptr,
+};
+
+use crate::{alloc, fast_array::fast_array_n::FastArrayN, FastMatrix};
+
+/// ## Info
+/// a [`FastMatrix`] whose shape is known at compile time, so it lives on the stack instead of the heap.
+///
+/// the elements are stored row after row, and operations like [`FastMatrixN::matmul`] check the shapes at compile time.
+///
+/// ## Example
+/// ```
+/// use fast_collections::fast_matrix;
+///
+/// let fast_matrix = fast_matrix!(const [1, 2, 3], [4, 5, 6]);
+///
+/// assert_eq!(fast_matrix.shape(), (2, 3));
+/// assert_eq!(fast_matrix[(1, 2)], 6);
+/// assert_eq!(fast_matrix.transpose(), fast_matrix!(const [1, 4], [2, 5], [3, 6]));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(align(32))]
+pub struct FastMatrixN<T, const R: usize, const C: usize> {
+    pub(crate) data: [[T; C]; R],
+}
+
+impl<T, const R: usize, const C: usize> FastMatrixN<T, R, C> {
+    /// the number of rows of the matrix.
+    pub const ROWS: usize = R;
+    /// the number of columns of the matrix.
+    pub const COLUMNS: usize = C;
+
+    #[inline(always)]
+    /// ## Info
+    /// creates a new [`FastMatrixN`] from a closure or function that accepts a tuple of (`row`, `column`) that's being set.
+    pub fn new_func<F: FnMut((usize, usize)) -> T>(mut func: F) -> FastMatrixN<T, R, C> {
+        FastMatrixN {
+            data: std::array::from_fn(|row| std::array::from_fn(|column| func((row, column)))),
+        }
+    }
+
+    #[inline(always)]
+    /// ## Info
+    /// returns the `(rows, columns)` of the [`FastMatrixN`], aka `(R, C)`.
+    pub const fn shape(&self) -> (usize, usize) {
+        (R, C)
+    }
+
+    #[inline(always)]
+    /// ## Info
+    /// gets a reference to the element at `index`, or `None` if it is out of bounds.
+    pub fn get(&self, index: (usize, usize)) -> Option<&T> {
+        self.data.get(index.0).and_then(|row| row.get(index.1))
+    }
+
+    #[inline(always)]
+    /// ## Info
+    /// swaps the elements at the two indices.
+    ///
+    /// ## Panics
+    /// if either index is out of bounds.
+    pub fn swap(&mut self, index1: (usize, usize), index2: (usize, usize)) {
+        assert!(
+            index1.0 < R && index1.1 < C && index2.0 < R && index2.1 < C,
+            "FastMatrixN: tried to index out of bounds."
+        );
+
+        let flat = self.data.as_flattened_mut();
+        flat.swap(index1.0 * C + index1.1, index2.0 * C + index2.1);
+    }
+
+    #[inline(always)]
+    /// ## Info
+    /// swaps two rows.
+    ///
+    /// ## Panics
+    /// if either row is out of bounds.
+    pub fn swap_rows(&mut self, row1: usize, row2: usize) {
+        self.data.swap(row1, row2);
+    }
+
+    /// ## Info
+    /// swaps two columns.
+    ///
+    /// ## Panics
+    /// if either column is out of bounds.
+    pub fn swap_columns(&mut self, column1: usize, column2: usize) {
+        assert!(
+            column1 < C && column2 < C,
+            "FastMatrixN: tried to index out of bounds."
+        );
+
+        for row in self.data.iter_mut() {
+            row.swap(column1, column2);
+        }
+    }
+
+    #[inline(always)]
+    /// ## Info
+    /// creates an iterator over the elements of the [`FastMatrixN`], row after row.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.data.as_flattened().iter()
+    }
+
+    #[inline(always)]
+    /// ## Info
+    /// creates an iterator over mutable references to the elements of the [`FastMatrixN`], row after row.
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.data.as_flattened_mut().iter_mut()
+    }
+
+    #[inline(always)]
+    /// ## Info
+    /// borrows the elements as a slice, row after row.
+    pub fn as_slice(&self) -> &[T] {
+        self.data.as_flattened()
+    }
+
+    #[inline(always)]
+    /// ## Info
+    /// returns the underlying array of rows.
+    pub fn into_inner(self) -> [[T; C]; R] {
+        self.data
+    }
+
+    /// ## Info
+    /// moves the elements into a heap-allocated, row-major [`FastMatrix`].
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::fast_matrix;
+    ///
+    /// assert_eq!(fast_matrix!(const [1, 2], [3, 4]).into_fast_matrix(), fast_matrix!([1, 2], [3, 4]));
+    /// ```
+    ///
+    /// ## Panics
+    /// if `R == 0` or `C == 0`.
+    pub fn into_fast_matrix(self) -> FastMatrix<T> {
+        let mut elements = self.data.into_iter().flatten();
+        FastMatrix::new_func(R, C, |_| elements.next().unwrap())
+    }
+}
+
+impl<T: Clone, const R: usize, const C: usize> FastMatrixN<T, R, C> {
+    #[inline(always)]
+    /// ## Info
+    /// creates a new [`FastMatrixN`] filled with `fill_value`.
+    pub fn new(fill_value: T) -> FastMatrixN<T, R, C> {
+        FastMatrixN::new_func(|_| fill_value.clone())
+    }
+
+    #[inline(always)]
+    /// ## Info
+    /// clones the given `row` into a [`FastArrayN`].
+    ///
+    /// ## Panics
+    /// if `row` is out of bounds.
+    pub fn get_row(&self, row: usize) -> FastArrayN<T, C> {
+        FastArrayN::from(self.data[row].clone())
+    }
+
+    #[inline(always)]
+    /// ## Info
+    /// clones the given `column` into a [`FastArrayN`].
+    ///
+    /// ## Panics
+    /// if `column` is out of bounds.
+    pub fn get_column(&self, column: usize) -> FastArrayN<T, R> {
+        assert!(column < C, "FastMatrixN: tried to index out of bounds.");
+        FastArrayN::new_func(|row| self.data[row][column].clone())
+    }
+
+    #[inline(always)]
+    /// ## Info
+    /// returns a new [`FastMatrixN`] that is the transpose of self.
+    pub fn transpose(&self) -> FastMatrixN<T, C, R> {
+        FastMatrixN::new_func(|(row, column)| self.data[column][row].clone())
+    }
+}
+
+impl<T: Default, const R: usize, const C: usize> FastMatrixN<T, R, C> {
+    #[inline(always)]
+    /// ## Info
+    /// creates a new [`FastMatrixN`] filled with the [`Default`] value of T.
+    pub fn new_default() -> FastMatrixN<T, R, C> {
+        FastMatrixN::new_func(|_| T::default())
+    }
+}
+
+impl<T: Default, const R: usize, const C: usize> Default for FastMatrixN<T, R, C> {
+    fn default() -> Self {
+        FastMatrixN::new_default()
+    }
+}
+
+impl<T, const R: usize, const K: usize> FastMatrixN<T, R, K>
+where
+    T: Clone + Default + AddAssign + Mul<Output = T>,
+{
+    /// ## Info
+    /// returns the matrix product `self * rhs`; the inner dimensions are checked at compile time.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::fast_matrix;
+    ///
+    /// let a = fast_matrix!(const [1, 2, 3], [4, 5, 6]);
+    /// let b = fast_matrix!(const [1, 0], [0, 1], [1, 1]);
+    ///
+    /// assert_eq!(a.matmul(&b), fast_matrix!(const [4, 5], [10, 11]));
+    /// ```
+    pub fn matmul<const C: usize>(&self, rhs: &FastMatrixN<T, K, C>) -> FastMatrixN<T, R, C> {
+        FastMatrixN::new_func(|(row, column)| {
+            let mut element = T::default();
+            for k in 0..K {
+                element += self.data[row][k].clone() * rhs.data[k][column].clone();
+            }
+            element
+        })
+    }
+}
+
+impl<T, const R: usize, const K: usize, const C: usize> Mul<&FastMatrixN<T, K, C>> for &FastMatrixN<T, R, K>
+where
+    T: Clone + Default + AddAssign + Mul<Output = T>,
+{
+    type Output = FastMatrixN<T, R, C>;
+
+    fn mul(self, rhs: &FastMatrixN<T, K, C>) -> Self::Output {
+        self.matmul(rhs)
+    }
+}
+
+impl<T, const N: usize> FastMatrixN<T, N, N>
+where
+    T: Clone
+        + Default
+        + PartialOrd
+        + Mul<Output = T>
+        + Div<Output = T>
+        + DivAssign
+        + SubAssign
+        + AddAssign
+        + TryFrom<f64>,
+
+    <T as TryFrom<f64>>::Error: std::fmt::Debug
+{
+    /// ## Info
+    /// returns the identity matrix.
+    pub fn identity() -> FastMatrixN<T, N, N> {
+        let one = T::try_from(1.0).expect("FastMatrixN: identity: couldn't convert T to f64");
+        FastMatrixN::new_func(|(row, column)| if row == column { one.clone() } else { T::default() })
+    }
+
+    /// ## Info
+    /// returns the determinant of self, with the same elimination as [`FastMatrix::determinant`] but without touching the heap.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::fast_matrix;
+    ///
+    /// let fast_matrix = fast_matrix!(const [1.0, 4.0], [2.0, 3.0]);
+    ///
+    /// assert_eq!(fast_matrix.determinant(), -5.0);
+    /// ```
+    pub fn determinant(&self) -> T {
+        let mut mat = self.data.clone();
+        let mut det = T::try_from(1.0).expect("FastMatrixN: determinant: couldn't convert T to f64");
+        let mut sign = T::try_from(1.0).expect("FastMatrixN: determinant: couldn't convert T to f64");
+
+        for k in 0..N {
+            let mut pivot = k;
+            for i in k + 1..N {
+                if mat[i][k] > mat[pivot][k] {
+                    pivot = i;
+                }
+            }
+
+            if pivot != k {
+                mat.swap(pivot, k);
+                sign = sign * T::try_from(-1.0).expect("FastMatrixN: determinant: couldn't convert T to f64");
+            }
+
+            let pivot_value = mat[k][k].clone();
+            if pivot_value == T::try_from(0.0).expect("FastMatrixN: determinant: couldn't convert T to f64") {
+                return T::try_from(0.0).expect("FastMatrixN: determinant: couldn't convert T to f64");
+            }
+
+            det = det * pivot_value.clone();
+
+            let pivot_row = mat[k].clone();
+            for row in mat[k + 1..].iter_mut() {
+                let factor = row[k].clone() / pivot_value.clone();
+                for (element, pivot_element) in row[k..].iter_mut().zip(&pivot_row[k..]) {
+                    *element -= factor.clone() * pivot_element.clone();
+                }
+            }
+        }
+
+        det * sign
+    }
+}
+
+impl<T, const R: usize, const C: usize> From<[[T; C]; R]> for FastMatrixN<T, R, C> {
+    #[inline(always)]
+    fn from(value: [[T; C]; R]) -> Self {
+        FastMatrixN { data: value }
+    }
+}
+
+impl<T, const R: usize, const C: usize> From<FastMatrixN<T, R, C>> for FastMatrix<T> {
+    /// ## Panics
+    /// if `R == 0` or `C == 0`.
+    fn from(value: FastMatrixN<T, R, C>) -> Self {
+        value.into_fast_matrix()
+    }
+}
+
+impl<T, const R: usize, const C: usize> TryFrom<FastMatrix<T>> for FastMatrixN<T, R, C> {
+    /// the [`FastMatrix`] is given back if its shape isn't `R x C`.
+    type Error = FastMatrix<T>;
+
+    /// ## Info
+    /// moves the elements of a `R x C` [`FastMatrix`] on the stack, whatever its storage order is.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::fast_matrix;
+    /// use fast_collections::fast_matrix::fast_matrix_n::FastMatrixN;
+    ///
+    /// let fast_matrix: FastMatrixN<i32, 2, 2> = fast_matrix!([1, 2], [3, 4]).try_into().unwrap();
+    /// assert_eq!(fast_matrix, fast_matrix!(const [1, 2], [3, 4]));
+    ///
+    /// assert!(FastMatrixN::<i32, 2, 3>::try_from(fast_matrix!([1, 2], [3, 4])).is_err());
+    /// ```
+    fn try_from(mut value: FastMatrix<T>) -> Result<Self, Self::Error> {
+        if value.rows != R || value.columns != C {
+            return Err(value);
+        }
+
+        let data = std::array::from_fn(|row| {
+            std::array::from_fn(|column| unsafe { ptr::read(value.pointer.add(value.offset((row, column)))) })
+        });
+
+        // the elements have been moved out, so only the buffer is left to free
+        unsafe { alloc::deallocate(value.pointer, R * C) };
+        value.pointer = ptr::null_mut();
+
+        Ok(FastMatrixN { data })
+    }
+}
+
+impl<T, const R: usize, const C: usize> Index<(usize, usize)> for FastMatrixN<T, R, C> {
+    type Output = T;
+
+    #[inline(always)]
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        &self.data[index.0][index.1]
+    }
+}
+
+impl<T, const R: usize, const C: usize> IndexMut<(usize, usize)> for FastMatrixN<T, R, C> {
+    #[inline(always)]
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+        &mut self.data[index.0][index.1]
+    }
+}
+
+impl<T: Display, const R: usize, const C: usize> Display for FastMatrixN<T, R, C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("[")?;
+
+        for (row_index, row) in self.data.iter().enumerate() {
+            if row_index == 0 {
+                f.write_str("\n    [")?;
+            } else {
+                f.write_str(",\n    [")?;
+            }
+
+            for (column_index, element) in row.iter().enumerate() {
+                if column_index == 0 {
+                    write!(f, "{}", element)?;
+                } else {
+                    write!(f, ", {}", element)?;
+                }
+            }
+
+            f.write_str("]")?;
+        }
+
+        f.write_str("\n]")
+    }
+}
+
+impl<T: PartialEq, const R: usize, const C: usize> PartialEq<FastMatrix<T>> for FastMatrixN<T, R, C> {
+    fn eq(&self, other: &FastMatrix<T>) -> bool {
+        other.rows == R
+            && other.columns == C
+            && (0..R).all(|row| (0..C).all(|column| self.data[row][column] == other[(row, column)]))
+    }
+}
@@ -0,0 +1,310 @@
+#![cfg(feature = "serde")]
+
+//! ## Info
+//! the `serde` representations of [`FastMatrix`].
+//!
+//! by default, a [`FastMatrix`] is serialized with its shape and its elements in row-major order, whatever its [`StorageOrder`] is:
+//! - as a `{ rows, columns, data }` struct for human readable formats (like json).
+//! - as a `(rows, columns, data)` tuple for binary formats, so no field names are written.
+//!
+//! the [`flat`] and [`nested`] modules can be used with `#[serde(with = "...")]` to pick a representation explicitly.
+
+use std::{fmt::Formatter, marker::PhantomData, ptr};
+
+use serde::{
+    de::{self, DeserializeSeed, MapAccess, SeqAccess, Visitor},
+    ser::{SerializeSeq, SerializeStruct, SerializeTuple},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use super::fast_matrix::{FastMatrix, StorageOrder};
+use crate::{fast_array::fast_array_builder::FastArrayBuilder, FastArray};
+
+const FIELDS: &[&str] = &["rows", "columns", "data"];
+
+/// the elements of a [`FastMatrix`] as a single sequence in row-major order, serialized without copying them.
+struct RowMajor<'a, T>(&'a FastMatrix<T>);
+
+impl<T: Serialize> Serialize for RowMajor<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let fast_matrix = self.0;
+        let mut state = serializer.serialize_seq(Some(fast_matrix.rows * fast_matrix.columns))?;
+
+        for row in 0..fast_matrix.rows {
+            for column in 0..fast_matrix.columns {
+                state.serialize_element(&fast_matrix[(row, column)])?;
+            }
+        }
+
+        state.end()
+    }
+}
+
+/// moves the elements of `data`, in row-major order, into a [`FastMatrix`] without copying them.
+fn from_parts<T, E: de::Error>(rows: usize, columns: usize, mut data: FastArray<T>) -> Result<FastMatrix<T>, E> {
+    if rows.checked_mul(columns) != Some(data.size) {
+        return Err(E::custom(format!(
+            "expected {rows} * {columns} elements, found {}",
+            data.size
+        )));
+    }
+
+    // both buffers are allocated the same way, so the FastMatrix can take ownership of it
+    let pointer = data.pointer;
+    data.pointer = ptr::null_mut();
+
+    Ok(FastMatrix { pointer, rows, columns, order: StorageOrder::RowMajor })
+}
+
+impl<T: Serialize> Serialize for FastMatrix<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("FastMatrix", 3)?;
+            state.serialize_field("rows", &self.rows)?;
+            state.serialize_field("columns", &self.columns)?;
+            state.serialize_field("data", &RowMajor(self))?;
+            state.end()
+        } else {
+            let mut state = serializer.serialize_tuple(3)?;
+            state.serialize_element(&self.rows)?;
+            state.serialize_element(&self.columns)?;
+            state.serialize_element(&RowMajor(self))?;
+            state.end()
+        }
+    }
+}
+
+/// the fields of the struct representation.
+enum Field {
+    Rows,
+    Columns,
+    Data,
+    Ignored,
+}
+
+impl<'de> Deserialize<'de> for Field {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FieldVisitor;
+
+        impl Visitor<'_> for FieldVisitor {
+            type Value = Field;
+
+            fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+                formatter.write_str("`rows`, `columns` or `data`")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Field, E> {
+                Ok(match value {
+                    "rows" => Field::Rows,
+                    "columns" => Field::Columns,
+                    "data" => Field::Data,
+                    _ => Field::Ignored,
+                })
+            }
+        }
+
+        deserializer.deserialize_identifier(FieldVisitor)
+    }
+}
+
+struct FlatVisitor<T> {
+    phant: PhantomData<T>,
+}
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for FlatVisitor<T> {
+    type Value = FastMatrix<T>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("a FastMatrix as `rows`, `columns` and `data`")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let rows = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let columns = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        let data = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(2, &self))?;
+
+        from_parts(rows, columns, data)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut rows = None;
+        let mut columns = None;
+        let mut data: Option<FastArray<T>> = None;
+
+        while let Some(field) = map.next_key()? {
+            match field {
+                Field::Rows if rows.is_some() => return Err(de::Error::duplicate_field("rows")),
+                Field::Columns if columns.is_some() => return Err(de::Error::duplicate_field("columns")),
+                Field::Data if data.is_some() => return Err(de::Error::duplicate_field("data")),
+                Field::Rows => rows = Some(map.next_value()?),
+                Field::Columns => columns = Some(map.next_value()?),
+                Field::Data => data = Some(map.next_value()?),
+                Field::Ignored => {
+                    map.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+
+        let rows = rows.ok_or_else(|| de::Error::missing_field("rows"))?;
+        let columns = columns.ok_or_else(|| de::Error::missing_field("columns"))?;
+        let data = data.ok_or_else(|| de::Error::missing_field("data"))?;
+
+        from_parts(rows, columns, data)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for FastMatrix<T> {
+    /// ## Info
+    /// deserializes a [`FastMatrix`] from the representation written by its [`Serialize`] implementation.
+    ///
+    /// the elements are moved straight into the matrix, and `data` must have exactly `rows * columns` elements.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let visitor = FlatVisitor { phant: PhantomData };
+
+        match deserializer.is_human_readable() {
+            true => deserializer.deserialize_struct("FastMatrix", FIELDS, visitor),
+            false => deserializer.deserialize_tuple(3, visitor),
+        }
+    }
+}
+
+/// ## Info
+/// the default representation of [`FastMatrix`], its shape followed by its elements in row-major order.
+///
+/// ## Example
+/// ```ignore
+/// #[derive(Serialize, Deserialize)]
+/// struct Model {
+///     #[serde(with = "fast_collections::fast_matrix::fast_matrix_serde::flat")]
+///     weights: FastMatrix<f32>,
+/// }
+/// ```
+pub mod flat {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::FastMatrix;
+
+    pub fn serialize<T: Serialize, S: Serializer>(fast_matrix: &FastMatrix<T>, serializer: S) -> Result<S::Ok, S::Error> {
+        fast_matrix.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, T: Deserialize<'de>, D: Deserializer<'de>>(deserializer: D) -> Result<FastMatrix<T>, D::Error> {
+        FastMatrix::deserialize(deserializer)
+    }
+}
+
+/// ## Info
+/// represents a [`FastMatrix`] as a sequence of rows, like `[[1, 2], [3, 4]]`.
+///
+/// every row must have the same number of elements, and none of them can be empty.
+///
+/// ## Example
+/// ```ignore
+/// #[derive(Serialize, Deserialize)]
+/// struct Board {
+///     #[serde(with = "fast_collections::fast_matrix::fast_matrix_serde::nested")]
+///     cells: FastMatrix<u8>,
+/// }
+/// ```
+pub mod nested {
+    use std::{fmt::Formatter, marker::PhantomData};
+
+    use serde::{
+        de::{self, SeqAccess, Visitor},
+        ser::SerializeSeq,
+        Deserialize, Deserializer, Serialize, Serializer,
+    };
+
+    use super::{from_parts, RowSeed};
+    use crate::{fast_array::fast_array_builder::FastArrayBuilder, FastMatrix};
+
+    /// a single row of a [`FastMatrix`], serialized without copying it.
+    struct Row<'a, T> {
+        fast_matrix: &'a FastMatrix<T>,
+        row: usize,
+    }
+
+    impl<T: Serialize> Serialize for Row<'_, T> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut state = serializer.serialize_seq(Some(self.fast_matrix.columns))?;
+            for column in 0..self.fast_matrix.columns {
+                state.serialize_element(&self.fast_matrix[(self.row, column)])?;
+            }
+            state.end()
+        }
+    }
+
+    pub fn serialize<T: Serialize, S: Serializer>(fast_matrix: &FastMatrix<T>, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_seq(Some(fast_matrix.rows))?;
+        for row in 0..fast_matrix.rows {
+            state.serialize_element(&Row { fast_matrix, row })?;
+        }
+        state.end()
+    }
+
+    struct NestedVisitor<T> {
+        phant: PhantomData<T>,
+    }
+
+    impl<'de, T: Deserialize<'de>> Visitor<'de> for NestedVisitor<T> {
+        type Value = FastMatrix<T>;
+
+        fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+            formatter.write_str("a non-empty sequence of rows of the same len")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            // every row is pushed straight into the same buffer
+            let mut builder = FastArrayBuilder::with_capacity(0);
+            let mut rows = 0;
+            let mut columns = None;
+
+            while let Some(len) = seq.next_element_seed(RowSeed { builder: &mut builder })? {
+                let expected = *columns.get_or_insert(len);
+                if len != expected {
+                    return Err(de::Error::custom(format!(
+                        "row {rows} has {len} elements, but the first one has {expected}"
+                    )));
+                }
+                rows += 1;
+            }
+
+            let data = builder.finish().ok_or_else(|| de::Error::invalid_length(0, &self))?;
+            from_parts(rows, columns.unwrap_or(0), data)
+        }
+    }
+
+    pub fn deserialize<'de, T: Deserialize<'de>, D: Deserializer<'de>>(deserializer: D) -> Result<FastMatrix<T>, D::Error> {
+        deserializer.deserialize_seq(NestedVisitor { phant: PhantomData })
+    }
+}
+
+/// deserializes a row into a [`FastArrayBuilder`], returning its len.
+struct RowSeed<'a, T> {
+    builder: &'a mut FastArrayBuilder<T>,
+}
+
+impl<'de, T: Deserialize<'de>> DeserializeSeed<'de> for RowSeed<'_, T> {
+    type Value = usize;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<usize, D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for RowSeed<'_, T> {
+    type Value = usize;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("a row of elements")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<usize, A::Error> {
+        let mut len = 0;
+        while let Some(element) = seq.next_element()? {
+            self.builder.push(element);
+            len += 1;
+        }
+        Ok(len)
+    }
+}
@@ -0,0 +1,351 @@
+use crate::{FastArray, FastMatrix};
+
+use super::{
+    fast_matrix::StorageOrder,
+    fast_matrix_decompositions::{identity, DecompositionError, Float},
+};
+
+/// maximum number of Jacobi sweeps before giving up on convergence.
+const MAX_SWEEPS: usize = 64;
+
+impl<T: Float> FastMatrix<T> {
+    /// ## Info
+    /// computes the eigen-decomposition of a symmetric matrix using the cyclic Jacobi method, returning `(eigenvalues, eigenvectors)`.
+    ///
+    /// the eigenvalues are sorted in descending order, and the `i`-th column of `eigenvectors` is the unit eigenvector of the `i`-th eigenvalue.
+    ///
+    /// ## Errors
+    /// if self isn't square or symmetric.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::fast_matrix;
+    ///
+    /// let fast_matrix = fast_matrix!([2.0, 1.0], [1.0, 2.0]);
+    /// let (eigenvalues, eigenvectors) = fast_matrix.symmetric_eigen().unwrap();
+    /// let (largest, smallest): (f64, f64) = (eigenvalues[0], eigenvalues[1]);
+    ///
+    /// assert!((largest - 3.0).abs() < 1e-12);
+    /// assert!((smallest - 1.0).abs() < 1e-12);
+    ///
+    /// // A * v == lambda * v
+    /// for row in 0..2 {
+    ///     let product: f64 = (0..2).map(|k| fast_matrix[(row, k)] * eigenvectors[(k, 0)]).sum();
+    ///     assert!((product - largest * eigenvectors[(row, 0)]).abs() < 1e-12);
+    /// }
+    /// ```
+    pub fn symmetric_eigen(&self) -> Result<(FastArray<T>, FastMatrix<T>), DecompositionError> {
+        self.check_symmetric()?;
+
+        let n = self.rows;
+        let mut a = self.to_order(StorageOrder::RowMajor);
+        let mut v = identity::<T>(n);
+
+        let mut norm = T::ZERO;
+        for i in 0..n * n {
+            let element = unsafe { *a.pointer.add(i) };
+            norm += element * element;
+        }
+        let threshold = T::EPSILON * T::EPSILON * norm;
+
+        for _ in 0..MAX_SWEEPS {
+            let mut off_diagonal = T::ZERO;
+            for p in 0..n {
+                for q in p + 1..n {
+                    let element = unsafe { *a.pointer.add(p * n + q) };
+                    off_diagonal += element * element;
+                }
+            }
+
+            if off_diagonal <= threshold {
+                break;
+            }
+
+            for p in 0..n {
+                for q in p + 1..n {
+                    let apq = unsafe { *a.pointer.add(p * n + q) };
+                    if apq == T::ZERO {
+                        continue;
+                    }
+
+                    let app = unsafe { *a.pointer.add(p * n + p) };
+                    let aqq = unsafe { *a.pointer.add(q * n + q) };
+                    let (c, s) = jacobi_rotation(app, aqq, apq);
+
+                    // A = J^T * A * J, V = V * J
+                    rotate_columns(&mut a, p, q, c, s);
+                    rotate_rows(&mut a, p, q, c, s);
+                    rotate_columns(&mut v, p, q, c, s);
+                }
+            }
+        }
+
+        let eigenvalues = FastArray::new_func(n, |i| unsafe { *a.pointer.add(i * n + i) });
+
+        let order = descending_order(&eigenvalues);
+        let sorted_eigenvalues = FastArray::new_func(n, |i| eigenvalues[order[i]]);
+
+        Ok((sorted_eigenvalues, reorder_columns(&v, &order)))
+    }
+
+    /// ## Info
+    /// computes the thin singular value decomposition of self using one-sided Jacobi rotations, returning `(U, S, Vt)`.
+    ///
+    /// for a `m x n` matrix and `k = min(m, n)`, `U` is `m x k`, `S` holds the `k` singular values in descending order and `Vt` is `k x n`, so that `U * diag(S) * Vt == self`.
+    ///
+    /// the columns of `U` belonging to a singular value of zero are left as zeros.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::fast_matrix;
+    ///
+    /// let fast_matrix = fast_matrix!([3.0, 2.0, 2.0], [2.0, 3.0, -2.0]);
+    /// let (u, s, vt) = fast_matrix.svd();
+    /// let (first, second): (f64, f64) = (s[0], s[1]);
+    ///
+    /// assert!((first - 5.0).abs() < 1e-12);
+    /// assert!((second - 3.0).abs() < 1e-12);
+    ///
+    /// for row in 0..2 {
+    ///     for column in 0..3 {
+    ///         let element: f64 = (0..2).map(|k| u[(row, k)] * s[k] * vt[(k, column)]).sum();
+    ///         assert!((element - fast_matrix[(row, column)]).abs() < 1e-12);
+    ///     }
+    /// }
+    /// ```
+    pub fn svd(&self) -> (FastMatrix<T>, FastArray<T>, FastMatrix<T>) {
+        if self.rows < self.columns {
+            // A^T = U * S * Vt  =>  A = Vt^T * S * U^T
+            let (u, s, vt) = self.transpose().svd();
+            return (vt.transpose(), s, u.transpose());
+        }
+
+        let m = self.rows;
+        let n = self.columns;
+        let mut u = self.to_order(StorageOrder::RowMajor);
+        let mut v = identity::<T>(n);
+
+        for _ in 0..MAX_SWEEPS {
+            let mut converged = true;
+
+            for p in 0..n {
+                for q in p + 1..n {
+                    let mut alpha = T::ZERO;
+                    let mut beta = T::ZERO;
+                    let mut gamma = T::ZERO;
+
+                    for i in 0..m {
+                        let up = unsafe { *u.pointer.add(i * n + p) };
+                        let uq = unsafe { *u.pointer.add(i * n + q) };
+                        alpha += up * up;
+                        beta += uq * uq;
+                        gamma += up * uq;
+                    }
+
+                    if gamma.abs() <= T::EPSILON * (alpha * beta).sqrt() {
+                        continue;
+                    }
+                    converged = false;
+
+                    let (c, s) = jacobi_rotation(alpha, beta, gamma);
+                    rotate_columns(&mut u, p, q, c, s);
+                    rotate_columns(&mut v, p, q, c, s);
+                }
+            }
+
+            if converged {
+                break;
+            }
+        }
+
+        let singular_values = FastArray::new_func(n, |column| {
+            let mut norm = T::ZERO;
+            for row in 0..m {
+                let element = unsafe { *u.pointer.add(row * n + column) };
+                norm += element * element;
+            }
+            norm.sqrt()
+        });
+
+        for column in 0..n {
+            let sigma = singular_values[column];
+            for row in 0..m {
+                unsafe {
+                    let element = u.pointer.add(row * n + column);
+                    *element = if sigma == T::ZERO { T::ZERO } else { *element / sigma };
+                }
+            }
+        }
+
+        let order = descending_order(&singular_values);
+        let sorted_singular_values = FastArray::new_func(n, |i| singular_values[order[i]]);
+
+        (
+            reorder_columns(&u, &order),
+            sorted_singular_values,
+            reorder_columns(&v, &order).transpose(),
+        )
+    }
+
+    /// ## Info
+    /// computes the Moore-Penrose pseudo-inverse of self through its singular value decomposition.
+    ///
+    /// singular values smaller than `EPSILON * max(rows, columns) * largest_singular_value` are treated as zero.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::fast_matrix;
+    ///
+    /// let fast_matrix = fast_matrix!([1.0, 2.0], [3.0, 4.0], [5.0, 6.0]);
+    /// let pseudo_inverse = fast_matrix.pseudo_inverse();
+    ///
+    /// assert_eq!((pseudo_inverse.rows, pseudo_inverse.columns), (2, 3));
+    ///
+    /// // A+ * A == I for a matrix with full column rank
+    /// for row in 0..2 {
+    ///     for column in 0..2 {
+    ///         let element: f64 = (0..3).map(|k| pseudo_inverse[(row, k)] * fast_matrix[(k, column)]).sum();
+    ///         let expected = if row == column { 1.0 } else { 0.0 };
+    ///         assert!((element - expected).abs() < 1e-9);
+    ///     }
+    /// }
+    /// ```
+    pub fn pseudo_inverse(&self) -> FastMatrix<T> {
+        let (u, s, vt) = self.svd();
+        let k = s.len();
+        let tolerance = self.singular_value_tolerance(&s);
+
+        let func = |(row, column): (usize, usize)| {
+            let mut element = T::ZERO;
+            for i in 0..k {
+                if s[i] > tolerance {
+                    element += vt[(i, row)] * u[(column, i)] / s[i];
+                }
+            }
+            element
+        };
+
+        FastMatrix::new_func(self.columns, self.rows, func)
+    }
+
+    /// ## Info
+    /// returns the 2-norm condition number of self, aka the ratio between its largest and smallest singular values.
+    ///
+    /// if the smallest singular value is zero, the condition number is infinite.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::fast_matrix;
+    ///
+    /// let fast_matrix = fast_matrix!([2.0, 0.0], [0.0, 0.5]);
+    /// let condition_number: f64 = fast_matrix.condition_number();
+    ///
+    /// assert!((condition_number - 4.0).abs() < 1e-12);
+    /// assert_eq!(fast_matrix!([1.0, 1.0], [1.0, 1.0]).condition_number(), f64::INFINITY);
+    /// ```
+    pub fn condition_number(&self) -> T {
+        let (_, s, _) = self.svd();
+        let smallest = s[s.len() - 1];
+
+        if smallest == T::ZERO {
+            T::INFINITY
+        } else {
+            s[0] / smallest
+        }
+    }
+
+    /// ## Info
+    /// returns the rank of self, aka the number of singular values bigger than `EPSILON * max(rows, columns) * largest_singular_value`.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::fast_matrix;
+    ///
+    /// let fast_matrix = fast_matrix!([1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [1.0, 0.0, 1.0]);
+    ///
+    /// assert_eq!(fast_matrix.matrix_rank(), 2);
+    /// ```
+    pub fn matrix_rank(&self) -> usize {
+        let (_, s, _) = self.svd();
+        let tolerance = self.singular_value_tolerance(&s);
+
+        let mut rank = 0;
+        for i in 0..s.len() {
+            if s[i] > tolerance {
+                rank += 1;
+            }
+        }
+        rank
+    }
+
+    #[inline(always)]
+    fn singular_value_tolerance(&self, singular_values: &FastArray<T>) -> T {
+        T::EPSILON * T::from_f64(self.rows.max(self.columns) as f64) * singular_values[0]
+    }
+}
+
+/// returns the `(cos, sin)` of the Jacobi rotation that zeroes the off-diagonal element of the symmetric 2x2 matrix `[[app, apq], [apq, aqq]]`.
+#[inline(always)]
+fn jacobi_rotation<T: Float>(app: T, aqq: T, apq: T) -> (T, T) {
+    let theta = (aqq - app) / (T::from_f64(2.0) * apq);
+    let t = if theta >= T::ZERO {
+        T::ONE / (theta + (theta * theta + T::ONE).sqrt())
+    } else {
+        -T::ONE / (-theta + (theta * theta + T::ONE).sqrt())
+    };
+
+    let c = T::ONE / (t * t + T::ONE).sqrt();
+    (c, t * c)
+}
+
+/// applies the rotation to columns `p` and `q` of `matrix`, in place.
+#[inline(always)]
+fn rotate_columns<T: Float>(matrix: &mut FastMatrix<T>, p: usize, q: usize, c: T, s: T) {
+    let columns = matrix.columns;
+
+    for row in 0..matrix.rows {
+        unsafe {
+            let element_p = matrix.pointer.add(row * columns + p);
+            let element_q = matrix.pointer.add(row * columns + q);
+            let (xp, xq) = (*element_p, *element_q);
+
+            *element_p = c * xp - s * xq;
+            *element_q = s * xp + c * xq;
+        }
+    }
+}
+
+/// applies the rotation to rows `p` and `q` of `matrix`, in place.
+#[inline(always)]
+fn rotate_rows<T: Float>(matrix: &mut FastMatrix<T>, p: usize, q: usize, c: T, s: T) {
+    let columns = matrix.columns;
+
+    for column in 0..columns {
+        unsafe {
+            let element_p = matrix.pointer.add(p * columns + column);
+            let element_q = matrix.pointer.add(q * columns + column);
+            let (xp, xq) = (*element_p, *element_q);
+
+            *element_p = c * xp - s * xq;
+            *element_q = s * xp + c * xq;
+        }
+    }
+}
+
+/// returns the indexes that sort `values` in descending order.
+fn descending_order<T: Float>(values: &FastArray<T>) -> FastArray<usize> {
+    let mut order = FastArray::new_func(values.len(), |i| i);
+    order.sort_by(|a, b| {
+        values[*b]
+            .partial_cmp(&values[*a])
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    order
+}
+
+/// returns a copy of `matrix` whose `i`-th column is the `order[i]`-th column of `matrix`.
+fn reorder_columns<T: Float>(matrix: &FastMatrix<T>, order: &FastArray<usize>) -> FastMatrix<T> {
+    FastMatrix::new_func(matrix.rows, matrix.columns, |(row, column)| {
+        matrix[(row, order[column])]
+    })
+}
@@ -0,0 +1,776 @@
+use std::{
+    fmt::Display,
+    marker::PhantomData,
+    ops::{AddAssign, Bound, Div, DivAssign, Index, IndexMut, Mul, Range, RangeBounds, SubAssign},
+};
+
+use crate::{FastArray, FastMatrix};
+
+use super::fast_matrix_decompositions::{DecompositionError, Float};
+
+/// ## Info
+/// a borrowed, read-only window over (a part of) a [`FastMatrix`].
+///
+/// the element at `(row, column)` lives at `row * row_stride + column * column_stride` from the start of the view,
+/// so taking a sub-matrix, a row, a column or a transpose never copies any element.
+///
+/// ## Example
+/// ```
+/// use fast_collections::fast_matrix;
+///
+/// let fast_matrix = fast_matrix!([1, 2, 3], [4, 5, 6], [7, 8, 9]);
+///
+/// let view = fast_matrix.view(1.., 1..3);
+/// assert_eq!(view.to_owned(), fast_matrix!([5, 6], [8, 9]));
+///
+/// let transposed = view.transpose_view();
+/// assert_eq!(transposed[(1, 0)], 6);
+/// ```
+#[derive(Debug)]
+pub struct MatrixView<'a, T> {
+    pub(crate) pointer: *const T,
+    pub(crate) rows: usize,
+    pub(crate) columns: usize,
+    pub(crate) row_stride: usize,
+    pub(crate) column_stride: usize,
+    pub(crate) marker: PhantomData<&'a T>,
+}
+
+/// ## Info
+/// a borrowed, mutable window over (a part of) a [`FastMatrix`].
+///
+/// same as [`MatrixView`], but also allows modifying the elements it covers.
+///
+/// ## Example
+/// ```
+/// use fast_collections::fast_matrix;
+///
+/// let mut fast_matrix = fast_matrix!([1, 2, 3], [4, 5, 6]);
+///
+/// let mut column = fast_matrix.column_mut(1);
+/// column[(0, 0)] = 20;
+/// column[(1, 0)] = 50;
+///
+/// assert_eq!(fast_matrix, fast_matrix!([1, 20, 3], [4, 50, 6]));
+/// ```
+#[derive(Debug)]
+pub struct MatrixViewMut<'a, T> {
+    pub(crate) pointer: *mut T,
+    pub(crate) rows: usize,
+    pub(crate) columns: usize,
+    pub(crate) row_stride: usize,
+    pub(crate) column_stride: usize,
+    pub(crate) marker: PhantomData<&'a mut T>,
+}
+
+unsafe impl<T: Sync> Send for MatrixView<'_, T> {}
+unsafe impl<T: Sync> Sync for MatrixView<'_, T> {}
+unsafe impl<T: Send> Send for MatrixViewMut<'_, T> {}
+unsafe impl<T: Sync> Sync for MatrixViewMut<'_, T> {}
+
+impl<T> Clone for MatrixView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for MatrixView<'_, T> {}
+
+/// turns `range` into a non-empty `start..end` range inside of `0..len`.
+pub(crate) fn resolve_range(range: impl RangeBounds<usize>, len: usize) -> Range<usize> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start + 1,
+        Bound::Unbounded => 0,
+    };
+
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end + 1,
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+
+    assert!(
+        start < end && end <= len,
+        "FastMatrix: tried to index out of bounds."
+    );
+
+    start..end
+}
+
+macro_rules! impl_view_common {
+    ($view:ident, $borrow:lifetime) => {
+        impl<'a, T> $view<'a, T> {
+            #[inline(always)]
+            /// ## Info
+            /// returns the `(rows, columns)` of the view.
+            pub fn shape(&self) -> (usize, usize) {
+                (self.rows, self.columns)
+            }
+
+            #[inline(always)]
+            /// ## Info
+            /// returns the `(row_stride, column_stride)` of the view, aka the distance in elements between two consecutive rows and two consecutive columns.
+            pub fn strides(&self) -> (usize, usize) {
+                (self.row_stride, self.column_stride)
+            }
+
+            #[inline(always)]
+            fn offset(&self, index: (usize, usize)) -> usize {
+                index.0 * self.row_stride + index.1 * self.column_stride
+            }
+
+            /// ## Info
+            /// gets a borrowed sub-view of the given `rows` and `columns`, relative to this view.
+            ///
+            /// ## Panics
+            /// if either range is empty or goes out of bounds.
+            pub fn view(
+                &self,
+                rows: impl RangeBounds<usize>,
+                columns: impl RangeBounds<usize>,
+            ) -> MatrixView<$borrow, T> {
+                let rows = resolve_range(rows, self.rows);
+                let columns = resolve_range(columns, self.columns);
+
+                MatrixView {
+                    pointer: unsafe { self.pointer.add(self.offset((rows.start, columns.start))) },
+                    rows: rows.len(),
+                    columns: columns.len(),
+                    row_stride: self.row_stride,
+                    column_stride: self.column_stride,
+                    marker: PhantomData,
+                }
+            }
+
+            /// ## Info
+            /// gets a borrowed `1 x columns` view of the given `row`.
+            ///
+            /// ## Panics
+            /// if `row` is out of bounds.
+            pub fn row(&self, row: usize) -> MatrixView<$borrow, T> {
+                self.view(row..=row, ..)
+            }
+
+            /// ## Info
+            /// gets a borrowed `rows x 1` view of the given `column`.
+            ///
+            /// ## Panics
+            /// if `column` is out of bounds.
+            pub fn column(&self, column: usize) -> MatrixView<$borrow, T> {
+                self.view(.., column..=column)
+            }
+
+            /// ## Info
+            /// gets a borrowed view of the transpose of this view, by swapping its dimensions and strides.
+            pub fn transpose_view(&self) -> MatrixView<$borrow, T> {
+                MatrixView {
+                    pointer: self.pointer,
+                    rows: self.columns,
+                    columns: self.rows,
+                    row_stride: self.column_stride,
+                    column_stride: self.row_stride,
+                    marker: PhantomData,
+                }
+            }
+
+            /// ## Info
+            /// creates a new iterator over references to the elements of the view, from left to right and from top to bottom.
+            pub fn iter(&self) -> MatrixViewIter<$borrow, T> {
+                MatrixViewIter {
+                    pointer: self.pointer,
+                    columns: self.columns,
+                    row_stride: self.row_stride,
+                    column_stride: self.column_stride,
+                    front: 0,
+                    back: self.rows * self.columns,
+                    marker: PhantomData,
+                }
+            }
+
+            #[inline(always)]
+            /// ## Info
+            /// gets a reference to the element at `index`, or `None` if it is out of bounds.
+            pub fn get(&self, index: (usize, usize)) -> Option<&T> {
+                if index.0 < self.rows && index.1 < self.columns {
+                    Some(unsafe { &*self.pointer.add(self.offset(index)) })
+                } else {
+                    None
+                }
+            }
+
+            #[inline(always)]
+            fn is_contiguous(&self) -> bool {
+                (self.columns == 1 || self.column_stride == 1)
+                    && (self.rows == 1 || self.row_stride == self.columns)
+            }
+
+            /// ## Info
+            /// borrows the elements of the view as a slice, from left to right and from top to bottom, or returns `None` if they aren't contiguous in memory.
+            ///
+            /// ## Example
+            /// ```
+            /// use fast_collections::fast_matrix;
+            ///
+            /// let fast_matrix = fast_matrix!([1, 2, 3], [4, 5, 6]);
+            ///
+            /// assert_eq!(fast_matrix.row(1).as_slice(), Some([4, 5, 6].as_slice()));
+            /// assert_eq!(fast_matrix.column(1).as_slice(), None);
+            /// ```
+            pub fn as_slice(&self) -> Option<&[T]> {
+                if self.is_contiguous() {
+                    Some(unsafe { std::slice::from_raw_parts(self.pointer, self.rows * self.columns) })
+                } else {
+                    None
+                }
+            }
+        }
+
+        impl<T: Clone> $view<'_, T> {
+            /// ## Info
+            /// clones the elements covered by the view into a new, contiguous [`FastMatrix`].
+            pub fn to_owned(&self) -> FastMatrix<T> {
+                let func = |index| self[index].clone();
+                unsafe { FastMatrix::new_func_unchecked(self.rows, self.columns, func) }
+            }
+
+            /// ## Info
+            /// clones the transpose of the view into a new [`FastMatrix`].
+            pub fn transpose(&self) -> FastMatrix<T> {
+                self.transpose_view().to_owned()
+            }
+        }
+
+        impl<T> Index<(usize, usize)> for $view<'_, T> {
+            type Output = T;
+
+            #[inline(always)]
+            fn index(&self, index: (usize, usize)) -> &Self::Output {
+                assert!(
+                    index.0 < self.rows && index.1 < self.columns,
+                    "FastMatrix: tried to index out of bounds."
+                );
+                unsafe { &*self.pointer.add(self.offset(index)) }
+            }
+        }
+
+        impl<'b, T> IntoIterator for &'b $view<'_, T> {
+            type Item = &'b T;
+
+            type IntoIter = MatrixViewIter<'b, T>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter()
+            }
+        }
+
+        impl<T: Display> Display for $view<'_, T> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("[")?;
+
+                for row in 0..self.rows {
+                    if row == 0 {
+                        f.write_str("\n    [")?;
+                    } else {
+                        f.write_str(",\n    [")?;
+                    }
+
+                    for column in 0..self.columns {
+                        if column == 0 {
+                            write!(f, "{}", self[(row, column)])?;
+                        } else {
+                            write!(f, ", {}", self[(row, column)])?;
+                        }
+                    }
+
+                    f.write_str("]")?;
+                }
+
+                f.write_str("\n]")
+            }
+        }
+
+        impl<T: PartialEq> PartialEq<FastMatrix<T>> for $view<'_, T> {
+            fn eq(&self, other: &FastMatrix<T>) -> bool {
+                // the view of the matrix walks it in logical order, whatever its storage order
+                self.eq(&other.as_view())
+            }
+        }
+
+        impl<T: PartialEq> PartialEq<MatrixView<'_, T>> for $view<'_, T> {
+            fn eq(&self, other: &MatrixView<'_, T>) -> bool {
+                self.rows == other.rows
+                    && self.columns == other.columns
+                    && self.iter().zip(other.iter()).all(|(a, b)| a == b)
+            }
+        }
+
+        impl<T> $view<'_, T>
+        where
+            T: Clone
+                + Default
+                + PartialOrd
+                + Mul<Output = T>
+                + Div<Output = T>
+                + DivAssign
+                + SubAssign
+                + AddAssign
+                + TryFrom<f64>,
+
+            <T as TryFrom<f64>>::Error: std::fmt::Debug,
+        {
+            /// ## Info
+            /// returns the determinant of the view, see [`FastMatrix::determinant`].
+            pub fn determinant(&self) -> T {
+                self.to_owned().determinant()
+            }
+        }
+
+        impl<T: Float> $view<'_, T> {
+            /// ## Info
+            /// computes the QR decomposition of the view, see [`FastMatrix::qr`].
+            pub fn qr(&self) -> (FastMatrix<T>, FastMatrix<T>) {
+                self.to_owned().qr()
+            }
+
+            /// ## Info
+            /// solves the least-squares problem of the view, see [`FastMatrix::least_squares`].
+            pub fn least_squares(&self, rhs: &FastArray<T>) -> Result<FastArray<T>, DecompositionError> {
+                self.to_owned().least_squares(rhs)
+            }
+
+            /// ## Info
+            /// computes the Cholesky decomposition of the view, see [`FastMatrix::cholesky`].
+            pub fn cholesky(&self) -> Result<FastMatrix<T>, DecompositionError> {
+                self.to_owned().cholesky()
+            }
+
+            /// ## Info
+            /// computes the eigen-decomposition of the view, see [`FastMatrix::symmetric_eigen`].
+            pub fn symmetric_eigen(&self) -> Result<(FastArray<T>, FastMatrix<T>), DecompositionError> {
+                self.to_owned().symmetric_eigen()
+            }
+
+            /// ## Info
+            /// computes the thin singular value decomposition of the view, see [`FastMatrix::svd`].
+            pub fn svd(&self) -> (FastMatrix<T>, FastArray<T>, FastMatrix<T>) {
+                self.to_owned().svd()
+            }
+
+            /// ## Info
+            /// computes the pseudo-inverse of the view, see [`FastMatrix::pseudo_inverse`].
+            pub fn pseudo_inverse(&self) -> FastMatrix<T> {
+                self.to_owned().pseudo_inverse()
+            }
+
+            /// ## Info
+            /// returns the condition number of the view, see [`FastMatrix::condition_number`].
+            pub fn condition_number(&self) -> T {
+                self.to_owned().condition_number()
+            }
+
+            /// ## Info
+            /// returns the rank of the view, see [`FastMatrix::matrix_rank`].
+            pub fn matrix_rank(&self) -> usize {
+                self.to_owned().matrix_rank()
+            }
+        }
+    };
+}
+
+impl_view_common!(MatrixView, 'a);
+impl_view_common!(MatrixViewMut, '_);
+
+impl<'a, T> MatrixViewMut<'a, T> {
+    /// ## Info
+    /// reborrows this view as a read-only [`MatrixView`].
+    pub fn as_view(&self) -> MatrixView<'_, T> {
+        MatrixView {
+            pointer: self.pointer,
+            rows: self.rows,
+            columns: self.columns,
+            row_stride: self.row_stride,
+            column_stride: self.column_stride,
+            marker: PhantomData,
+        }
+    }
+
+    /// ## Info
+    /// gets a mutable sub-view of the given `rows` and `columns`, relative to this view.
+    ///
+    /// ## Panics
+    /// if either range is empty or goes out of bounds.
+    pub fn view_mut(
+        &mut self,
+        rows: impl RangeBounds<usize>,
+        columns: impl RangeBounds<usize>,
+    ) -> MatrixViewMut<'_, T> {
+        let rows = resolve_range(rows, self.rows);
+        let columns = resolve_range(columns, self.columns);
+
+        MatrixViewMut {
+            pointer: unsafe { self.pointer.add(self.offset((rows.start, columns.start))) },
+            rows: rows.len(),
+            columns: columns.len(),
+            row_stride: self.row_stride,
+            column_stride: self.column_stride,
+            marker: PhantomData,
+        }
+    }
+
+    /// ## Info
+    /// gets a mutable `1 x columns` view of the given `row`.
+    ///
+    /// ## Panics
+    /// if `row` is out of bounds.
+    pub fn row_mut(&mut self, row: usize) -> MatrixViewMut<'_, T> {
+        self.view_mut(row..=row, ..)
+    }
+
+    /// ## Info
+    /// gets a mutable `rows x 1` view of the given `column`.
+    ///
+    /// ## Panics
+    /// if `column` is out of bounds.
+    pub fn column_mut(&mut self, column: usize) -> MatrixViewMut<'_, T> {
+        self.view_mut(.., column..=column)
+    }
+
+    /// ## Info
+    /// gets a mutable view of the transpose of this view, by swapping its dimensions and strides.
+    pub fn transpose_view_mut(&mut self) -> MatrixViewMut<'_, T> {
+        MatrixViewMut {
+            pointer: self.pointer,
+            rows: self.columns,
+            columns: self.rows,
+            row_stride: self.column_stride,
+            column_stride: self.row_stride,
+            marker: PhantomData,
+        }
+    }
+
+    /// ## Info
+    /// creates a new iterator over mutable references to the elements of the view, from left to right and from top to bottom.
+    pub fn iter_mut(&mut self) -> MatrixViewIterMut<'_, T> {
+        MatrixViewIterMut {
+            pointer: self.pointer,
+            columns: self.columns,
+            row_stride: self.row_stride,
+            column_stride: self.column_stride,
+            front: 0,
+            back: self.rows * self.columns,
+            marker: PhantomData,
+        }
+    }
+
+    #[inline(always)]
+    /// ## Info
+    /// gets a mutable reference to the element at `index`, or `None` if it is out of bounds.
+    pub fn get_mut(&mut self, index: (usize, usize)) -> Option<&mut T> {
+        if index.0 < self.rows && index.1 < self.columns {
+            Some(unsafe { &mut *self.pointer.add(self.offset(index)) })
+        } else {
+            None
+        }
+    }
+
+    /// ## Info
+    /// borrows the elements of the view as a mutable slice, from left to right and from top to bottom, or returns `None` if they aren't contiguous in memory.
+    pub fn as_mut_slice(&mut self) -> Option<&mut [T]> {
+        if self.is_contiguous() {
+            Some(unsafe { std::slice::from_raw_parts_mut(self.pointer, self.rows * self.columns) })
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for MatrixViewMut<'_, T> {
+    #[inline(always)]
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+        assert!(
+            index.0 < self.rows && index.1 < self.columns,
+            "FastMatrix: tried to index out of bounds."
+        );
+        unsafe { &mut *self.pointer.add(self.offset(index)) }
+    }
+}
+
+impl<'b, T> IntoIterator for &'b mut MatrixViewMut<'_, T> {
+    type Item = &'b mut T;
+
+    type IntoIter = MatrixViewIterMut<'b, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T> FastMatrix<T> {
+    /// ## Info
+    /// gets a borrowed view of the given `rows` and `columns` of the [`FastMatrix`], without copying anything.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::fast_matrix;
+    ///
+    /// let fast_matrix = fast_matrix!([1, 2, 3], [4, 5, 6], [7, 8, 9]);
+    /// let view = fast_matrix.view(0..2, 1..);
+    ///
+    /// assert_eq!(view, fast_matrix!([2, 3], [5, 6]));
+    /// ```
+    ///
+    /// ## Panics
+    /// if either range is empty or goes out of bounds.
+    pub fn view(
+        &self,
+        rows: impl RangeBounds<usize>,
+        columns: impl RangeBounds<usize>,
+    ) -> MatrixView<'_, T> {
+        let rows = resolve_range(rows, self.rows);
+        let columns = resolve_range(columns, self.columns);
+        let (row_stride, column_stride) = self.strides();
+
+        MatrixView {
+            pointer: unsafe { self.pointer.add(self.offset((rows.start, columns.start))) },
+            rows: rows.len(),
+            columns: columns.len(),
+            row_stride,
+            column_stride,
+            marker: PhantomData,
+        }
+    }
+
+    /// ## Info
+    /// gets a mutable view of the given `rows` and `columns` of the [`FastMatrix`], without copying anything.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::fast_matrix;
+    ///
+    /// let mut fast_matrix = fast_matrix!([1, 2, 3], [4, 5, 6], [7, 8, 9]);
+    /// for element in fast_matrix.view_mut(1.., 1..).iter_mut() {
+    ///     *element = 0;
+    /// }
+    ///
+    /// assert_eq!(fast_matrix, fast_matrix!([1, 2, 3], [4, 0, 0], [7, 0, 0]));
+    /// ```
+    ///
+    /// ## Panics
+    /// if either range is empty or goes out of bounds.
+    pub fn view_mut(
+        &mut self,
+        rows: impl RangeBounds<usize>,
+        columns: impl RangeBounds<usize>,
+    ) -> MatrixViewMut<'_, T> {
+        let rows = resolve_range(rows, self.rows);
+        let columns = resolve_range(columns, self.columns);
+        let (row_stride, column_stride) = self.strides();
+
+        MatrixViewMut {
+            pointer: unsafe { self.pointer.add(self.offset((rows.start, columns.start))) },
+            rows: rows.len(),
+            columns: columns.len(),
+            row_stride,
+            column_stride,
+            marker: PhantomData,
+        }
+    }
+
+    #[inline(always)]
+    /// ## Info
+    /// gets a borrowed view of the whole [`FastMatrix`].
+    pub fn as_view(&self) -> MatrixView<'_, T> {
+        let (row_stride, column_stride) = self.strides();
+
+        MatrixView {
+            pointer: self.pointer,
+            rows: self.rows,
+            columns: self.columns,
+            row_stride,
+            column_stride,
+            marker: PhantomData,
+        }
+    }
+
+    #[inline(always)]
+    /// ## Info
+    /// gets a mutable view of the whole [`FastMatrix`].
+    pub fn as_view_mut(&mut self) -> MatrixViewMut<'_, T> {
+        let (row_stride, column_stride) = self.strides();
+
+        MatrixViewMut {
+            pointer: self.pointer,
+            rows: self.rows,
+            columns: self.columns,
+            row_stride,
+            column_stride,
+            marker: PhantomData,
+        }
+    }
+
+    /// ## Info
+    /// gets a borrowed `1 x columns` view of the given `row`.
+    ///
+    /// unlike [`FastMatrix::get_row`], this doesn't clone anything.
+    ///
+    /// ## Panics
+    /// if `row` is out of bounds.
+    pub fn row(&self, row: usize) -> MatrixView<'_, T> {
+        self.view(row..=row, ..)
+    }
+
+    /// ## Info
+    /// gets a mutable `1 x columns` view of the given `row`.
+    ///
+    /// ## Panics
+    /// if `row` is out of bounds.
+    pub fn row_mut(&mut self, row: usize) -> MatrixViewMut<'_, T> {
+        self.view_mut(row..=row, ..)
+    }
+
+    /// ## Info
+    /// gets a borrowed `rows x 1` view of the given `column`.
+    ///
+    /// unlike [`FastMatrix::get_column`], this doesn't clone anything.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::fast_matrix;
+    ///
+    /// let fast_matrix = fast_matrix!([1, 2, 3], [4, 5, 6], [7, 8, 9]);
+    ///
+    /// assert_eq!(fast_matrix.column(1), fast_matrix!([2], [5], [8]));
+    /// ```
+    ///
+    /// ## Panics
+    /// if `column` is out of bounds.
+    pub fn column(&self, column: usize) -> MatrixView<'_, T> {
+        self.view(.., column..=column)
+    }
+
+    /// ## Info
+    /// gets a mutable `rows x 1` view of the given `column`.
+    ///
+    /// ## Panics
+    /// if `column` is out of bounds.
+    pub fn column_mut(&mut self, column: usize) -> MatrixViewMut<'_, T> {
+        self.view_mut(.., column..=column)
+    }
+
+    /// ## Info
+    /// gets a borrowed view of the transpose of the [`FastMatrix`].
+    ///
+    /// unlike [`FastMatrix::transpose`], this doesn't clone anything: it only swaps the dimensions and strides of the view.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::fast_matrix;
+    ///
+    /// let fast_matrix = fast_matrix!([1, 2, 3], [4, 5, 6]);
+    ///
+    /// assert_eq!(fast_matrix.transpose_view(), fast_matrix!([1, 4], [2, 5], [3, 6]));
+    /// ```
+    pub fn transpose_view(&self) -> MatrixView<'_, T> {
+        let (row_stride, column_stride) = self.strides();
+
+        MatrixView {
+            pointer: self.pointer,
+            rows: self.columns,
+            columns: self.rows,
+            row_stride: column_stride,
+            column_stride: row_stride,
+            marker: PhantomData,
+        }
+    }
+}
+
+/// ## Info
+/// an iterator over references to the elements of a [`MatrixView`], from left to right and from top to bottom.
+pub struct MatrixViewIter<'a, T> {
+    pointer: *const T,
+    columns: usize,
+    row_stride: usize,
+    column_stride: usize,
+    front: usize,
+    back: usize,
+    marker: PhantomData<&'a T>,
+}
+
+/// ## Info
+/// an iterator over mutable references to the elements of a [`MatrixViewMut`], from left to right and from top to bottom.
+pub struct MatrixViewIterMut<'a, T> {
+    pointer: *mut T,
+    columns: usize,
+    row_stride: usize,
+    column_stride: usize,
+    front: usize,
+    back: usize,
+    marker: PhantomData<&'a mut T>,
+}
+
+unsafe impl<T: Sync> Send for MatrixViewIter<'_, T> {}
+unsafe impl<T: Sync> Sync for MatrixViewIter<'_, T> {}
+unsafe impl<T: Send> Send for MatrixViewIterMut<'_, T> {}
+unsafe impl<T: Sync> Sync for MatrixViewIterMut<'_, T> {}
+
+macro_rules! impl_view_iter {
+    ($iter:ident, $item:ty, $deref:ident) => {
+        impl<'a, T> $iter<'a, T> {
+            #[inline(always)]
+            fn offset(&self, flat_index: usize) -> usize {
+                (flat_index / self.columns) * self.row_stride
+                    + (flat_index % self.columns) * self.column_stride
+            }
+        }
+
+        impl<'a, T> Iterator for $iter<'a, T> {
+            type Item = $item;
+
+            #[inline(always)]
+            fn next(&mut self) -> Option<Self::Item> {
+                if self.front >= self.back {
+                    None
+                } else {
+                    let offset = self.offset(self.front);
+                    self.front += 1;
+                    Some(unsafe { $deref(self.pointer.add(offset)) })
+                }
+            }
+
+            #[inline(always)]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let len = self.back - self.front;
+                (len, Some(len))
+            }
+        }
+
+        impl<'a, T> DoubleEndedIterator for $iter<'a, T> {
+            #[inline(always)]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                if self.front >= self.back {
+                    None
+                } else {
+                    self.back -= 1;
+                    let offset = self.offset(self.back);
+                    Some(unsafe { $deref(self.pointer.add(offset)) })
+                }
+            }
+        }
+
+        impl<'a, T> ExactSizeIterator for $iter<'a, T> {}
+    };
+}
+
+#[inline(always)]
+unsafe fn as_ref<'a, T>(pointer: *const T) -> &'a T {
+    unsafe { &*pointer }
+}
+
+#[inline(always)]
+unsafe fn as_mut<'a, T>(pointer: *mut T) -> &'a mut T {
+    unsafe { &mut *pointer }
+}
+
+impl_view_iter!(MatrixViewIter, &'a T, as_ref);
+impl_view_iter!(MatrixViewIterMut, &'a mut T, as_mut);
@@ -1,3 +1,12 @@
 pub mod fast_matrix;
 pub mod fast_matrix_basics;
-pub mod fast_matrix_maths;
\ No newline at end of file
+pub mod fast_matrix_csv;
+pub mod fast_matrix_decompositions;
+pub mod fast_matrix_iterators;
+pub mod fast_matrix_maths;
+pub mod fast_matrix_mmap;
+pub mod fast_matrix_mtx;
+pub mod fast_matrix_n;
+pub mod fast_matrix_serde;
+pub mod fast_matrix_spectral;
+pub mod fast_matrix_view;
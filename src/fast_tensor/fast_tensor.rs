@@ -0,0 +1,632 @@
+use std::{
+    fmt::Display,
+    marker::PhantomData,
+    mem,
+    ops::{Index, IndexMut},
+    ptr,
+};
+
+use crate::{alloc, error::AllocError, FastError};
+
+/// ## Info
+/// a n-dimensional tensor, whose shape and strides are decided at runtime.
+///
+/// like every other collection of the library it's not resizable: all of its elements live in a single buffer that gets allocated once.
+/// operations like [`FastTensor::permute_axes`] only shuffle the strides around, so the elements aren't necessarily laid out in row-major order.
+///
+/// ## Example
+/// ```
+/// use fast_collections::FastTensor;
+///
+/// let tensor = FastTensor::new_func(&[2, 3, 4], |index| index[0] * 100 + index[1] * 10 + index[2]);
+///
+/// assert_eq!(tensor.shape(), &[2, 3, 4]);
+/// assert_eq!(tensor[[1, 2, 3]], 123);
+/// ```
+#[derive(Debug)]
+pub struct FastTensor<T> {
+    pub(crate) pointer: *mut T,
+    pub(crate) shape: Box<[usize]>,
+    pub(crate) strides: Box<[usize]>,
+}
+
+/// the number of elements of a tensor of the given shape, or an error if it overflows.
+#[inline(always)]
+pub(crate) fn try_len(shape: &[usize]) -> Result<usize, AllocError> {
+    let len = shape.iter().try_fold(1usize, |len, &dimension| len.checked_mul(dimension));
+    len.ok_or(AllocError::CapacityOverflow)
+}
+
+/// same as [`try_len`], but panics on overflow.
+#[inline(always)]
+pub(crate) fn checked_len(shape: &[usize]) -> usize {
+    alloc::unwrap_alloc(try_len(shape))
+}
+
+/// writes the elements of a row-major buffer of the given shape, calling `func` with the index of each of them.
+///
+/// if `func` panics, the elements written so far are dropped and the buffer is freed, so it must not be used by the caller anymore.
+unsafe fn fill<T, F>(pointer: *mut T, shape: &[usize], mut func: F)
+where
+    F: FnMut(&[usize]) -> T,
+{
+    let len = shape.iter().product();
+    let mut guard = FillGuard { pointer, written: 0, len };
+
+    let mut index = vec![0; shape.len()];
+    while guard.written < len {
+        unsafe { pointer.add(guard.written).write(func(&index)) };
+        guard.written += 1;
+        next_index(&mut index, shape);
+    }
+
+    mem::forget(guard);
+}
+
+/// the buffer being filled by [`fill`], which drops the elements written so far and frees it on a panic.
+struct FillGuard<T> {
+    pointer: *mut T,
+    written: usize,
+    len: usize,
+}
+
+impl<T> Drop for FillGuard<T> {
+    fn drop(&mut self) {
+        for i in 0..self.written {
+            unsafe { ptr::drop_in_place(self.pointer.add(i)) };
+        }
+
+        unsafe { alloc::deallocate(self.pointer, self.len) };
+    }
+}
+
+/// the strides of a row-major buffer of the given shape.
+pub(crate) fn contiguous_strides(shape: &[usize]) -> Box<[usize]> {
+    let mut strides = vec![1; shape.len()].into_boxed_slice();
+
+    for axis in (0..shape.len().saturating_sub(1)).rev() {
+        strides[axis] = strides[axis + 1] * shape[axis + 1];
+    }
+
+    strides
+}
+
+/// moves `index` to the next position in row-major order, returning `false` once it wraps around.
+#[inline(always)]
+pub(crate) fn next_index(index: &mut [usize], shape: &[usize]) -> bool {
+    for axis in (0..index.len()).rev() {
+        index[axis] += 1;
+        if index[axis] < shape[axis] {
+            return true;
+        }
+        index[axis] = 0;
+    }
+
+    false
+}
+
+impl<T> FastTensor<T> {
+    /// ## Info
+    /// makes a new empty [`FastTensor`] of the given shape, laid out in row-major order.
+    ///
+    /// # Safety
+    /// the elements of the tensor are uninitialized: every one of them must be written before it's read,
+    /// and before the tensor is dropped, since dropping it drops all of its elements.
+    ///
+    /// ## Panics
+    /// if any of the dimensions is 0.
+    pub unsafe fn new_empty(shape: &[usize]) -> FastTensor<T> {
+        assert!(
+            !shape.contains(&0),
+            "FastTensor: dimensions cannot be 0, found {shape:?}!"
+        );
+
+        let pointer = alloc::allocate::<T>(checked_len(shape));
+
+        FastTensor {
+            pointer,
+            shape: shape.into(),
+            strides: contiguous_strides(shape),
+        }
+    }
+
+    /// ## Info
+    /// creates a new [`FastTensor`] of the given shape from a closure or function that accepts the index being set.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::FastTensor;
+    ///
+    /// let tensor = FastTensor::new_func(&[2, 2], |index| index[0] == index[1]);
+    ///
+    /// assert!(tensor[[1, 1]]);
+    /// assert!(!tensor[[0, 1]]);
+    /// ```
+    ///
+    /// ## Panics
+    /// if any of the dimensions is 0.
+    pub fn new_func<F>(shape: &[usize], func: F) -> FastTensor<T>
+    where
+        F: FnMut(&[usize]) -> T,
+    {
+        assert!(
+            !shape.contains(&0),
+            "FastTensor: dimensions cannot be 0, found {shape:?}!"
+        );
+
+        let pointer = alloc::allocate::<T>(checked_len(shape));
+        unsafe { fill(pointer, shape, func) };
+
+        FastTensor {
+            pointer,
+            shape: shape.into(),
+            strides: contiguous_strides(shape),
+        }
+    }
+
+    /// ## Info
+    /// does the same thing as [`FastTensor::new_func`], but returns an error instead of panicking.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::{FastError, FastTensor};
+    ///
+    /// let tensor = FastTensor::try_new_func(&[2, 2], |index| index[0] * 2 + index[1]).unwrap();
+    /// assert_eq!(tensor[[1, 0]], 2);
+    ///
+    /// assert!(matches!(FastTensor::try_new_func(&[2, 0], |_| 0), Err(FastError::ZeroLength)));
+    /// assert!(matches!(FastTensor::try_new_func(&[usize::MAX, 2], |_| 0), Err(FastError::Alloc)));
+    /// ```
+    ///
+    /// ## Errors
+    /// - [`FastError::ZeroLength`] if any of the dimensions is 0.
+    /// - [`FastError::Alloc`] if the number of elements overflows, or if the memory couldn't be allocated.
+    pub fn try_new_func<F>(shape: &[usize], func: F) -> Result<FastTensor<T>, FastError>
+    where
+        F: FnMut(&[usize]) -> T,
+    {
+        if shape.contains(&0) {
+            return Err(FastError::ZeroLength);
+        }
+
+        let pointer = alloc::try_allocate::<T>(try_len(shape)?)?;
+        unsafe { fill(pointer, shape, func) };
+
+        Ok(FastTensor {
+            pointer,
+            shape: shape.into(),
+            strides: contiguous_strides(shape),
+        })
+    }
+
+    #[inline(always)]
+    /// ## Info
+    /// returns the size of each axis of the [`FastTensor`].
+    pub fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+
+    #[inline(always)]
+    /// ## Info
+    /// returns the distance in elements between two consecutive positions along each axis.
+    pub fn strides(&self) -> &[usize] {
+        &self.strides
+    }
+
+    #[inline(always)]
+    /// ## Info
+    /// returns the number of axes of the [`FastTensor`].
+    pub fn ndim(&self) -> usize {
+        self.shape.len()
+    }
+
+    #[inline(always)]
+    /// ## Info
+    /// returns the total number of elements of the [`FastTensor`].
+    pub fn len(&self) -> usize {
+        self.shape.iter().product()
+    }
+
+    #[inline(always)]
+    /// ## Info
+    /// always `false`, since a [`FastTensor`] can't have a dimension of size 0.
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    #[inline(always)]
+    /// ## Info
+    /// returns `true` if the elements are laid out in row-major order.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::FastTensor;
+    ///
+    /// let tensor = FastTensor::new(&[2, 3], 0);
+    /// assert!(tensor.is_contiguous());
+    ///
+    /// let tensor = tensor.permute_axes(&[1, 0]);
+    /// assert!(!tensor.is_contiguous());
+    /// ```
+    pub fn is_contiguous(&self) -> bool {
+        self.strides == contiguous_strides(&self.shape)
+    }
+
+    /// the position of `index` in the buffer, or `None` if it is out of bounds.
+    #[inline(always)]
+    pub(crate) fn offset(&self, index: &[usize]) -> Option<usize> {
+        if index.len() != self.shape.len() {
+            return None;
+        }
+
+        let mut offset = 0;
+        for ((&position, &size), &stride) in index.iter().zip(&*self.shape).zip(&*self.strides) {
+            if position >= size {
+                return None;
+            }
+            offset += position * stride;
+        }
+
+        Some(offset)
+    }
+
+    /// the positions in the buffer of every element, in row-major order of their indices.
+    #[inline(always)]
+    pub(crate) fn offsets(&self) -> Offsets<'_> {
+        Offsets::new(&self.shape, &self.strides)
+    }
+
+    #[inline(always)]
+    /// ## Info
+    /// gets a reference to the element at `index`, or `None` if it is out of bounds or has the wrong number of axes.
+    pub fn get(&self, index: &[usize]) -> Option<&T> {
+        self.offset(index)
+            .map(|offset| unsafe { &*self.pointer.add(offset) })
+    }
+
+    #[inline(always)]
+    /// ## Info
+    /// gets a mutable reference to the element at `index`, or `None` if it is out of bounds or has the wrong number of axes.
+    pub fn get_mut(&mut self, index: &[usize]) -> Option<&mut T> {
+        self.offset(index)
+            .map(|offset| unsafe { &mut *self.pointer.add(offset) })
+    }
+
+    /// ## Info
+    /// borrows the elements as a slice in row-major order, or returns `None` if they aren't laid out that way.
+    pub fn as_slice(&self) -> Option<&[T]> {
+        if self.is_contiguous() {
+            Some(unsafe { std::slice::from_raw_parts(self.pointer, self.len()) })
+        } else {
+            None
+        }
+    }
+
+    /// ## Info
+    /// borrows the elements as a mutable slice in row-major order, or returns `None` if they aren't laid out that way.
+    pub fn as_mut_slice(&mut self) -> Option<&mut [T]> {
+        if self.is_contiguous() {
+            Some(unsafe { std::slice::from_raw_parts_mut(self.pointer, self.len()) })
+        } else {
+            None
+        }
+    }
+
+    /// ## Info
+    /// creates an iterator over the elements of the [`FastTensor`], in row-major order of their indices whatever the strides are.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::FastTensor;
+    ///
+    /// let tensor = FastTensor::new_func(&[2, 3], |index| index[0] * 3 + index[1]);
+    /// let transposed = tensor.permute_axes(&[1, 0]);
+    ///
+    /// assert_eq!(transposed.iter().copied().collect::<Vec<_>>(), vec![0, 3, 1, 4, 2, 5]);
+    /// ```
+    pub fn iter(&self) -> TensorIter<'_, T> {
+        TensorIter {
+            pointer: self.pointer,
+            offsets: self.offsets(),
+            marker: PhantomData,
+        }
+    }
+
+    /// ## Info
+    /// creates an iterator over mutable references to the elements of the [`FastTensor`], in row-major order of their indices.
+    pub fn iter_mut(&mut self) -> TensorIterMut<'_, T> {
+        TensorIterMut {
+            pointer: self.pointer,
+            offsets: Offsets::new(&self.shape, &self.strides),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T: Clone> FastTensor<T> {
+    /// ## Info
+    /// creates a new [`FastTensor`] of the given shape and fills it with `fill_value`.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::FastTensor;
+    ///
+    /// let tensor = FastTensor::new(&[2, 2, 2], 1.5);
+    ///
+    /// assert_eq!(tensor.len(), 8);
+    /// assert_eq!(tensor[[1, 0, 1]], 1.5);
+    /// ```
+    ///
+    /// ## Panics
+    /// if any of the dimensions is 0.
+    pub fn new(shape: &[usize], fill_value: T) -> FastTensor<T> {
+        FastTensor::new_func(shape, |_| fill_value.clone())
+    }
+
+    /// ## Info
+    /// does the same thing as [`FastTensor::new`], but returns an error instead of panicking.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::{FastError, FastTensor};
+    ///
+    /// assert_eq!(FastTensor::try_new(&[3], 1).unwrap().len(), 3);
+    /// assert!(matches!(FastTensor::try_new(&[0, 3], 1), Err(FastError::ZeroLength)));
+    /// ```
+    ///
+    /// ## Errors
+    /// - [`FastError::ZeroLength`] if any of the dimensions is 0.
+    /// - [`FastError::Alloc`] if the number of elements overflows, or if the memory couldn't be allocated.
+    pub fn try_new(shape: &[usize], fill_value: T) -> Result<FastTensor<T>, FastError> {
+        FastTensor::try_new_func(shape, |_| fill_value.clone())
+    }
+}
+
+impl<T: Default> FastTensor<T> {
+    /// ## Info
+    /// creates a new [`FastTensor`] of the given shape and fills it with the [`Default`] value of T.
+    ///
+    /// ## Panics
+    /// if any of the dimensions is 0.
+    pub fn new_default(shape: &[usize]) -> FastTensor<T> {
+        FastTensor::new_func(shape, |_| T::default())
+    }
+
+    /// ## Info
+    /// does the same thing as [`FastTensor::new_default`], but returns an error instead of panicking.
+    ///
+    /// ## Errors
+    /// - [`FastError::ZeroLength`] if any of the dimensions is 0.
+    /// - [`FastError::Alloc`] if the number of elements overflows, or if the memory couldn't be allocated.
+    pub fn try_new_default(shape: &[usize]) -> Result<FastTensor<T>, FastError> {
+        FastTensor::try_new_func(shape, |_| T::default())
+    }
+}
+
+/// an iterator over the buffer positions of a strided shape, in row-major order of the indices.
+#[derive(Debug, Clone)]
+pub(crate) struct Offsets<'a> {
+    shape: &'a [usize],
+    strides: &'a [usize],
+    index: Box<[usize]>,
+    offset: usize,
+    remaining: usize,
+}
+
+impl<'a> Offsets<'a> {
+    pub(crate) fn new(shape: &'a [usize], strides: &'a [usize]) -> Offsets<'a> {
+        Offsets {
+            shape,
+            strides,
+            index: vec![0; shape.len()].into_boxed_slice(),
+            offset: 0,
+            remaining: shape.iter().product(),
+        }
+    }
+}
+
+impl Iterator for Offsets<'_> {
+    type Item = usize;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let offset = self.offset;
+        self.remaining -= 1;
+
+        for axis in (0..self.index.len()).rev() {
+            self.index[axis] += 1;
+            if self.index[axis] < self.shape[axis] {
+                self.offset += self.strides[axis];
+                break;
+            }
+            self.index[axis] = 0;
+            self.offset -= (self.shape[axis] - 1) * self.strides[axis];
+        }
+
+        Some(offset)
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl ExactSizeIterator for Offsets<'_> {}
+
+/// ## Info
+/// an iterator over the elements of a [`FastTensor`], created by [`FastTensor::iter`].
+pub struct TensorIter<'a, T> {
+    pointer: *const T,
+    offsets: Offsets<'a>,
+    marker: PhantomData<&'a T>,
+}
+
+/// ## Info
+/// an iterator over mutable references to the elements of a [`FastTensor`], created by [`FastTensor::iter_mut`].
+pub struct TensorIterMut<'a, T> {
+    pointer: *mut T,
+    offsets: Offsets<'a>,
+    marker: PhantomData<&'a mut T>,
+}
+
+macro_rules! impl_tensor_iter {
+    ($iter:ident, $item:ty, $as_ref:ident) => {
+        impl<'a, T> Iterator for $iter<'a, T> {
+            type Item = $item;
+
+            #[inline(always)]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.offsets
+                    .next()
+                    .map(|offset| unsafe { self.pointer.add(offset).$as_ref().unwrap() })
+            }
+
+            #[inline(always)]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.offsets.size_hint()
+            }
+        }
+
+        impl<'a, T> ExactSizeIterator for $iter<'a, T> {}
+    };
+}
+
+impl_tensor_iter!(TensorIter, &'a T, as_ref);
+impl_tensor_iter!(TensorIterMut, &'a mut T, as_mut);
+
+impl<'b, T> IntoIterator for &'b FastTensor<T> {
+    type Item = &'b T;
+
+    type IntoIter = TensorIter<'b, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'b, T> IntoIterator for &'b mut FastTensor<T> {
+    type Item = &'b mut T;
+
+    type IntoIter = TensorIterMut<'b, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T, const N: usize> Index<[usize; N]> for FastTensor<T> {
+    type Output = T;
+
+    #[inline(always)]
+    fn index(&self, index: [usize; N]) -> &Self::Output {
+        &self[index.as_slice()]
+    }
+}
+
+impl<T, const N: usize> IndexMut<[usize; N]> for FastTensor<T> {
+    #[inline(always)]
+    fn index_mut(&mut self, index: [usize; N]) -> &mut Self::Output {
+        &mut self[index.as_slice()]
+    }
+}
+
+impl<T> Index<&[usize]> for FastTensor<T> {
+    type Output = T;
+
+    #[inline(always)]
+    fn index(&self, index: &[usize]) -> &Self::Output {
+        match self.offset(index) {
+            Some(offset) => unsafe { &*self.pointer.add(offset) },
+            None => panic!(
+                "FastTensor: index {index:?} is out of bounds for shape {:?}.",
+                self.shape
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<&[usize]> for FastTensor<T> {
+    #[inline(always)]
+    fn index_mut(&mut self, index: &[usize]) -> &mut Self::Output {
+        match self.offset(index) {
+            Some(offset) => unsafe { &mut *self.pointer.add(offset) },
+            None => panic!(
+                "FastTensor: index {index:?} is out of bounds for shape {:?}.",
+                self.shape
+            ),
+        }
+    }
+}
+
+impl<T: Clone> Clone for FastTensor<T> {
+    fn clone(&self) -> Self {
+        // the buffer is cloned as a flat tensor, so that a panicking `clone` never drops uninitialized elements,
+        // and the strides are kept, so the buffer can be copied as it is
+        let mut clone = FastTensor::new_func(&[self.len()], |index| unsafe { (*self.pointer.add(index[0])).clone() });
+        clone.shape = self.shape.clone();
+        clone.strides = self.strides.clone();
+
+        clone
+    }
+}
+
+impl<T: PartialEq> PartialEq for FastTensor<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.shape == other.shape && self.iter().zip(other.iter()).all(|(a, b)| a == b)
+    }
+}
+
+impl<T: Eq> Eq for FastTensor<T> {}
+
+impl<T: Display> Display for FastTensor<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut elements = self.iter();
+
+        if self.shape.is_empty() {
+            return write!(f, "{}", elements.next().unwrap());
+        }
+
+        let mut index = vec![0; self.shape.len()];
+        f.write_str(&"[".repeat(self.shape.len()))?;
+
+        loop {
+            write!(f, "{}", elements.next().unwrap())?;
+
+            // the number of axes that wrap around after this element
+            let mut closed = 0;
+            for axis in (0..index.len()).rev() {
+                index[axis] += 1;
+                if index[axis] < self.shape[axis] {
+                    break;
+                }
+                index[axis] = 0;
+                closed += 1;
+            }
+
+            f.write_str(&"]".repeat(closed))?;
+            if closed == self.shape.len() {
+                return Ok(());
+            }
+            write!(f, ", {}", "[".repeat(closed))?;
+        }
+    }
+}
+
+impl<T> Drop for FastTensor<T> {
+    fn drop(&mut self) {
+        if !self.pointer.is_null() {
+            let len = self.len();
+
+            for i in 0..len {
+                unsafe { ptr::drop_in_place(self.pointer.add(i)) };
+            }
+
+            unsafe { alloc::deallocate(self.pointer, len) };
+        }
+    }
+}
@@ -0,0 +1,354 @@
+use std::{ops::RangeBounds, ptr};
+
+use crate::{
+    alloc,
+    fast_matrix::{fast_matrix::StorageOrder, fast_matrix_view::resolve_range},
+    FastArray, FastError, FastMatrix,
+};
+
+use super::fast_tensor::{contiguous_strides, try_len, FastTensor, Offsets};
+
+/// checks that `axes` is a permutation of `0..ndim`.
+fn is_permutation(axes: &[usize], ndim: usize) -> bool {
+    let mut seen = vec![false; ndim];
+
+    axes.len() == ndim
+        && axes.iter().all(|&axis| axis < ndim && !std::mem::replace(&mut seen[axis], true))
+}
+
+impl<T> FastTensor<T> {
+    /// ## Info
+    /// moves the elements of self into row-major order, so that the buffer follows the indices.
+    ///
+    /// if self is already contiguous this doesn't do anything, otherwise the elements are moved (not cloned) into a new buffer.
+    pub fn into_contiguous(mut self) -> FastTensor<T> {
+        if self.is_contiguous() {
+            return self;
+        }
+
+        let contiguous: FastTensor<T> = unsafe { FastTensor::new_empty(&self.shape) };
+
+        for (position, offset) in self.offsets().enumerate() {
+            unsafe {
+                contiguous
+                    .pointer
+                    .add(position)
+                    .write(ptr::read(self.pointer.add(offset)))
+            };
+        }
+
+        // every element has been moved out, so only the buffer is left to free
+        unsafe { alloc::deallocate(self.pointer, self.len()) };
+        self.pointer = ptr::null_mut();
+
+        contiguous
+    }
+
+    /// ## Info
+    /// gives self a new shape with the same number of elements, read in row-major order.
+    ///
+    /// this doesn't copy anything if self is contiguous.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::FastTensor;
+    ///
+    /// let tensor = FastTensor::new_func(&[6], |index| index[0]);
+    /// let reshaped = tensor.reshape(&[2, 3]);
+    ///
+    /// assert_eq!(reshaped[[1, 0]], 3);
+    /// ```
+    ///
+    /// ## Panics
+    /// if the new shape doesn't have the same number of elements, or if any of its dimensions is 0.
+    pub fn reshape(self, shape: &[usize]) -> FastTensor<T> {
+        assert!(
+            !shape.contains(&0) && try_len(shape) == Ok(self.len()),
+            "FastTensor: cannot reshape {:?} into {shape:?}!",
+            self.shape
+        );
+
+        let mut reshaped = self.into_contiguous();
+        reshaped.shape = shape.into();
+        reshaped.strides = contiguous_strides(shape);
+
+        reshaped
+    }
+
+    /// ## Info
+    /// does the same thing as [`FastTensor::reshape`], but returns an error instead of panicking.
+    ///
+    /// self is dropped if it can't be reshaped.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::{FastError, FastTensor};
+    ///
+    /// let tensor = FastTensor::new_func(&[6], |index| index[0]);
+    /// assert_eq!(tensor.clone().try_reshape(&[3, 2]).unwrap()[[1, 1]], 3);
+    ///
+    /// assert!(matches!(tensor.clone().try_reshape(&[4, 2]), Err(FastError::ReshapeMismatch { .. })));
+    /// assert!(matches!(tensor.try_reshape(&[6, 0]), Err(FastError::ZeroLength)));
+    /// ```
+    ///
+    /// ## Errors
+    /// - [`FastError::ZeroLength`] if any of the dimensions of `shape` is 0.
+    /// - [`FastError::ReshapeMismatch`] if `shape` doesn't have the same number of elements as self.
+    pub fn try_reshape(self, shape: &[usize]) -> Result<FastTensor<T>, FastError> {
+        if shape.contains(&0) {
+            return Err(FastError::ZeroLength);
+        }
+        if try_len(shape) != Ok(self.len()) {
+            return Err(FastError::ReshapeMismatch { from: self.shape.clone(), to: shape.into() });
+        }
+
+        Ok(self.reshape(shape))
+    }
+
+    /// ## Info
+    /// reorders the axes of self, so that the axis `i` of the result is the axis `axes[i]` of self.
+    ///
+    /// this only shuffles the shape and the strides, the elements don't move.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::FastTensor;
+    ///
+    /// let tensor = FastTensor::new_func(&[2, 3, 4], |index| index[0] * 100 + index[1] * 10 + index[2]);
+    /// let permuted = tensor.permute_axes(&[2, 0, 1]);
+    ///
+    /// assert_eq!(permuted.shape(), &[4, 2, 3]);
+    /// assert_eq!(permuted[[3, 1, 2]], 123);
+    /// ```
+    ///
+    /// ## Panics
+    /// if `axes` isn't a permutation of `0..self.ndim()`.
+    pub fn permute_axes(mut self, axes: &[usize]) -> FastTensor<T> {
+        let ndim = self.ndim();
+        assert!(
+            is_permutation(axes, ndim),
+            "FastTensor: {axes:?} isn't a permutation of the {ndim} axes!"
+        );
+
+        self.shape = axes.iter().map(|&axis| self.shape[axis]).collect();
+        self.strides = axes.iter().map(|&axis| self.strides[axis]).collect();
+
+        self
+    }
+
+    /// ## Info
+    /// does the same thing as [`FastTensor::permute_axes`], but returns an error instead of panicking.
+    ///
+    /// self is dropped if `axes` isn't a permutation.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::{FastError, FastTensor};
+    ///
+    /// let tensor = FastTensor::new(&[2, 3], 0);
+    /// assert_eq!(tensor.clone().try_permute_axes(&[1, 0]).unwrap().shape(), &[3, 2]);
+    ///
+    /// assert!(matches!(tensor.clone().try_permute_axes(&[0, 0]), Err(FastError::InvalidAxes { ndim: 2, .. })));
+    /// assert!(matches!(tensor.try_permute_axes(&[0]), Err(FastError::InvalidAxes { ndim: 2, .. })));
+    /// ```
+    ///
+    /// ## Errors
+    /// [`FastError::InvalidAxes`] if `axes` isn't a permutation of `0..self.ndim()`.
+    pub fn try_permute_axes(self, axes: &[usize]) -> Result<FastTensor<T>, FastError> {
+        if !is_permutation(axes, self.ndim()) {
+            return Err(FastError::InvalidAxes { axes: axes.into(), ndim: self.ndim() });
+        }
+
+        Ok(self.permute_axes(axes))
+    }
+}
+
+impl<T: Clone> FastTensor<T> {
+    /// ## Info
+    /// clones the elements whose index along `axis` falls in `range` into a new [`FastTensor`].
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::FastTensor;
+    ///
+    /// let tensor = FastTensor::new_func(&[3, 4], |index| index[0] * 4 + index[1]);
+    /// let sliced = tensor.slice_axis(1, 1..3);
+    ///
+    /// assert_eq!(sliced.shape(), &[3, 2]);
+    /// assert_eq!(sliced.iter().copied().collect::<Vec<_>>(), vec![1, 2, 5, 6, 9, 10]);
+    /// ```
+    ///
+    /// ## Panics
+    /// if `axis` doesn't exist, or if `range` is empty or goes out of bounds.
+    pub fn slice_axis(&self, axis: usize, range: impl RangeBounds<usize>) -> FastTensor<T> {
+        assert!(
+            axis < self.ndim(),
+            "FastTensor: axis {axis} doesn't exist in shape {:?}!",
+            self.shape
+        );
+
+        let range = resolve_range(range, self.shape[axis]);
+
+        let mut shape = self.shape.clone();
+        shape[axis] = range.len();
+
+        let first = unsafe { self.pointer.add(range.start * self.strides[axis]) };
+        let mut offsets = Offsets::new(&shape, &self.strides);
+
+        FastTensor::new_func(&shape, |_| unsafe { (*first.add(offsets.next().unwrap())).clone() })
+    }
+
+    /// ## Info
+    /// clones self into a new [`FastTensor`] of the given shape, following NumPy's broadcasting rules:
+    /// the shapes are aligned on their last axis, and every axis of self must either match the new one or be 1.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::FastTensor;
+    ///
+    /// let row = FastTensor::new_func(&[3], |index| index[0]);
+    /// let broadcast = row.broadcast_to(&[2, 3]);
+    ///
+    /// assert_eq!(broadcast.iter().copied().collect::<Vec<_>>(), vec![0, 1, 2, 0, 1, 2]);
+    /// ```
+    ///
+    /// ## Panics
+    /// if self can't be broadcast to `shape`.
+    pub fn broadcast_to(&self, shape: &[usize]) -> FastTensor<T> {
+        let strides = match broadcast_strides(&self.shape, &self.strides, shape) {
+            Some(strides) => strides,
+            None => panic!(
+                "FastTensor: cannot broadcast {:?} to {shape:?}!",
+                self.shape
+            ),
+        };
+
+        let mut offsets = Offsets::new(shape, &strides);
+
+        FastTensor::new_func(shape, |_| unsafe {
+            (*self.pointer.add(offsets.next().unwrap())).clone()
+        })
+    }
+}
+
+/// the strides that read a tensor of shape `from` as if it had shape `to`, repeating it along the stretched axes,
+/// or `None` if `from` can't be broadcast to `to`.
+pub(crate) fn broadcast_strides(
+    from: &[usize],
+    from_strides: &[usize],
+    to: &[usize],
+) -> Option<Box<[usize]>> {
+    if from.len() > to.len() {
+        return None;
+    }
+
+    let mut strides = vec![0; to.len()].into_boxed_slice();
+    let skipped = to.len() - from.len();
+
+    for axis in 0..from.len() {
+        if from[axis] == to[skipped + axis] {
+            strides[skipped + axis] = from_strides[axis];
+        } else if from[axis] != 1 {
+            return None;
+        }
+    }
+
+    Some(strides)
+}
+
+impl<T> FastTensor<T> {
+    /// ## Info
+    /// turns the [`FastTensor`] into a [`FastArray`] holding its elements in row-major order.
+    ///
+    /// this doesn't copy anything if self is contiguous.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::{fast_arr, FastTensor};
+    ///
+    /// let tensor = FastTensor::new_func(&[2, 2], |index| index[0] * 2 + index[1]);
+    ///
+    /// assert_eq!(tensor.into_fast_array(), fast_arr!(0, 1, 2, 3));
+    /// ```
+    pub fn into_fast_array(self) -> FastArray<T> {
+        let mut contiguous = self.into_contiguous();
+
+        let size = contiguous.len();
+        let pointer = contiguous.pointer;
+        contiguous.pointer = ptr::null_mut();
+
+        FastArray { pointer, size }
+    }
+
+    /// ## Info
+    /// turns a 2-dimensional [`FastTensor`] into a [`FastMatrix`].
+    ///
+    /// this doesn't copy anything if self is laid out in either row-major or column-major order, and the matrix keeps that [`StorageOrder`].
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::{fast_matrix, FastTensor};
+    ///
+    /// let tensor = FastTensor::new_func(&[2, 3], |index| index[0] * 3 + index[1]);
+    ///
+    /// assert_eq!(tensor.into_fast_matrix(), fast_matrix!([0, 1, 2], [3, 4, 5]));
+    /// ```
+    ///
+    /// ## Panics
+    /// if self doesn't have exactly 2 axes.
+    pub fn into_fast_matrix(self) -> FastMatrix<T> {
+        assert_eq!(
+            self.ndim(),
+            2,
+            "FastTensor: only 2-dimensional tensors can become a FastMatrix, found {:?}!",
+            self.shape
+        );
+
+        let (rows, columns) = (self.shape[0], self.shape[1]);
+
+        let (mut tensor, order) = if *self.strides == [1, rows] {
+            (self, StorageOrder::ColumnMajor)
+        } else {
+            (self.into_contiguous(), StorageOrder::RowMajor)
+        };
+
+        let pointer = tensor.pointer;
+        tensor.pointer = ptr::null_mut();
+
+        FastMatrix {
+            pointer,
+            rows,
+            columns,
+            order,
+        }
+    }
+}
+
+impl<T> From<FastArray<T>> for FastTensor<T> {
+    /// takes over the buffer of the [`FastArray`] as a 1-dimensional tensor, without copying anything.
+    fn from(mut value: FastArray<T>) -> Self {
+        let pointer = value.pointer;
+        value.pointer = ptr::null_mut();
+
+        FastTensor {
+            pointer,
+            shape: Box::new([value.size]),
+            strides: Box::new([1]),
+        }
+    }
+}
+
+impl<T> From<FastMatrix<T>> for FastTensor<T> {
+    /// takes over the buffer of the [`FastMatrix`] as a 2-dimensional tensor, without copying anything.
+    fn from(mut value: FastMatrix<T>) -> Self {
+        let (row_stride, column_stride) = value.strides();
+        let pointer = value.pointer;
+        value.pointer = ptr::null_mut();
+
+        FastTensor {
+            pointer,
+            shape: Box::new([value.rows, value.columns]),
+            strides: Box::new([row_stride, column_stride]),
+        }
+    }
+}
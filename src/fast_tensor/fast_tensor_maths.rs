@@ -0,0 +1,96 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use super::fast_tensor::FastTensor;
+
+impl<T> FastTensor<T> {
+    /// ## Info
+    /// creates a new [`FastTensor`] of the same shape, by applying `func` to every element of self.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::FastTensor;
+    ///
+    /// let tensor = FastTensor::new_func(&[2, 2], |index| index[0] + index[1]);
+    ///
+    /// assert_eq!(tensor.map(|element| element * 10)[[1, 1]], 20);
+    /// ```
+    pub fn map<U, F>(&self, mut func: F) -> FastTensor<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        let mut elements = self.iter();
+        FastTensor::new_func(&self.shape, |_| func(elements.next().unwrap()))
+    }
+
+    /// ## Info
+    /// creates a new [`FastTensor`] by applying `func` to every pair of elements of self and `other` at the same index.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::FastTensor;
+    ///
+    /// let a = FastTensor::new(&[2, 3], 2_i32);
+    /// let b = FastTensor::new_func(&[2, 3], |index| index[1]);
+    ///
+    /// assert_eq!(a.zip_map(&b, |a, b| a.pow(*b as u32))[[0, 2]], 4);
+    /// ```
+    ///
+    /// ## Panics
+    /// if the shapes of self and `other` don't match.
+    pub fn zip_map<U, V, F>(&self, other: &FastTensor<U>, mut func: F) -> FastTensor<V>
+    where
+        F: FnMut(&T, &U) -> V,
+    {
+        assert_eq!(
+            self.shape, other.shape,
+            "FastTensor: the shapes {:?} and {:?} don't match!",
+            self.shape, other.shape
+        );
+
+        let mut elements = self.iter().zip(other.iter());
+        FastTensor::new_func(&self.shape, |_| {
+            let (a, b) = elements.next().unwrap();
+            func(a, b)
+        })
+    }
+
+    /// ## Info
+    /// applies `func` to every element of self, in place.
+    pub fn apply<F>(&mut self, func: F)
+    where
+        F: FnMut(&mut T),
+    {
+        self.iter_mut().for_each(func);
+    }
+}
+
+macro_rules! impl_owned_ops {
+    ($($trait:ident, $method:ident);+ $(;)?) => {
+        $(
+            impl<T: Clone + $trait<Output = T>> $trait for FastTensor<T> {
+                type Output = FastTensor<T>;
+
+                /// ## Panics
+                /// if the shapes of the two tensors can't be broadcast together.
+                fn $method(self, rhs: FastTensor<T>) -> Self::Output {
+                    (&self).$method(&rhs)
+                }
+            }
+        )+
+    };
+}
+
+impl_owned_ops!(
+    Add, add;
+    Sub, sub;
+    Mul, mul;
+    Div, div;
+);
+
+impl<T: Clone + Neg<Output = T>> Neg for &FastTensor<T> {
+    type Output = FastTensor<T>;
+
+    fn neg(self) -> Self::Output {
+        self.map(|element| -element.clone())
+    }
+}
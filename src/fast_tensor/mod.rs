@@ -0,0 +1,3 @@
+pub mod fast_tensor;
+pub mod fast_tensor_basics;
+pub mod fast_tensor_maths;
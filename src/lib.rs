@@ -3,6 +3,8 @@
 //!
 //! by philosophy, no collection in this library is resizable.
 //!
+//! the `_unchecked` methods skip their checks for performance reasons, but only in release builds:
+//! with `debug_assertions` on (like in tests), breaking their preconditions panics with a descriptive message.
 //!
 
 #![cfg_attr(feature = "nightly", feature(step_trait))]
@@ -11,11 +13,23 @@
 // #![feature(step_trait)]
 #![allow(soft_unstable)]
 
+mod alloc;
+pub mod broadcast;
+pub mod error;
 pub mod fast_array;
 pub mod fast_iterator;
 pub mod fast_matrix;
+pub mod fast_tensor;
 pub mod macros;
+pub mod npy;
+pub mod pod;
 pub mod prelude;
+pub mod sparse_matrix;
+pub use error::{AllocError, FastError};
 pub use fast_array::fast_array::FastArray;
+pub use fast_array::fast_array_n::FastArrayN;
 pub use fast_iterator::fast_iterator::FastIterator;
-pub use fast_matrix::fast_matrix::FastMatrix;
\ No newline at end of file
+pub use fast_matrix::fast_matrix::FastMatrix;
+pub use fast_matrix::fast_matrix_n::FastMatrixN;
+pub use fast_tensor::fast_tensor::FastTensor;
+pub use sparse_matrix::sparse_matrix::SparseMatrix;
@@ -26,17 +26,28 @@ pub fn useless_fn<T>(_: &T) -> () {
 /// ## Examples
 /// possible syntaxes:
 /// ```
-/// use fast_array::fast_arr;
+/// use fast_collections::fast_arr;
 /// 
 /// let fast_arr1 = fast_arr!(1,2,3,4,5,6); // [1, 2, 3, 4, 5, 6]
 /// 
-/// let fast_arr2 = fast_arr!(3; 6) // [3, 3, 3, 3, 3, 3]
+/// let fast_arr2 = fast_arr!(3; 6); // [3, 3, 3, 3, 3, 3]
 /// 
 /// // repeating pattern syntax
-/// let fast_arr3 = fast_arr!([1,2]; 3) // [1, 2, 1, 2, 1, 2]
+/// let fast_arr3 = fast_arr!([1,2]; 3); // [1, 2, 1, 2, 1, 2]
+///
+/// // fixed-size FastArrayN, that lives on the stack
+/// let fast_arr4 = fast_arr!(const 1, 2, 3); // FastArrayN<i32, 3>
+/// let fast_arr5 = fast_arr!(const 0; 4); // FastArrayN<i32, 4>
 /// ```
 macro_rules! fast_arr {
-    
+    (const $value:expr; $num:expr) => {
+        $crate::fast_array::fast_array_n::FastArrayN::from([$value; $num])
+    };
+
+    (const $($val:expr),+ $(,)?) => {
+        $crate::fast_array::fast_array_n::FastArrayN::from([$($val),+])
+    };
+
     [$($val:expr),+$(,)?] => {
         {
             use $crate::fast_array::fast_array::FastArray;
@@ -51,7 +62,8 @@ macro_rules! fast_arr {
             let mut index = 0;
 
             $(
-                fast_arr[index] = $val;
+                let element = $val;
+                unsafe { ::std::ptr::write(<*mut _>::add(FastArray::as_mut_ptr(&mut fast_arr), index), element) };
                 index += 1;
             )+
 
@@ -71,7 +83,8 @@ macro_rules! fast_arr {
             
             for _ in 0..$reps {
                 $(
-                    fast_arr[index] = $element;
+                    let element = $element;
+                    unsafe { ::std::ptr::write(<*mut _>::add(FastArray::as_mut_ptr(&mut fast_arr), index), element) };
                     index+=1;
                 )+
             }
@@ -84,7 +97,7 @@ macro_rules! fast_arr {
         {
             use $crate::fast_array::fast_array::FastArray;
 
-            let closure = || {
+            let closure = |_| {
                 $value
             };
             let fast_arr = FastArray::new_func($num, closure);
@@ -142,7 +155,7 @@ macro_rules! fast_arr {
 /// ## Examples
 /// possible syntaxes:
 /// ```
-/// use fast_array::fast_matrix;
+/// use fast_collections::fast_matrix;
 /// 
 /// // [
 /// //     [1, 2, 3],
@@ -171,8 +184,20 @@ macro_rules! fast_arr {
 /// //   [4, 5, 6, 4, 5, 6],
 /// // ]
 /// let fast_matrix4 = fast_matrix!([1,2; 3], [3; 6], [4, 5, 6; 2]);
+///
+/// // fixed-size FastMatrixN, that lives on the stack
+/// let fast_matrix5 = fast_matrix!(const [1, 2], [3, 4]); // FastMatrixN<i32, 2, 2>
+/// let fast_matrix6 = fast_matrix!(const 0; 2; 3); // FastMatrixN<i32, 2, 3>
 /// ```
 macro_rules! fast_matrix {
+    (const $element:expr; $rows:expr; $cols:expr) => {
+        $crate::fast_matrix::fast_matrix_n::FastMatrixN::from([[$element; $cols]; $rows])
+    };
+
+    (const $([$($element:expr),+ $(,)?]),+ $(,)?) => {
+        $crate::fast_matrix::fast_matrix_n::FastMatrixN::from([$([$($element),+]),+])
+    };
+
     ($( [$( $element:expr ),+ $(,)?; $reps:expr ] ),+ $(,)?) => {
         {
             use $crate::FastMatrix;
@@ -204,7 +229,8 @@ macro_rules! fast_matrix {
                 let reps: usize = $reps;
                 for _ in 0..reps {
                     $(
-                        fast_matrix[(_row_index, _col_index)] = $element;
+                        let element = $element;
+                        unsafe { ::std::ptr::write(FastMatrix::get_pointer_mut_unchecked(&mut fast_matrix, (_row_index, _col_index)), element) };
                         _col_index+=1;
                     )+
                 }
@@ -235,7 +261,8 @@ macro_rules! fast_matrix {
 
             for index_row in 0..$num {
                 $(
-                    fast_matrix[(index_row, index_column)] = $element;
+                    let element = $element;
+                    unsafe { ::std::ptr::write(FastMatrix::get_pointer_mut_unchecked(&mut fast_matrix, (index_row, index_column)), element) };
                     index_column+=1;
                 )+
                 index_column = 0;
@@ -264,7 +291,8 @@ macro_rules! fast_matrix {
 
             $(
                 $(
-                    fast_matrix[(_index_row, _index_column)] = $element;
+                    let element = $element;
+                    unsafe { ::std::ptr::write(FastMatrix::get_pointer_mut_unchecked(&mut fast_matrix, (_index_row, _index_column)), element) };
                     _index_column+=1;
 
                 )+
@@ -289,5 +317,5 @@ macro_rules! fast_matrix {
 #[test]
 fn test() {
     let fast_matrix: crate::FastMatrix<usize> = fast_matrix!([1,2; 3], [3; 6], [4, 5, 6; 2]);
-    println!("{}", fast_matrix)
+    assert_eq!(fast_matrix.to_string(), fast_matrix!([1, 2, 1, 2, 1, 2], [3, 3, 3, 3, 3, 3], [4, 5, 6, 4, 5, 6]).to_string());
 }
@@ -0,0 +1,549 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    io::{self, Read, Write},
+    ptr,
+};
+
+use crate::{
+    fast_matrix::fast_matrix::StorageOrder,
+    pod::{Endian, Pod},
+    FastArray, FastMatrix,
+};
+
+const MAGIC: &[u8; 6] = b"\x93NUMPY";
+
+/// ## Info
+/// everything that can go wrong while reading a `.npy` file or a `.npz` archive.
+#[derive(Debug)]
+pub enum NpyError {
+    /// reading or writing failed.
+    Io(io::Error),
+    /// the data doesn't start with the `\x93NUMPY` magic string.
+    InvalidMagic,
+    /// the format version isn't 1.0, 2.0 or 3.0.
+    UnsupportedVersion { major: u8, minor: u8 },
+    /// the header dictionary couldn't be parsed.
+    MalformedHeader(String),
+    /// the dtype of the file isn't the one of the requested element type.
+    DtypeMismatch { expected: String, found: String },
+    /// the file doesn't hold an array with the requested number of dimensions.
+    ShapeMismatch { expected_ndim: usize, found: Box<[usize]> },
+    /// the array data is invalid, like an empty array or a `bool` that isn't 0 or 1.
+    InvalidData(String),
+    /// the `.npz` archive couldn't be read.
+    MalformedArchive(String),
+    /// there's no array with that name in the `.npz` archive.
+    MissingArray(String),
+}
+
+impl Display for NpyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NpyError::Io(error) => write!(f, "npy: {error}"),
+            NpyError::InvalidMagic => write!(f, "npy: the data doesn't start with the npy magic string"),
+            NpyError::UnsupportedVersion { major, minor } => {
+                write!(f, "npy: unsupported format version {major}.{minor}")
+            }
+            NpyError::MalformedHeader(reason) => write!(f, "npy: malformed header: {reason}"),
+            NpyError::DtypeMismatch { expected, found } => {
+                write!(f, "npy: expected the dtype '{expected}', found '{found}'")
+            }
+            NpyError::ShapeMismatch { expected_ndim, found } => {
+                write!(f, "npy: expected a {expected_ndim}-d array, found the shape {found:?}")
+            }
+            NpyError::InvalidData(reason) => write!(f, "npy: invalid data: {reason}"),
+            NpyError::MalformedArchive(reason) => write!(f, "npz: malformed archive: {reason}"),
+            NpyError::MissingArray(name) => write!(f, "npz: there's no array named '{name}'"),
+        }
+    }
+}
+
+impl Error for NpyError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            NpyError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for NpyError {
+    fn from(value: io::Error) -> Self {
+        NpyError::Io(value)
+    }
+}
+
+/// ## Info
+/// an element type that can be stored in a `.npy` file.
+///
+/// implemented for `bool`, the signed and unsigned integers from 8 to 64 bits, `f32` and `f64`.
+pub trait NpyElement: Copy + 'static {
+    /// the numpy type kind: `b'b'` for booleans, `b'i'` for signed integers, `b'u'` for unsigned ones and `b'f'` for floats.
+    const KIND: u8;
+
+    /// decodes one element from exactly `size_of::<Self>()` bytes in the `endian` byte order.
+    fn decode(bytes: &[u8], endian: Endian) -> Result<Self, NpyError>;
+
+    /// appends the native-endian bytes of the element to `out`.
+    fn encode(self, out: &mut Vec<u8>);
+
+    /// ## Info
+    /// returns the numpy dtype string of the type in the native byte order, like `"<f8"` or `"|u1"`.
+    fn descr() -> String {
+        let size = std::mem::size_of::<Self>();
+        let order = if size == 1 {
+            '|'
+        } else if cfg!(target_endian = "little") {
+            '<'
+        } else {
+            '>'
+        };
+
+        format!("{order}{}{size}", Self::KIND as char)
+    }
+}
+
+macro_rules! impl_npy_pod {
+    ($($type:ty: $kind:literal),+ $(,)?) => {
+        $(
+            impl NpyElement for $type {
+                const KIND: u8 = $kind;
+
+                #[inline(always)]
+                fn decode(bytes: &[u8], endian: Endian) -> Result<Self, NpyError> {
+                    let value = unsafe { ptr::read_unaligned(bytes.as_ptr() as *const $type) };
+
+                    Ok(if endian.is_native() { value } else { Pod::swap_bytes(value) })
+                }
+
+                #[inline(always)]
+                fn encode(self, out: &mut Vec<u8>) {
+                    out.extend_from_slice(&self.to_ne_bytes());
+                }
+            }
+        )+
+    };
+}
+
+impl_npy_pod!(
+    u8: b'u', u16: b'u', u32: b'u', u64: b'u',
+    i8: b'i', i16: b'i', i32: b'i', i64: b'i',
+    f32: b'f', f64: b'f',
+);
+
+impl NpyElement for bool {
+    const KIND: u8 = b'b';
+
+    #[inline(always)]
+    fn decode(bytes: &[u8], _: Endian) -> Result<Self, NpyError> {
+        match bytes[0] {
+            0 => Ok(false),
+            1 => Ok(true),
+            byte => Err(NpyError::InvalidData(format!("{byte} isn't a valid bool"))),
+        }
+    }
+
+    #[inline(always)]
+    fn encode(self, out: &mut Vec<u8>) {
+        out.push(self as u8);
+    }
+}
+
+/// ## Info
+/// the header of a `.npy` file: the dtype, the memory order and the shape of the array.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NpyHeader {
+    /// the numpy dtype string, like `"<f8"`.
+    pub descr: String,
+    /// `true` if the elements are stored column after column.
+    pub fortran_order: bool,
+    pub shape: Box<[usize]>,
+}
+
+impl NpyHeader {
+    /// ## Info
+    /// reads and parses the magic string, the version and the header dictionary of a `.npy` file.
+    ///
+    /// ## Errors
+    /// if the magic string, the version or the header dictionary are invalid, or if reading fails.
+    pub fn read(mut reader: impl Read) -> Result<NpyHeader, NpyError> {
+        let mut preamble = [0; 8];
+        reader.read_exact(&mut preamble)?;
+
+        if &preamble[..6] != MAGIC {
+            return Err(NpyError::InvalidMagic);
+        }
+
+        let header_len = match (preamble[6], preamble[7]) {
+            (1, 0) => {
+                let mut len = [0; 2];
+                reader.read_exact(&mut len)?;
+                u16::from_le_bytes(len) as usize
+            }
+            (2, 0) | (3, 0) => {
+                let mut len = [0; 4];
+                reader.read_exact(&mut len)?;
+                u32::from_le_bytes(len) as usize
+            }
+            (major, minor) => return Err(NpyError::UnsupportedVersion { major, minor }),
+        };
+
+        let mut header = Vec::new();
+        reader.take(header_len as u64).read_to_end(&mut header)?;
+        if header.len() != header_len {
+            return Err(NpyError::Io(io::ErrorKind::UnexpectedEof.into()));
+        }
+
+        let header = String::from_utf8(header)
+            .map_err(|_| NpyError::MalformedHeader("the header isn't valid utf-8".to_string()))?;
+
+        HeaderParser { input: header.trim_end().as_bytes(), position: 0 }.parse()
+    }
+
+    /// ## Info
+    /// writes the magic string, the version and the header dictionary, padded so that the data is 64-byte aligned.
+    ///
+    /// ## Errors
+    /// if writing fails.
+    pub fn write(&self, mut writer: impl Write) -> io::Result<()> {
+        let shape = match self.shape.len() {
+            1 => format!("({},)", self.shape[0]),
+            _ => format!(
+                "({})",
+                self.shape.iter().map(|len| len.to_string()).collect::<Vec<_>>().join(", ")
+            ),
+        };
+
+        let mut dict = format!(
+            "{{'descr': '{}', 'fortran_order': {}, 'shape': {}, }}",
+            self.descr,
+            if self.fortran_order { "True" } else { "False" },
+            shape
+        );
+
+        // the magic string, the version, the header length, and the newline that ends the header
+        let padding = |len_size: usize| {
+            let unpadded = MAGIC.len() + 2 + len_size + dict.len() + 1;
+            unpadded.next_multiple_of(64) - unpadded
+        };
+        let v1_len = dict.len() + padding(2) + 1;
+        let (version, padding) = match u16::try_from(v1_len) {
+            Ok(_) => (1, padding(2)),
+            Err(_) => (2, padding(4)),
+        };
+        dict.extend(std::iter::repeat_n(' ', padding));
+        dict.push('\n');
+
+        writer.write_all(MAGIC)?;
+        writer.write_all(&[version, 0])?;
+        match version {
+            1 => writer.write_all(&(dict.len() as u16).to_le_bytes())?,
+            _ => writer.write_all(&(dict.len() as u32).to_le_bytes())?,
+        }
+        writer.write_all(dict.as_bytes())
+    }
+
+    /// checks that the header describes a non-empty `ndim`-d array of `T`, and returns its endianness and its number of elements.
+    fn check<T: NpyElement>(&self, ndim: usize) -> Result<(Endian, usize), NpyError> {
+        if self.shape.len() != ndim {
+            return Err(NpyError::ShapeMismatch { expected_ndim: ndim, found: self.shape.clone() });
+        }
+
+        let mismatch = || NpyError::DtypeMismatch { expected: T::descr(), found: self.descr.clone() };
+
+        let bytes = self.descr.as_bytes();
+        let (&order, kind, size) = match bytes {
+            [order, kind, size @ ..] => (order, *kind, size),
+            _ => return Err(mismatch()),
+        };
+
+        let endian = match order {
+            b'<' => Endian::Little,
+            b'>' => Endian::Big,
+            b'|' | b'=' => Endian::Native,
+            _ => return Err(mismatch()),
+        };
+
+        let size = std::str::from_utf8(size).ok().and_then(|size| size.parse::<usize>().ok());
+        if kind != T::KIND || size != Some(std::mem::size_of::<T>()) {
+            return Err(mismatch());
+        }
+
+        let len = self
+            .shape
+            .iter()
+            .try_fold(1_usize, |len, &dim| len.checked_mul(dim))
+            .ok_or_else(|| NpyError::MalformedHeader("the shape is too big".to_string()))?;
+
+        if len == 0 {
+            return Err(NpyError::InvalidData("empty arrays aren't supported".to_string()));
+        }
+
+        Ok((endian, len))
+    }
+}
+
+/// a parser for the python dictionary literal of a `.npy` header.
+struct HeaderParser<'a> {
+    input: &'a [u8],
+    position: usize,
+}
+
+impl HeaderParser<'_> {
+    fn error(&self, reason: &str) -> NpyError {
+        NpyError::MalformedHeader(format!("{reason} at byte {}", self.position))
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.input.get(self.position).is_some_and(u8::is_ascii_whitespace) {
+            self.position += 1;
+        }
+    }
+
+    fn peek(&mut self) -> Option<u8> {
+        self.skip_whitespace();
+        self.input.get(self.position).copied()
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), NpyError> {
+        if self.peek() != Some(byte) {
+            return Err(self.error(&format!("expected '{}'", byte as char)));
+        }
+
+        self.position += 1;
+        Ok(())
+    }
+
+    /// eats `byte` if it is next.
+    fn eat(&mut self, byte: u8) -> bool {
+        let found = self.peek() == Some(byte);
+        if found {
+            self.position += 1;
+        }
+        found
+    }
+
+    fn string(&mut self) -> Result<String, NpyError> {
+        let quote = match self.peek() {
+            Some(quote @ (b'\'' | b'"')) => quote,
+            _ => return Err(self.error("expected a string")),
+        };
+
+        let start = self.position + 1;
+        let len = self.input[start..]
+            .iter()
+            .position(|&byte| byte == quote)
+            .ok_or_else(|| self.error("unterminated string"))?;
+
+        self.position = start + len + 1;
+        Ok(String::from_utf8_lossy(&self.input[start..start + len]).into_owned())
+    }
+
+    fn word(&mut self) -> &[u8] {
+        self.skip_whitespace();
+        let start = self.position;
+        while self.input.get(self.position).is_some_and(u8::is_ascii_alphanumeric) {
+            self.position += 1;
+        }
+        &self.input[start..self.position]
+    }
+
+    fn boolean(&mut self) -> Result<bool, NpyError> {
+        match self.word() {
+            b"True" => Ok(true),
+            b"False" => Ok(false),
+            _ => Err(self.error("expected True or False")),
+        }
+    }
+
+    fn shape(&mut self) -> Result<Box<[usize]>, NpyError> {
+        self.expect(b'(')?;
+
+        let mut shape = Vec::new();
+        while !self.eat(b')') {
+            let dim = std::str::from_utf8(self.word())
+                .ok()
+                .and_then(|word| word.strip_suffix('L').unwrap_or(word).parse().ok())
+                .ok_or_else(|| self.error("expected a dimension"))?;
+            shape.push(dim);
+
+            if !self.eat(b',') {
+                self.expect(b')')?;
+                break;
+            }
+        }
+
+        Ok(shape.into_boxed_slice())
+    }
+
+    fn parse(mut self) -> Result<NpyHeader, NpyError> {
+        let (mut descr, mut fortran_order, mut shape) = (None, None, None);
+
+        self.expect(b'{')?;
+        while !self.eat(b'}') {
+            let key = self.string()?;
+            self.expect(b':')?;
+
+            match key.as_str() {
+                "descr" => descr = Some(self.string()?),
+                "fortran_order" => fortran_order = Some(self.boolean()?),
+                "shape" => shape = Some(self.shape()?),
+                _ => return Err(NpyError::MalformedHeader(format!("unknown key '{key}'"))),
+            }
+
+            if !self.eat(b',') {
+                self.expect(b'}')?;
+                break;
+            }
+        }
+
+        if self.peek().is_some() {
+            return Err(self.error("unexpected data after the dictionary"));
+        }
+
+        let missing = |key: &str| NpyError::MalformedHeader(format!("the key '{key}' is missing"));
+        Ok(NpyHeader {
+            descr: descr.ok_or_else(|| missing("descr"))?,
+            fortran_order: fortran_order.ok_or_else(|| missing("fortran_order"))?,
+            shape: shape.ok_or_else(|| missing("shape"))?,
+        })
+    }
+}
+
+/// reads and decodes the `len` elements that follow the header.
+fn read_elements<T: NpyElement>(reader: impl Read, len: usize, endian: Endian) -> Result<Vec<T>, NpyError> {
+    let size = std::mem::size_of::<T>();
+    let byte_len = len
+        .checked_mul(size)
+        .ok_or_else(|| NpyError::MalformedHeader("the shape is too big".to_string()))?;
+
+    // grows with the data actually read, so a lying header can't trigger a huge allocation
+    let mut bytes = Vec::new();
+    reader.take(byte_len as u64).read_to_end(&mut bytes)?;
+    if bytes.len() != byte_len {
+        return Err(NpyError::Io(io::ErrorKind::UnexpectedEof.into()));
+    }
+
+    bytes.chunks_exact(size).map(|element| T::decode(element, endian)).collect()
+}
+
+/// encodes `elements` after a header of the given shape and order.
+fn write_elements<'a, T: NpyElement>(
+    mut writer: impl Write,
+    shape: &[usize],
+    fortran_order: bool,
+    elements: impl Iterator<Item = &'a T>,
+) -> io::Result<()> {
+    NpyHeader { descr: T::descr(), fortran_order, shape: shape.into() }.write(&mut writer)?;
+
+    let mut bytes = Vec::with_capacity(shape.iter().product::<usize>() * std::mem::size_of::<T>());
+    for element in elements {
+        element.encode(&mut bytes);
+    }
+
+    writer.write_all(&bytes)
+}
+
+impl<T: NpyElement> FastArray<T> {
+    /// ## Info
+    /// reads a 1-d array from `.npy` data.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::{fast_arr, FastArray};
+    ///
+    /// let mut npy = Vec::new();
+    /// fast_arr!(1.5_f64, 2.5, 3.5).write_npy(&mut npy).unwrap();
+    ///
+    /// assert_eq!(FastArray::<f64>::read_npy(npy.as_slice()).unwrap(), fast_arr!(1.5, 2.5, 3.5));
+    ///
+    /// // the dtype has to match exactly
+    /// assert!(FastArray::<f32>::read_npy(npy.as_slice()).is_err());
+    /// ```
+    ///
+    /// ## Errors
+    /// if the header is malformed, if the array isn't a non-empty 1-d array of `T`, or if reading fails.
+    pub fn read_npy(mut reader: impl Read) -> Result<FastArray<T>, NpyError> {
+        let header = NpyHeader::read(&mut reader)?;
+        let (endian, len) = header.check::<T>(1)?;
+
+        Ok(FastArray::from(read_elements::<T>(reader, len, endian)?))
+    }
+
+    /// ## Info
+    /// writes the [`FastArray`] as `.npy` data, with a 1-d shape and the native byte order.
+    ///
+    /// ## Errors
+    /// if writing fails.
+    pub fn write_npy(&self, writer: impl Write) -> io::Result<()> {
+        let elements = unsafe { std::slice::from_raw_parts(self.pointer, self.size) };
+
+        write_elements(writer, &[self.size], false, elements.iter())
+    }
+}
+
+impl<T: NpyElement> FastMatrix<T> {
+    /// ## Info
+    /// reads a 2-d array from `.npy` data.
+    ///
+    /// Fortran-ordered data gives a [`StorageOrder::ColumnMajor`] matrix, so the elements never need to be shuffled around.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::{fast_matrix, FastMatrix};
+    /// use fast_collections::fast_matrix::fast_matrix::StorageOrder;
+    ///
+    /// let fast_matrix = fast_matrix!([1_i32, 2, 3], [4, 5, 6]).into_order(StorageOrder::ColumnMajor);
+    ///
+    /// let mut npy = Vec::new();
+    /// fast_matrix.write_npy(&mut npy).unwrap();
+    /// let read = FastMatrix::<i32>::read_npy(npy.as_slice()).unwrap();
+    ///
+    /// assert_eq!(read.order(), StorageOrder::ColumnMajor);
+    /// assert_eq!(read, fast_matrix!([1, 2, 3], [4, 5, 6]));
+    /// ```
+    ///
+    /// ## Errors
+    /// if the header is malformed, if the array isn't a non-empty 2-d array of `T`, or if reading fails.
+    pub fn read_npy(mut reader: impl Read) -> Result<FastMatrix<T>, NpyError> {
+        let header = NpyHeader::read(&mut reader)?;
+        let (endian, len) = header.check::<T>(2)?;
+        let (rows, columns) = (header.shape[0], header.shape[1]);
+
+        let mut elements = read_elements::<T>(reader, len, endian)?.into_iter();
+
+        // the elements are in storage order, so fill the buffer as it is and fix the shape afterwards
+        let (storage_rows, storage_columns) = match header.fortran_order {
+            false => (rows, columns),
+            true => (columns, rows),
+        };
+        let mut fast_matrix = FastMatrix::new_func(storage_rows, storage_columns, |_| elements.next().unwrap());
+
+        fast_matrix.rows = rows;
+        fast_matrix.columns = columns;
+        if header.fortran_order {
+            fast_matrix.order = StorageOrder::ColumnMajor;
+        }
+
+        Ok(fast_matrix)
+    }
+
+    /// ## Info
+    /// writes the [`FastMatrix`] as 2-d `.npy` data in the native byte order.
+    ///
+    /// column-major matrices are written in Fortran order, so the elements are written as they are stored.
+    ///
+    /// ## Errors
+    /// if writing fails.
+    pub fn write_npy(&self, writer: impl Write) -> io::Result<()> {
+        let elements = unsafe { std::slice::from_raw_parts(self.pointer, self.rows * self.columns) };
+
+        write_elements(
+            writer,
+            &[self.rows, self.columns],
+            self.order == StorageOrder::ColumnMajor,
+            elements.iter(),
+        )
+    }
+}
@@ -0,0 +1,338 @@
+use std::io::{self, Read, Write};
+
+use super::npy::{NpyElement, NpyError};
+use crate::{FastArray, FastMatrix};
+
+const LOCAL_HEADER: u32 = 0x04034b50;
+const CENTRAL_HEADER: u32 = 0x02014b50;
+const END_OF_CENTRAL_DIRECTORY: u32 = 0x06054b50;
+const ZIP64_EXTRA: u16 = 0x0001;
+
+/// the crc-32 (ieee) checksum zip archives use.
+fn crc32(bytes: &[u8]) -> u32 {
+    const TABLE: [u32; 256] = {
+        let mut table = [0; 256];
+        let mut i = 0;
+        while i < 256 {
+            let mut crc = i as u32;
+            let mut bit = 0;
+            while bit < 8 {
+                crc = if crc & 1 == 1 { (crc >> 1) ^ 0xEDB88320 } else { crc >> 1 };
+                bit += 1;
+            }
+            table[i] = crc;
+            i += 1;
+        }
+        table
+    };
+
+    !bytes
+        .iter()
+        .fold(!0_u32, |crc, &byte| TABLE[((crc ^ byte as u32) & 0xFF) as usize] ^ (crc >> 8))
+}
+
+/// a little-endian cursor over the bytes of an archive.
+struct Cursor<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], NpyError> {
+        let bytes = self
+            .position
+            .checked_add(len)
+            .and_then(|end| self.bytes.get(self.position..end))
+            .ok_or_else(|| NpyError::MalformedArchive("unexpected end of the archive".to_string()))?;
+
+        self.position += len;
+        Ok(bytes)
+    }
+
+    fn u16(&mut self) -> Result<u16, NpyError> {
+        Ok(u16::from_le_bytes(self.take(2)?.try_into().unwrap()))
+    }
+
+    fn u32(&mut self) -> Result<u32, NpyError> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn u64(&mut self) -> Result<u64, NpyError> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+    }
+}
+
+/// ## Info
+/// an uncompressed `.npz` archive (as made by `numpy.savez`), read in memory.
+///
+/// ## Example
+/// ```
+/// use fast_collections::{fast_arr, fast_matrix};
+/// use fast_collections::npy::npz::{NpzArchive, NpzWriter};
+///
+/// let mut writer = NpzWriter::new(Vec::new());
+/// writer.add_array("weights", &fast_arr!(0.5_f32, 0.25)).unwrap();
+/// writer.add_matrix("adjacency", &fast_matrix!([true, false], [false, true])).unwrap();
+/// let bytes = writer.finish().unwrap();
+///
+/// let archive = NpzArchive::read(bytes.as_slice()).unwrap();
+///
+/// assert_eq!(archive.names().collect::<Vec<_>>(), vec!["weights", "adjacency"]);
+/// assert_eq!(archive.array::<f32>("weights").unwrap(), fast_arr!(0.5, 0.25));
+/// assert_eq!(archive.matrix::<bool>("adjacency").unwrap(), fast_matrix!([true, false], [false, true]));
+/// assert!(archive.array::<f32>("biases").is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct NpzArchive {
+    data: Vec<u8>,
+    entries: Vec<NpzEntry>,
+}
+
+#[derive(Debug, Clone)]
+struct NpzEntry {
+    name: String,
+    start: usize,
+    len: usize,
+}
+
+impl NpzArchive {
+    /// ## Info
+    /// reads a whole `.npz` archive, and lists the arrays it holds.
+    ///
+    /// ## Errors
+    /// if reading fails, if the data isn't a zip archive, or if an array is compressed (only `numpy.savez` archives are supported, not `numpy.savez_compressed` ones).
+    pub fn read(mut reader: impl Read) -> Result<NpzArchive, NpyError> {
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data)?;
+
+        let malformed = |reason: &str| NpyError::MalformedArchive(reason.to_string());
+
+        // the end of central directory record is the last thing in the archive, followed by a comment of up to 65535 bytes
+        let end = (0..data.len().saturating_sub(21))
+            .rev()
+            .take(u16::MAX as usize + 1)
+            .find(|&i| data[i..i + 4] == END_OF_CENTRAL_DIRECTORY.to_le_bytes())
+            .ok_or_else(|| malformed("no end of central directory record"))?;
+
+        let mut cursor = Cursor { bytes: &data, position: end + 10 };
+        let count = cursor.u16()? as usize;
+        cursor.take(4)?;
+        let directory = cursor.u32()? as usize;
+
+        let mut entries = Vec::with_capacity(count);
+        let mut cursor = Cursor { bytes: &data, position: directory };
+
+        for _ in 0..count {
+            if cursor.u32()? != CENTRAL_HEADER {
+                return Err(malformed("invalid central directory entry"));
+            }
+
+            cursor.take(6)?;
+            let compression = cursor.u16()?;
+            cursor.take(8)?;
+            let mut compressed_len = cursor.u32()? as u64;
+            let mut len = cursor.u32()? as u64;
+            let name_len = cursor.u16()? as usize;
+            let extra_len = cursor.u16()? as usize;
+            let comment_len = cursor.u16()? as usize;
+            cursor.take(8)?;
+            let mut offset = cursor.u32()? as u64;
+            let name = String::from_utf8_lossy(cursor.take(name_len)?).into_owned();
+
+            // zip64 archives store the fields that don't fit in 32 bits in an extra field, in that order
+            let mut extra = Cursor { bytes: cursor.take(extra_len)?, position: 0 };
+            while extra.position + 4 <= extra.bytes.len() {
+                let id = extra.u16()?;
+                let size = extra.u16()? as usize;
+                let mut field = Cursor { bytes: extra.take(size)?, position: 0 };
+
+                if id == ZIP64_EXTRA {
+                    for value in [&mut len, &mut compressed_len, &mut offset] {
+                        if *value == u32::MAX as u64 {
+                            *value = field.u64()?;
+                        }
+                    }
+                }
+            }
+            cursor.take(comment_len)?;
+
+            if compression != 0 {
+                return Err(malformed(&format!("'{name}' is compressed, which isn't supported")));
+            }
+            if compressed_len != len {
+                return Err(malformed(&format!("'{name}' has inconsistent sizes")));
+            }
+
+            // the local header repeats the name, and may have a different extra field
+            let mut local = Cursor { bytes: &data, position: usize::try_from(offset).map_err(|_| malformed("invalid offset"))? };
+            if local.u32()? != LOCAL_HEADER {
+                return Err(malformed(&format!("invalid local header for '{name}'")));
+            }
+            local.take(22)?;
+            let local_name_len = local.u16()? as usize;
+            let local_extra_len = local.u16()? as usize;
+            local.take(local_name_len + local_extra_len)?;
+
+            let len = usize::try_from(len).map_err(|_| malformed("the array is too big"))?;
+            let start = local.position;
+            local.take(len)?;
+
+            entries.push(NpzEntry {
+                name: name.strip_suffix(".npy").unwrap_or(&name).to_string(),
+                start,
+                len,
+            });
+        }
+
+        Ok(NpzArchive { data, entries })
+    }
+
+    /// ## Info
+    /// returns the names of the arrays in the archive, without their `.npy` extension.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.entries.iter().map(|entry| entry.name.as_str())
+    }
+
+    /// ## Info
+    /// returns the raw `.npy` data of the array called `name`.
+    ///
+    /// ## Errors
+    /// if there's no array called `name`.
+    pub fn npy_bytes(&self, name: &str) -> Result<&[u8], NpyError> {
+        self.entries
+            .iter()
+            .find(|entry| entry.name == name)
+            .map(|entry| &self.data[entry.start..entry.start + entry.len])
+            .ok_or_else(|| NpyError::MissingArray(name.to_string()))
+    }
+
+    /// ## Info
+    /// reads the 1-d array called `name`, see [`FastArray::read_npy`].
+    ///
+    /// ## Errors
+    /// if there's no array called `name`, or if it isn't a 1-d array of `T`.
+    pub fn array<T: NpyElement>(&self, name: &str) -> Result<FastArray<T>, NpyError> {
+        FastArray::read_npy(self.npy_bytes(name)?)
+    }
+
+    /// ## Info
+    /// reads the 2-d array called `name`, see [`FastMatrix::read_npy`].
+    ///
+    /// ## Errors
+    /// if there's no array called `name`, or if it isn't a 2-d array of `T`.
+    pub fn matrix<T: NpyElement>(&self, name: &str) -> Result<FastMatrix<T>, NpyError> {
+        FastMatrix::read_npy(self.npy_bytes(name)?)
+    }
+}
+
+/// ## Info
+/// writes an uncompressed `.npz` archive, readable by `numpy.load`.
+///
+/// the arrays are written as they are added, and the archive is completed by [`NpzWriter::finish`].
+#[derive(Debug)]
+pub struct NpzWriter<W: Write> {
+    writer: W,
+    written: u64,
+    directory: Vec<u8>,
+    count: u16,
+}
+
+impl<W: Write> NpzWriter<W> {
+    /// ## Info
+    /// creates a new, empty archive that will be written to `writer`.
+    pub fn new(writer: W) -> NpzWriter<W> {
+        NpzWriter { writer, written: 0, directory: Vec::new(), count: 0 }
+    }
+
+    /// ## Info
+    /// adds `npy`, the `.npy` data of an array, to the archive as `name.npy`.
+    ///
+    /// ## Errors
+    /// if writing fails, or if the archive grows past the limits of a non-zip64 archive (4 GiB, 65535 arrays).
+    pub fn add_npy(&mut self, name: &str, npy: &[u8]) -> io::Result<()> {
+        let name = format!("{name}.npy");
+        let too_big = || io::Error::new(io::ErrorKind::InvalidInput, "npz: the archive is too big");
+
+        let offset = u32::try_from(self.written).map_err(|_| too_big())?;
+        let len = u32::try_from(npy.len()).map_err(|_| too_big())?;
+        let name_len = u16::try_from(name.len()).map_err(|_| too_big())?;
+        let count = self.count.checked_add(1).ok_or_else(too_big)?;
+        let crc = crc32(npy);
+
+        // version needed, flags, compression (stored), modification time and date, crc, sizes, name and extra lengths
+        let mut common = Vec::with_capacity(26);
+        common.extend_from_slice(&20_u16.to_le_bytes());
+        common.extend_from_slice(&0_u16.to_le_bytes());
+        common.extend_from_slice(&0_u16.to_le_bytes());
+        common.extend_from_slice(&0_u16.to_le_bytes());
+        common.extend_from_slice(&0x21_u16.to_le_bytes());
+        common.extend_from_slice(&crc.to_le_bytes());
+        common.extend_from_slice(&len.to_le_bytes());
+        common.extend_from_slice(&len.to_le_bytes());
+        common.extend_from_slice(&name_len.to_le_bytes());
+        common.extend_from_slice(&0_u16.to_le_bytes());
+
+        self.writer.write_all(&LOCAL_HEADER.to_le_bytes())?;
+        self.writer.write_all(&common)?;
+        self.writer.write_all(name.as_bytes())?;
+        self.writer.write_all(npy)?;
+        self.written += 30 + name.len() as u64 + npy.len() as u64;
+
+        // made by, then the same fields, then comment length, disk, attributes and offset
+        self.directory.extend_from_slice(&CENTRAL_HEADER.to_le_bytes());
+        self.directory.extend_from_slice(&20_u16.to_le_bytes());
+        self.directory.extend_from_slice(&common);
+        self.directory.extend_from_slice(&[0; 10]);
+        self.directory.extend_from_slice(&offset.to_le_bytes());
+        self.directory.extend_from_slice(name.as_bytes());
+        self.count = count;
+
+        Ok(())
+    }
+
+    /// ## Info
+    /// adds a [`FastArray`] to the archive as `name.npy`.
+    ///
+    /// ## Errors
+    /// see [`NpzWriter::add_npy`].
+    pub fn add_array<T: NpyElement>(&mut self, name: &str, fast_array: &FastArray<T>) -> io::Result<()> {
+        let mut npy = Vec::new();
+        fast_array.write_npy(&mut npy)?;
+        self.add_npy(name, &npy)
+    }
+
+    /// ## Info
+    /// adds a [`FastMatrix`] to the archive as `name.npy`.
+    ///
+    /// ## Errors
+    /// see [`NpzWriter::add_npy`].
+    pub fn add_matrix<T: NpyElement>(&mut self, name: &str, fast_matrix: &FastMatrix<T>) -> io::Result<()> {
+        let mut npy = Vec::new();
+        fast_matrix.write_npy(&mut npy)?;
+        self.add_npy(name, &npy)
+    }
+
+    /// ## Info
+    /// writes the central directory that completes the archive, and returns the writer.
+    ///
+    /// ## Errors
+    /// if writing fails, or if the archive is too big.
+    pub fn finish(mut self) -> io::Result<W> {
+        let too_big = || io::Error::new(io::ErrorKind::InvalidInput, "npz: the archive is too big");
+        let offset = u32::try_from(self.written).map_err(|_| too_big())?;
+        let directory_len = u32::try_from(self.directory.len()).map_err(|_| too_big())?;
+
+        self.writer.write_all(&self.directory)?;
+
+        self.writer.write_all(&END_OF_CENTRAL_DIRECTORY.to_le_bytes())?;
+        self.writer.write_all(&[0; 4])?;
+        self.writer.write_all(&self.count.to_le_bytes())?;
+        self.writer.write_all(&self.count.to_le_bytes())?;
+        self.writer.write_all(&directory_len.to_le_bytes())?;
+        self.writer.write_all(&offset.to_le_bytes())?;
+        self.writer.write_all(&0_u16.to_le_bytes())?;
+
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
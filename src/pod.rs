@@ -0,0 +1,67 @@
+/// ## Info
+/// plain-old-data: a [`Copy`] type without padding, for which every bit pattern is a valid value.
+///
+/// this is what allows reading the elements of a [`FastArray`](crate::FastArray) straight from raw bytes, like a file or a memory map.
+///
+/// ## Safety
+/// implementing this trait for a type with padding bytes, or with invalid bit patterns (like `bool`, `char` or references), is undefined behavior.
+pub unsafe trait Pod: Copy + 'static {
+    /// ## Info
+    /// reverses the byte order of the value, used to convert between [`Endian`]s.
+    ///
+    /// for types made of several fields, every field should be swapped on its own.
+    fn swap_bytes(self) -> Self;
+}
+
+macro_rules! impl_pod {
+    (ints: $($int:ty),+; floats: $($float:ty),+ $(;)?) => {
+        $(
+            unsafe impl Pod for $int {
+                #[inline(always)]
+                fn swap_bytes(self) -> Self {
+                    <$int>::swap_bytes(self)
+                }
+            }
+        )+
+
+        $(
+            unsafe impl Pod for $float {
+                #[inline(always)]
+                fn swap_bytes(self) -> Self {
+                    <$float>::from_bits(self.to_bits().swap_bytes())
+                }
+            }
+        )+
+    };
+}
+
+impl_pod!(
+    ints: u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize;
+    floats: f32, f64;
+);
+
+/// ## Info
+/// the byte order used to read or write [`Pod`] values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Endian {
+    /// least significant byte first.
+    Little,
+    /// most significant byte first.
+    Big,
+    /// the byte order of the target, so no conversion is ever needed.
+    #[default]
+    Native,
+}
+
+impl Endian {
+    #[inline(always)]
+    /// ## Info
+    /// returns `true` if values in this byte order can be used as they are on the target.
+    pub fn is_native(self) -> bool {
+        match self {
+            Endian::Native => true,
+            Endian::Little => cfg!(target_endian = "little"),
+            Endian::Big => cfg!(target_endian = "big"),
+        }
+    }
+}
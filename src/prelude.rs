@@ -1,7 +1,15 @@
 pub use crate::fast_array::fast_array::FastArray;
+pub use crate::fast_array::fast_array_n::FastArrayN;
 pub use crate::fast_arr;
 pub use crate::fast_array::fast_array_basics::IntoFastArray;
 pub use crate::fast_iterator::fast_iterator::FastIterator;
 pub use crate::fast_matrix::fast_matrix::FastMatrix;
+pub use crate::fast_matrix::fast_matrix_n::FastMatrixN;
 pub use crate::fast_matrix::fast_matrix_basics::IntoFastMatrix;
-pub use crate::fast_matrix;
\ No newline at end of file
+pub use crate::fast_matrix;
+pub use crate::fast_matrix::fast_matrix_view::{MatrixView, MatrixViewMut};
+pub use crate::fast_tensor::fast_tensor::FastTensor;
+pub use crate::broadcast::{Broadcast, BroadcastError};
+pub use crate::sparse_matrix::sparse_matrix::{SparseFormat, SparseMatrix};
+pub use crate::pod::{Endian, Pod};
+pub use crate::error::FastError;
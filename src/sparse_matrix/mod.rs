@@ -0,0 +1,2 @@
+pub mod sparse_matrix;
+pub mod sparse_matrix_maths;
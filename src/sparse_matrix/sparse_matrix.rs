@@ -0,0 +1,570 @@
+use std::{
+    ops::{AddAssign, Sub},
+    ptr,
+};
+
+use crate::{alloc, FastArray, FastMatrix};
+
+/// ## Info
+/// the way the non-zero entries of a [`SparseMatrix`] are stored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SparseFormat {
+    /// compressed sparse rows: the entries are grouped by row, and each row is found through a pointer array of length `rows + 1`.
+    ///
+    /// fast row access and products with dense matrices.
+    #[default]
+    Csr,
+    /// compressed sparse columns: the entries are grouped by column, and each column is found through a pointer array of length `columns + 1`.
+    ///
+    /// fast column access, and the transpose of a CSR matrix.
+    Csc,
+    /// coordinates: every entry stores its own row and column, sorted row after row.
+    Coo,
+}
+
+/// ## Info
+/// a [`FastArray`] that is allowed to be empty, which a [`FastArray`] can't be.
+#[derive(Debug)]
+pub(crate) struct SparseBuffer<T> {
+    array: Option<FastArray<T>>,
+}
+
+impl<T> SparseBuffer<T> {
+    pub(crate) fn from_vec(vec: Vec<T>) -> SparseBuffer<T> {
+        SparseBuffer {
+            array: (!vec.is_empty()).then(|| FastArray::from(vec)),
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) fn as_slice(&self) -> &[T] {
+        match &self.array {
+            Some(array) => unsafe { std::slice::from_raw_parts(array.pointer, array.size) },
+            None => &[],
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) fn as_mut_slice(&mut self) -> &mut [T] {
+        match &mut self.array {
+            Some(array) => unsafe { std::slice::from_raw_parts_mut(array.pointer, array.size) },
+            None => &mut [],
+        }
+    }
+
+    pub(crate) fn into_vec(self) -> Vec<T> {
+        let Some(mut array) = self.array else {
+            return Vec::new();
+        };
+
+        let vec = (0..array.size)
+            .map(|i| unsafe { ptr::read(array.pointer.add(i)) })
+            .collect();
+
+        // the elements have been moved out, so only the buffer is left to free
+        unsafe { alloc::deallocate(array.pointer, array.size) };
+        array.pointer = ptr::null_mut();
+
+        vec
+    }
+}
+
+impl<T: Clone> Clone for SparseBuffer<T> {
+    fn clone(&self) -> Self {
+        SparseBuffer::from_vec(self.as_slice().to_vec())
+    }
+}
+
+/// ## Info
+/// a matrix that only stores its non-zero entries, for matrices that are mostly zeros.
+///
+/// it comes in the three usual [`SparseFormat`]s, and as everything else in this library it is not resizable once built.
+/// the index and value buffers are [`FastArray`]s.
+///
+/// ## Example
+/// ```
+/// use fast_collections::fast_matrix;
+/// use fast_collections::sparse_matrix::sparse_matrix::{SparseFormat, SparseMatrix};
+///
+/// // [
+/// //     [1, 0, 0],
+/// //     [0, 0, 2],
+/// // ]
+/// let sparse = SparseMatrix::from_triplets(2, 3, SparseFormat::Csr, [(0, 0, 1), (1, 2, 2)]);
+///
+/// assert_eq!(sparse.nnz(), 2);
+/// assert_eq!(sparse.get((1, 2)), Some(&2));
+/// assert_eq!(sparse.get((1, 1)), None);
+/// assert_eq!(sparse.to_dense(), fast_matrix!([1, 0, 0], [0, 0, 2]));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SparseMatrix<T> {
+    pub rows: usize,
+    pub columns: usize,
+    pub(crate) format: SparseFormat,
+    /// CSR: row pointers, CSC: column pointers, COO: the row of every entry.
+    pub(crate) outer: SparseBuffer<usize>,
+    /// CSR: the column of every entry, CSC: the row of every entry, COO: the column of every entry.
+    pub(crate) inner: SparseBuffer<usize>,
+    pub(crate) values: SparseBuffer<T>,
+}
+
+impl<T> SparseMatrix<T> {
+    /// builds the matrix from entries that are already sorted in the storage order of `format`, without duplicates.
+    pub(crate) fn from_sorted_entries(
+        rows: usize,
+        columns: usize,
+        format: SparseFormat,
+        entries: Vec<(usize, usize, T)>,
+    ) -> SparseMatrix<T> {
+        let mut outer = Vec::new();
+        let mut inner = Vec::with_capacity(entries.len());
+        let mut values = Vec::with_capacity(entries.len());
+
+        match format {
+            SparseFormat::Coo => {
+                outer.reserve(entries.len());
+                for (row, column, value) in entries {
+                    outer.push(row);
+                    inner.push(column);
+                    values.push(value);
+                }
+            }
+            SparseFormat::Csr | SparseFormat::Csc => {
+                let (major_len, transposed) = match format {
+                    SparseFormat::Csr => (rows, false),
+                    _ => (columns, true),
+                };
+
+                outer.resize(major_len + 1, 0);
+                for (row, column, value) in entries {
+                    let (major, minor) = if transposed { (column, row) } else { (row, column) };
+                    outer[major + 1] += 1;
+                    inner.push(minor);
+                    values.push(value);
+                }
+
+                for i in 0..major_len {
+                    outer[i + 1] += outer[i];
+                }
+            }
+        }
+
+        SparseMatrix {
+            rows,
+            columns,
+            format,
+            outer: SparseBuffer::from_vec(outer),
+            inner: SparseBuffer::from_vec(inner),
+            values: SparseBuffer::from_vec(values),
+        }
+    }
+
+    /// the key the entries are sorted by in `format`.
+    #[inline(always)]
+    pub(crate) fn sort_key(format: SparseFormat, row: usize, column: usize) -> (usize, usize) {
+        match format {
+            SparseFormat::Csc => (column, row),
+            SparseFormat::Csr | SparseFormat::Coo => (row, column),
+        }
+    }
+
+    /// ## Info
+    /// returns the `(rows, columns)` of the matrix.
+    #[inline(always)]
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.columns)
+    }
+
+    /// ## Info
+    /// returns the [`SparseFormat`] the matrix is stored in.
+    #[inline(always)]
+    pub fn format(&self) -> SparseFormat {
+        self.format
+    }
+
+    /// ## Info
+    /// returns the number of stored (non-zero) entries.
+    #[inline(always)]
+    pub fn nnz(&self) -> usize {
+        self.values.as_slice().len()
+    }
+
+    /// ## Info
+    /// returns the stored values, in the storage order of the matrix.
+    #[inline(always)]
+    pub fn values(&self) -> &[T] {
+        self.values.as_slice()
+    }
+
+    /// ## Info
+    /// returns the stored values mutably, in the storage order of the matrix.
+    ///
+    /// the sparsity pattern can't be changed, only the values.
+    #[inline(always)]
+    pub fn values_mut(&mut self) -> &mut [T] {
+        self.values.as_mut_slice()
+    }
+
+    /// ## Info
+    /// returns the outer index buffer:
+    /// the row pointers for CSR, the column pointers for CSC, and the row of every entry for COO.
+    #[inline(always)]
+    pub fn outer_indices(&self) -> &[usize] {
+        self.outer.as_slice()
+    }
+
+    /// ## Info
+    /// returns the inner index buffer:
+    /// the column of every entry for CSR and COO, and the row of every entry for CSC.
+    #[inline(always)]
+    pub fn inner_indices(&self) -> &[usize] {
+        self.inner.as_slice()
+    }
+
+    /// ## Info
+    /// returns the position of the entry at `index` in the value buffer, if it is stored.
+    fn position(&self, index: (usize, usize)) -> Option<usize> {
+        let (outer, inner) = (self.outer.as_slice(), self.inner.as_slice());
+
+        match self.format {
+            SparseFormat::Csr | SparseFormat::Csc => {
+                let (major, minor) = Self::sort_key(self.format, index.0, index.1);
+                let (start, end) = (outer[major], outer[major + 1]);
+                inner[start..end].binary_search(&minor).ok().map(|i| start + i)
+            }
+            SparseFormat::Coo => {
+                // the entries are sorted row after row, so the row is a contiguous run
+                let start = outer.partition_point(|&row| row < index.0);
+                let end = outer.partition_point(|&row| row <= index.0);
+                inner[start..end].binary_search(&index.1).ok().map(|i| start + i)
+            }
+        }
+    }
+
+    /// ## Info
+    /// returns a reference to the element at `index`, or `None` if it isn't stored (aka it is zero).
+    ///
+    /// ## Panics
+    /// if `index` is out of bounds.
+    pub fn get(&self, index: (usize, usize)) -> Option<&T> {
+        assert!(
+            index.0 < self.rows && index.1 < self.columns,
+            "SparseMatrix: tried to index out of bounds."
+        );
+
+        self.position(index).map(|i| &self.values.as_slice()[i])
+    }
+
+    /// ## Info
+    /// returns a mutable reference to the element at `index`, or `None` if it isn't stored (aka it is zero).
+    ///
+    /// ## Panics
+    /// if `index` is out of bounds.
+    pub fn get_mut(&mut self, index: (usize, usize)) -> Option<&mut T> {
+        assert!(
+            index.0 < self.rows && index.1 < self.columns,
+            "SparseMatrix: tried to index out of bounds."
+        );
+
+        self.position(index).map(|i| &mut self.values.as_mut_slice()[i])
+    }
+
+    /// ## Info
+    /// creates an iterator over the stored entries as `(row, column, &value)`, in the storage order of the matrix.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::sparse_matrix::sparse_matrix::{SparseFormat, SparseMatrix};
+    ///
+    /// let sparse = SparseMatrix::from_triplets(2, 2, SparseFormat::Csc, [(0, 1, 1), (1, 0, 2)]);
+    ///
+    /// assert_eq!(sparse.iter().collect::<Vec<_>>(), vec![(1, 0, &2), (0, 1, &1)]);
+    /// ```
+    pub fn iter(&self) -> SparseEntries<'_, T> {
+        SparseEntries {
+            format: self.format,
+            outer: self.outer.as_slice(),
+            inner: self.inner.as_slice(),
+            values: self.values.as_slice(),
+            position: 0,
+            major: 0,
+        }
+    }
+
+    /// ## Info
+    /// returns the stored entries of `row`.
+    ///
+    /// ## Panics
+    /// if `row` is out of bounds, or if the matrix is stored as CSC; use [`SparseMatrix::to_format`] first.
+    pub fn row(&self, row: usize) -> SparseRow<'_, T> {
+        assert!(row < self.rows, "SparseMatrix: tried to index out of bounds.");
+
+        let outer = self.outer.as_slice();
+        let (start, end) = match self.format {
+            SparseFormat::Csr => (outer[row], outer[row + 1]),
+            SparseFormat::Coo => (
+                outer.partition_point(|&r| r < row),
+                outer.partition_point(|&r| r <= row),
+            ),
+            SparseFormat::Csc => panic!("SparseMatrix: row access needs a CSR or COO matrix."),
+        };
+
+        SparseRow {
+            row,
+            columns: &self.inner.as_slice()[start..end],
+            values: &self.values.as_slice()[start..end],
+        }
+    }
+
+    /// ## Info
+    /// creates an iterator over every row of the matrix, including the empty ones.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::sparse_matrix::sparse_matrix::{SparseFormat, SparseMatrix};
+    ///
+    /// let sparse = SparseMatrix::from_triplets(3, 3, SparseFormat::Csr, [(0, 2, 1), (2, 0, 2), (2, 1, 3)]);
+    ///
+    /// let lens = sparse.rows().map(|row| row.len()).collect::<Vec<_>>();
+    /// assert_eq!(lens, vec![1, 0, 2]);
+    ///
+    /// let last = sparse.row(2).iter().collect::<Vec<_>>();
+    /// assert_eq!(last, vec![(0, &2), (1, &3)]);
+    /// ```
+    ///
+    /// ## Panics
+    /// if the matrix is stored as CSC; use [`SparseMatrix::to_format`] first.
+    pub fn rows(&self) -> impl ExactSizeIterator<Item = SparseRow<'_, T>> + DoubleEndedIterator {
+        assert!(
+            self.format != SparseFormat::Csc,
+            "SparseMatrix: row access needs a CSR or COO matrix."
+        );
+
+        (0..self.rows).map(|row| self.row(row))
+    }
+
+    /// ## Info
+    /// consumes the matrix and returns its entries as `(row, column, value)`, in the storage order of the matrix.
+    pub fn into_triplets(self) -> Vec<(usize, usize, T)> {
+        let indices = self.iter().map(|(row, column, _)| (row, column)).collect::<Vec<_>>();
+
+        indices
+            .into_iter()
+            .zip(self.values.into_vec())
+            .map(|((row, column), value)| (row, column, value))
+            .collect()
+    }
+}
+
+impl<T: AddAssign> SparseMatrix<T> {
+    /// ## Info
+    /// builds a `rows x columns` [`SparseMatrix`] from `(row, column, value)` triplets, in any order.
+    ///
+    /// duplicated entries are summed together.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::fast_matrix;
+    /// use fast_collections::sparse_matrix::sparse_matrix::{SparseFormat, SparseMatrix};
+    ///
+    /// let sparse = SparseMatrix::from_triplets(2, 2, SparseFormat::Coo, [(1, 1, 3), (0, 1, 1), (1, 1, 4)]);
+    ///
+    /// assert_eq!(sparse.nnz(), 2);
+    /// assert_eq!(sparse.to_dense(), fast_matrix!([0, 1], [0, 7]));
+    /// ```
+    ///
+    /// ## Panics
+    /// if `rows == 0`, `columns == 0`, or if a triplet is out of bounds.
+    pub fn from_triplets<I>(rows: usize, columns: usize, format: SparseFormat, triplets: I) -> SparseMatrix<T>
+    where
+        I: IntoIterator<Item = (usize, usize, T)>,
+    {
+        assert_ne!(rows, 0, "SparseMatrix: rows cannot be 0!");
+        assert_ne!(columns, 0, "SparseMatrix: columns cannot be 0!");
+
+        let mut triplets = triplets.into_iter().collect::<Vec<_>>();
+        for &(row, column, _) in triplets.iter() {
+            assert!(
+                row < rows && column < columns,
+                "SparseMatrix: the triplet ({row}, {column}) is out of bounds for a {rows}x{columns} matrix."
+            );
+        }
+
+        triplets.sort_by_key(|&(row, column, _)| Self::sort_key(format, row, column));
+
+        let mut entries: Vec<(usize, usize, T)> = Vec::with_capacity(triplets.len());
+        for (row, column, value) in triplets {
+            match entries.last_mut() {
+                Some(last) if last.0 == row && last.1 == column => last.2 += value,
+                _ => entries.push((row, column, value)),
+            }
+        }
+
+        SparseMatrix::from_sorted_entries(rows, columns, format, entries)
+    }
+}
+
+impl<T: Clone + Default> SparseMatrix<T> {
+    /// ## Info
+    /// creates a dense, row-major [`FastMatrix`] with the same elements; the missing entries are [`Default`].
+    pub fn to_dense(&self) -> FastMatrix<T> {
+        let mut dense = FastMatrix::new_default(self.rows, self.columns);
+
+        for (row, column, value) in self.iter() {
+            dense[(row, column)] = value.clone();
+        }
+
+        dense
+    }
+}
+
+impl<T: Clone + Default + PartialOrd + Sub<Output = T>> SparseMatrix<T> {
+    /// ## Info
+    /// creates a [`SparseMatrix`] with the elements of `dense` whose magnitude is greater than `tolerance`.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::fast_matrix;
+    /// use fast_collections::sparse_matrix::sparse_matrix::{SparseFormat, SparseMatrix};
+    ///
+    /// let dense = fast_matrix!([1.0, 1e-9], [0.0, -2.0]);
+    /// let sparse = SparseMatrix::from_dense(&dense, 1e-6, SparseFormat::Csr);
+    ///
+    /// assert_eq!(sparse.nnz(), 2);
+    /// assert_eq!(sparse.get((1, 1)), Some(&-2.0));
+    /// ```
+    pub fn from_dense(dense: &FastMatrix<T>, tolerance: T, format: SparseFormat) -> SparseMatrix<T> {
+        let zero = T::default();
+        let (majors, minors) = match format {
+            SparseFormat::Csc => (dense.columns, dense.rows),
+            SparseFormat::Csr | SparseFormat::Coo => (dense.rows, dense.columns),
+        };
+
+        let mut entries = Vec::new();
+        for major in 0..majors {
+            for minor in 0..minors {
+                let (row, column) = Self::sort_key(format, major, minor);
+                let value = &dense[(row, column)];
+
+                let magnitude = if *value < zero {
+                    zero.clone() - value.clone()
+                } else {
+                    value.clone()
+                };
+
+                if magnitude > tolerance {
+                    entries.push((row, column, value.clone()));
+                }
+            }
+        }
+
+        SparseMatrix::from_sorted_entries(dense.rows, dense.columns, format, entries)
+    }
+}
+
+impl<T: PartialEq> PartialEq for SparseMatrix<T> {
+    /// two sparse matrices are equal when they store the same entries, whatever their formats are.
+    fn eq(&self, other: &Self) -> bool {
+        self.shape() == other.shape()
+            && self.nnz() == other.nnz()
+            && self.iter().all(|(row, column, value)| other.get((row, column)) == Some(value))
+    }
+}
+
+/// ## Info
+/// an iterator over the stored entries of a [`SparseMatrix`], created by [`SparseMatrix::iter`].
+#[derive(Debug, Clone)]
+pub struct SparseEntries<'a, T> {
+    format: SparseFormat,
+    outer: &'a [usize],
+    inner: &'a [usize],
+    values: &'a [T],
+    position: usize,
+    major: usize,
+}
+
+impl<'a, T> Iterator for SparseEntries<'a, T> {
+    type Item = (usize, usize, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = self.values.get(self.position)?;
+        let position = self.position;
+        self.position += 1;
+
+        let entry = match self.format {
+            SparseFormat::Coo => (self.outer[position], self.inner[position], value),
+            SparseFormat::Csr | SparseFormat::Csc => {
+                while self.outer[self.major + 1] <= position {
+                    self.major += 1;
+                }
+
+                match self.format {
+                    SparseFormat::Csr => (self.major, self.inner[position], value),
+                    _ => (self.inner[position], self.major, value),
+                }
+            }
+        };
+
+        Some(entry)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.values.len() - self.position;
+        (len, Some(len))
+    }
+}
+
+impl<T> ExactSizeIterator for SparseEntries<'_, T> {}
+
+/// ## Info
+/// the stored entries of one row of a [`SparseMatrix`], created by [`SparseMatrix::row`] and [`SparseMatrix::rows`].
+#[derive(Debug, Clone, Copy)]
+pub struct SparseRow<'a, T> {
+    row: usize,
+    columns: &'a [usize],
+    values: &'a [T],
+}
+
+impl<'a, T> SparseRow<'a, T> {
+    /// ## Info
+    /// returns the index of the row.
+    #[inline(always)]
+    pub fn row(&self) -> usize {
+        self.row
+    }
+
+    /// ## Info
+    /// returns the columns of the stored entries, in increasing order.
+    #[inline(always)]
+    pub fn columns(&self) -> &'a [usize] {
+        self.columns
+    }
+
+    /// ## Info
+    /// returns the values of the stored entries.
+    #[inline(always)]
+    pub fn values(&self) -> &'a [T] {
+        self.values
+    }
+
+    /// ## Info
+    /// returns the number of stored entries in the row.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// ## Info
+    /// returns `true` if the row has no stored entries.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// ## Info
+    /// creates an iterator over the `(column, &value)` of the stored entries.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (usize, &'a T)> + DoubleEndedIterator {
+        self.columns.iter().copied().zip(self.values.iter())
+    }
+}
@@ -0,0 +1,207 @@
+use std::ops::{AddAssign, Mul};
+
+use super::sparse_matrix::{SparseFormat, SparseMatrix};
+use crate::{FastArray, FastMatrix};
+
+impl<T> SparseMatrix<T> {
+    /// ## Info
+    /// converts the matrix to `format`, moving the values instead of cloning them.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::sparse_matrix::sparse_matrix::{SparseFormat, SparseMatrix};
+    ///
+    /// let csr = SparseMatrix::from_triplets(2, 2, SparseFormat::Csr, [(0, 1, 1), (1, 0, 2)]);
+    /// let csc = csr.clone().into_format(SparseFormat::Csc);
+    ///
+    /// assert_eq!(csc.format(), SparseFormat::Csc);
+    /// assert_eq!(csc.values(), &[2, 1]);
+    /// assert_eq!(csc, csr);
+    /// ```
+    pub fn into_format(self, format: SparseFormat) -> SparseMatrix<T> {
+        if self.format == format {
+            return self;
+        }
+
+        let (rows, columns) = self.shape();
+        let mut entries = self.into_triplets();
+        entries.sort_by_key(|&(row, column, _)| Self::sort_key(format, row, column));
+
+        SparseMatrix::from_sorted_entries(rows, columns, format, entries)
+    }
+
+    /// ## Info
+    /// consumes the matrix and returns its transpose.
+    ///
+    /// a CSR matrix becomes a CSC one and the other way around, which reuses the buffers as they are.
+    /// a COO matrix stays COO, and has its entries sorted again.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::fast_matrix;
+    /// use fast_collections::sparse_matrix::sparse_matrix::{SparseFormat, SparseMatrix};
+    ///
+    /// let sparse = SparseMatrix::from_triplets(2, 3, SparseFormat::Csr, [(0, 2, 1), (1, 0, 2)]);
+    /// let transposed = sparse.into_transpose();
+    ///
+    /// assert_eq!(transposed.format(), SparseFormat::Csc);
+    /// assert_eq!(transposed.to_dense(), fast_matrix!([0, 2], [0, 0], [1, 0]));
+    /// ```
+    pub fn into_transpose(self) -> SparseMatrix<T> {
+        match self.format {
+            SparseFormat::Csr | SparseFormat::Csc => SparseMatrix {
+                rows: self.columns,
+                columns: self.rows,
+                format: match self.format {
+                    SparseFormat::Csr => SparseFormat::Csc,
+                    _ => SparseFormat::Csr,
+                },
+                outer: self.outer,
+                inner: self.inner,
+                values: self.values,
+            },
+            SparseFormat::Coo => {
+                let (rows, columns) = self.shape();
+                let mut entries = self
+                    .into_triplets()
+                    .into_iter()
+                    .map(|(row, column, value)| (column, row, value))
+                    .collect::<Vec<_>>();
+                entries.sort_by_key(|&(row, column, _)| (row, column));
+
+                SparseMatrix::from_sorted_entries(columns, rows, SparseFormat::Coo, entries)
+            }
+        }
+    }
+}
+
+impl<T: Clone> SparseMatrix<T> {
+    /// ## Info
+    /// returns a copy of the matrix stored in `format`.
+    pub fn to_format(&self, format: SparseFormat) -> SparseMatrix<T> {
+        let mut entries = self
+            .iter()
+            .map(|(row, column, value)| (row, column, value.clone()))
+            .collect::<Vec<_>>();
+        entries.sort_by_key(|&(row, column, _)| Self::sort_key(format, row, column));
+
+        SparseMatrix::from_sorted_entries(self.rows, self.columns, format, entries)
+    }
+
+    /// ## Info
+    /// returns the transpose of the matrix, stored in the same format.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::fast_matrix;
+    /// use fast_collections::sparse_matrix::sparse_matrix::{SparseFormat, SparseMatrix};
+    ///
+    /// let sparse = SparseMatrix::from_triplets(2, 3, SparseFormat::Csr, [(0, 2, 1), (1, 0, 2)]);
+    /// let transposed = sparse.transpose();
+    ///
+    /// assert_eq!(transposed.format(), SparseFormat::Csr);
+    /// assert_eq!(transposed.to_dense(), fast_matrix!([0, 2], [0, 0], [1, 0]));
+    /// ```
+    pub fn transpose(&self) -> SparseMatrix<T> {
+        let format = self.format;
+        let mut entries = self
+            .iter()
+            .map(|(row, column, value)| (column, row, value.clone()))
+            .collect::<Vec<_>>();
+        entries.sort_by_key(|&(row, column, _)| Self::sort_key(format, row, column));
+
+        SparseMatrix::from_sorted_entries(self.columns, self.rows, format, entries)
+    }
+}
+
+impl<T: Clone + Default + AddAssign + Mul<Output = T>> SparseMatrix<T> {
+    /// ## Info
+    /// returns the product of self with the dense matrix `rhs`, as a dense row-major [`FastMatrix`].
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::fast_matrix;
+    /// use fast_collections::sparse_matrix::sparse_matrix::{SparseFormat, SparseMatrix};
+    ///
+    /// let sparse = SparseMatrix::from_triplets(2, 2, SparseFormat::Csr, [(0, 1, 2), (1, 0, 3)]);
+    /// let dense = fast_matrix!([1, 2], [3, 4]);
+    ///
+    /// assert_eq!(sparse.mul_dense(&dense), fast_matrix!([6, 8], [3, 6]));
+    /// assert_eq!(&sparse * &dense, fast_matrix!([6, 8], [3, 6]));
+    /// ```
+    ///
+    /// ## Panics
+    /// if `self.columns != rhs.rows`.
+    pub fn mul_dense(&self, rhs: &FastMatrix<T>) -> FastMatrix<T> {
+        assert_eq!(
+            self.columns, rhs.rows,
+            "SparseMatrix: can't multiply a {}x{} matrix with a {}x{} one!",
+            self.rows, self.columns, rhs.rows, rhs.columns
+        );
+
+        let mut result = FastMatrix::new_default(self.rows, rhs.columns);
+
+        for (row, inner, value) in self.iter() {
+            for column in 0..rhs.columns {
+                result[(row, column)] += value.clone() * rhs[(inner, column)].clone();
+            }
+        }
+
+        result
+    }
+
+    /// ## Info
+    /// returns the product of self with the vector `rhs`.
+    ///
+    /// ## Example
+    /// ```
+    /// use fast_collections::fast_arr;
+    /// use fast_collections::sparse_matrix::sparse_matrix::{SparseFormat, SparseMatrix};
+    ///
+    /// let sparse = SparseMatrix::from_triplets(2, 3, SparseFormat::Csc, [(0, 0, 1), (0, 2, 2), (1, 1, 3)]);
+    ///
+    /// assert_eq!(sparse.mul_array(&fast_arr!(1, 2, 3)), fast_arr!(7, 6));
+    /// assert_eq!(&sparse * &fast_arr!(1, 2, 3), fast_arr!(7, 6));
+    /// ```
+    ///
+    /// ## Panics
+    /// if `self.columns != rhs.len()`.
+    pub fn mul_array(&self, rhs: &FastArray<T>) -> FastArray<T> {
+        assert_eq!(
+            self.columns,
+            rhs.len(),
+            "SparseMatrix: can't multiply a {}x{} matrix with a vector of len {}!",
+            self.rows,
+            self.columns,
+            rhs.len()
+        );
+
+        let mut result = FastArray::new_default(self.rows);
+
+        for (row, column, value) in self.iter() {
+            result[row] += value.clone() * rhs[column].clone();
+        }
+
+        result
+    }
+}
+
+impl<T: Clone + Default + AddAssign + Mul<Output = T>> Mul<&FastMatrix<T>> for &SparseMatrix<T> {
+    type Output = FastMatrix<T>;
+
+    /// ## Panics
+    /// if `self.columns != rhs.rows`.
+    fn mul(self, rhs: &FastMatrix<T>) -> Self::Output {
+        self.mul_dense(rhs)
+    }
+}
+
+impl<T: Clone + Default + AddAssign + Mul<Output = T>> Mul<&FastArray<T>> for &SparseMatrix<T> {
+    type Output = FastArray<T>;
+
+    /// ## Panics
+    /// if `self.columns != rhs.len()`.
+    fn mul(self, rhs: &FastArray<T>) -> Self::Output {
+        self.mul_array(rhs)
+    }
+}
@@ -0,0 +1,124 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use fast_collections::{
+    error::AllocError, fast_arr, fast_matrix, prelude::IntoFastMatrix, FastArray, FastError, FastMatrix, FastTensor,
+};
+
+#[test]
+fn fast_array_overflowing_len() {
+    assert_eq!(FastArray::<u64>::try_with_len(usize::MAX).unwrap_err(), AllocError::CapacityOverflow);
+    // fits in a usize, but not in an isize
+    assert_eq!(FastArray::<u8>::try_with_len(usize::MAX / 2 + 1).unwrap_err(), AllocError::CapacityOverflow);
+    assert!(matches!(FastArray::try_new(usize::MAX, 0u64), Err(FastError::Alloc)));
+}
+
+#[test]
+fn fast_array_huge_len() {
+    // small enough to be a valid layout, but no allocator can give it back
+    let error = FastArray::<u8>::try_with_len(isize::MAX as usize - 64).unwrap_err();
+    assert!(matches!(error, AllocError::AllocFailed { layout } if layout.size() == isize::MAX as usize - 64));
+    assert!(matches!(FastError::from(error), FastError::Alloc));
+}
+
+#[test]
+fn fast_array_zero_len() {
+    assert_eq!(FastArray::<u8>::try_with_len(0).unwrap_err(), AllocError::ZeroLength);
+    assert!(matches!(FastError::from(AllocError::ZeroLength), FastError::ZeroLength));
+}
+
+#[test]
+#[should_panic(expected = "capacity overflow")]
+fn fast_array_new_overflow_panics() {
+    FastArray::new(usize::MAX, 0u64);
+}
+
+#[test]
+fn fast_matrix_overflowing_shape() {
+    assert_eq!(FastMatrix::<u8>::try_with_len(usize::MAX, 2).unwrap_err(), AllocError::CapacityOverflow);
+    // rows * columns fits, but not once multiplied by the size of the elements
+    assert_eq!(FastMatrix::<u64>::try_with_len(1 << 32, 1 << 30).unwrap_err(), AllocError::CapacityOverflow);
+    assert_eq!(FastMatrix::<u8>::try_with_len(3, 0).unwrap_err(), AllocError::ZeroLength);
+
+    assert!(matches!(FastMatrix::try_new(1 << 40, 1 << 40, 0u8), Err(FastError::Alloc)));
+    assert!(matches!(FastMatrix::<u8>::try_new_default(usize::MAX, usize::MAX), Err(FastError::Alloc)));
+}
+
+#[test]
+fn fast_matrix_try_with_len() {
+    assert_eq!(FastMatrix::<i32>::try_with_len(2, 3).unwrap(), fast_matrix!([0, 0, 0], [0, 0, 0]));
+}
+
+#[test]
+#[should_panic(expected = "capacity overflow")]
+fn fast_matrix_new_overflow_panics() {
+    FastMatrix::new(usize::MAX, 2, 0u8);
+}
+
+#[test]
+#[should_panic(expected = "capacity overflow")]
+fn fast_matrix_new_default_overflow_panics() {
+    FastMatrix::<u8>::new_default(1 << 33, 1 << 33);
+}
+
+#[test]
+#[should_panic(expected = "FastIterator::into_fast_matrix: a 9223372036854775809x2 matrix can't hold the 2 elements")]
+fn fast_iterator_into_fast_matrix_overflowing_shape() {
+    // (2^63 + 1) * 2 wraps around to 2, the length of the iterator
+    FastArray::new(2, 7u64).into_fast_iterator().into_fast_matrix((1 << 63) + 1, 2);
+}
+
+#[test]
+#[should_panic(expected = "a 9223372036854775809x2 matrix can't hold 1 rows of 2 elements")]
+fn into_fast_matrix_overflowing_shape() {
+    IntoFastMatrix::into_fast_matrix([fast_arr!(7u64, 7)].into_iter(), (1 << 63) + 1, 2);
+}
+
+#[test]
+fn try_into_fast_matrix_overflowing_shape() {
+    let error = FastArray::new(2, 7u64).into_fast_iterator().try_into_fast_matrix((1 << 63) + 1, 2).unwrap_err();
+    assert!(matches!(error, FastError::ShapeMismatch { expected: (9223372036854775809, 2), found: (1, 2) }));
+
+    let error = [fast_arr!(7u64, 7)].into_iter().try_into_fast_matrix((1 << 63) + 1, 2).unwrap_err();
+    assert!(matches!(error, FastError::ShapeMismatch { expected: (9223372036854775809, 2), found: (1, 2) }));
+}
+
+#[test]
+#[should_panic(expected = "capacity overflow")]
+fn fast_tensor_overflowing_shape_panics() {
+    FastTensor::new(&[1 << 20, 1 << 20, 1 << 30], 0u8);
+}
+
+static DROPPED: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Clone, Default)]
+struct Unit;
+
+impl Drop for Unit {
+    fn drop(&mut self) {
+        DROPPED.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn zero_sized_elements() {
+    let fast_arr = FastArray::new(4, ());
+    assert_eq!(fast_arr.len(), 4);
+
+    let units = FastArray::<Unit>::try_with_len(3).unwrap();
+    drop(units);
+    assert_eq!(DROPPED.load(Ordering::SeqCst), 3);
+}
+
+#[derive(Clone, Copy, Default, Debug, PartialEq)]
+#[repr(align(64))]
+struct Aligned(u8);
+
+#[test]
+fn over_aligned_elements() {
+    let fast_arr = FastArray::new(3, Aligned(7));
+    assert_eq!(fast_arr[2], Aligned(7));
+    assert!((&fast_arr[0] as *const Aligned).is_aligned());
+
+    let fast_matrix = FastMatrix::<Aligned>::try_with_len(2, 2).unwrap();
+    assert_eq!(fast_matrix[(1, 1)], Aligned(0));
+}
@@ -0,0 +1,780 @@
+//! two tiny serde formats, used to test the `serde` implementations without depending on a real format crate:
+//! - [`json`]: a self-describing text format, which doesn't know the len of sequences upfront.
+//! - [`binary`]: a bincode-like format, with little endian numbers and length-prefixed sequences.
+
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq)]
+pub struct Error(pub String);
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl serde::ser::Error for Error {
+    fn custom<T: Display>(msg: T) -> Self {
+        Error(msg.to_string())
+    }
+}
+
+impl serde::de::Error for Error {
+    fn custom<T: Display>(msg: T) -> Self {
+        Error(msg.to_string())
+    }
+}
+
+/// implements the serializer methods of the types a format doesn't support.
+macro_rules! unsupported {
+    ($($method:ident($($argument:ty),*) -> $output:ty;)*) => {
+        $(
+            fn $method(self, $(_: $argument),*) -> Result<$output, Error> {
+                Err(Error(format!("{} isn't supported", stringify!($method))))
+            }
+        )*
+    };
+}
+
+pub mod json {
+    use serde::{
+        de::{self, DeserializeSeed, MapAccess, SeqAccess, Visitor},
+        ser::{self, Impossible, Serialize},
+        Deserialize,
+    };
+
+    use super::Error;
+
+    pub fn to_string<T: Serialize + ?Sized>(value: &T) -> Result<String, Error> {
+        let mut serializer = Serializer { output: String::new() };
+        value.serialize(&mut serializer)?;
+        Ok(serializer.output)
+    }
+
+    pub fn from_str<'de, T: Deserialize<'de>>(input: &'de str) -> Result<T, Error> {
+        let mut deserializer = Deserializer { input };
+        let value = T::deserialize(&mut deserializer)?;
+
+        match deserializer.input.trim_start().is_empty() {
+            true => Ok(value),
+            false => Err(Error(format!("trailing characters: {}", deserializer.input))),
+        }
+    }
+
+    pub struct Serializer {
+        output: String,
+    }
+
+    impl<'a> ser::Serializer for &'a mut Serializer {
+        type Ok = ();
+        type Error = Error;
+        type SerializeSeq = Compound<'a>;
+        type SerializeTuple = Compound<'a>;
+        type SerializeTupleStruct = Compound<'a>;
+        type SerializeTupleVariant = Impossible<(), Error>;
+        type SerializeMap = Impossible<(), Error>;
+        type SerializeStruct = Compound<'a>;
+        type SerializeStructVariant = Impossible<(), Error>;
+
+        fn serialize_bool(self, v: bool) -> Result<(), Error> {
+            self.output.push_str(if v { "true" } else { "false" });
+            Ok(())
+        }
+
+        fn serialize_i8(self, v: i8) -> Result<(), Error> {
+            self.serialize_i64(v as i64)
+        }
+
+        fn serialize_i16(self, v: i16) -> Result<(), Error> {
+            self.serialize_i64(v as i64)
+        }
+
+        fn serialize_i32(self, v: i32) -> Result<(), Error> {
+            self.serialize_i64(v as i64)
+        }
+
+        fn serialize_i64(self, v: i64) -> Result<(), Error> {
+            self.output.push_str(&v.to_string());
+            Ok(())
+        }
+
+        fn serialize_u8(self, v: u8) -> Result<(), Error> {
+            self.serialize_u64(v as u64)
+        }
+
+        fn serialize_u16(self, v: u16) -> Result<(), Error> {
+            self.serialize_u64(v as u64)
+        }
+
+        fn serialize_u32(self, v: u32) -> Result<(), Error> {
+            self.serialize_u64(v as u64)
+        }
+
+        fn serialize_u64(self, v: u64) -> Result<(), Error> {
+            self.output.push_str(&v.to_string());
+            Ok(())
+        }
+
+        fn serialize_f32(self, v: f32) -> Result<(), Error> {
+            self.serialize_f64(v as f64)
+        }
+
+        fn serialize_f64(self, v: f64) -> Result<(), Error> {
+            // always written with a dot, to be read back as a float
+            self.output.push_str(&format!("{v:?}"));
+            Ok(())
+        }
+
+        fn serialize_str(self, v: &str) -> Result<(), Error> {
+            self.output.push('"');
+            for character in v.chars() {
+                if character == '"' || character == '\\' {
+                    self.output.push('\\');
+                }
+                self.output.push(character);
+            }
+            self.output.push('"');
+            Ok(())
+        }
+
+        fn serialize_char(self, v: char) -> Result<(), Error> {
+            self.serialize_str(&v.to_string())
+        }
+
+        fn serialize_bytes(self, v: &[u8]) -> Result<(), Error> {
+            let mut seq = ser::Serializer::serialize_seq(self, Some(v.len()))?;
+            for byte in v {
+                ser::SerializeSeq::serialize_element(&mut seq, byte)?;
+            }
+            ser::SerializeSeq::end(seq)
+        }
+
+        fn serialize_newtype_struct<T: Serialize + ?Sized>(self, _: &'static str, value: &T) -> Result<(), Error> {
+            value.serialize(self)
+        }
+
+        fn serialize_seq(self, _: Option<usize>) -> Result<Compound<'a>, Error> {
+            self.output.push('[');
+            Ok(Compound { serializer: self, first: true, close: ']' })
+        }
+
+        fn serialize_tuple(self, len: usize) -> Result<Compound<'a>, Error> {
+            self.serialize_seq(Some(len))
+        }
+
+        fn serialize_tuple_struct(self, _: &'static str, len: usize) -> Result<Compound<'a>, Error> {
+            self.serialize_seq(Some(len))
+        }
+
+        fn serialize_struct(self, _: &'static str, _: usize) -> Result<Compound<'a>, Error> {
+            self.output.push('{');
+            Ok(Compound { serializer: self, first: true, close: '}' })
+        }
+
+        fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<(), Error> {
+            value.serialize(self)
+        }
+
+        unsupported! {
+            serialize_none() -> ();
+            serialize_unit() -> ();
+            serialize_unit_struct(&'static str) -> ();
+            serialize_unit_variant(&'static str, u32, &'static str) -> ();
+            serialize_tuple_variant(&'static str, u32, &'static str, usize) -> Impossible<(), Error>;
+            serialize_map(Option<usize>) -> Impossible<(), Error>;
+            serialize_struct_variant(&'static str, u32, &'static str, usize) -> Impossible<(), Error>;
+        }
+
+        fn serialize_newtype_variant<T: Serialize + ?Sized>(
+            self,
+            _: &'static str,
+            _: u32,
+            _: &'static str,
+            _: &T,
+        ) -> Result<(), Error> {
+            Err(Error("serialize_newtype_variant isn't supported".to_string()))
+        }
+    }
+
+    pub struct Compound<'a> {
+        serializer: &'a mut Serializer,
+        first: bool,
+        close: char,
+    }
+
+    impl Compound<'_> {
+        fn separator(&mut self) {
+            if !self.first {
+                self.serializer.output.push(',');
+            }
+            self.first = false;
+        }
+
+        fn close(self) -> Result<(), Error> {
+            self.serializer.output.push(self.close);
+            Ok(())
+        }
+    }
+
+    impl ser::SerializeSeq for Compound<'_> {
+        type Ok = ();
+        type Error = Error;
+
+        fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+            self.separator();
+            value.serialize(&mut *self.serializer)
+        }
+
+        fn end(self) -> Result<(), Error> {
+            self.close()
+        }
+    }
+
+    impl ser::SerializeTuple for Compound<'_> {
+        type Ok = ();
+        type Error = Error;
+
+        fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+            ser::SerializeSeq::serialize_element(self, value)
+        }
+
+        fn end(self) -> Result<(), Error> {
+            self.close()
+        }
+    }
+
+    impl ser::SerializeTupleStruct for Compound<'_> {
+        type Ok = ();
+        type Error = Error;
+
+        fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+            ser::SerializeSeq::serialize_element(self, value)
+        }
+
+        fn end(self) -> Result<(), Error> {
+            self.close()
+        }
+    }
+
+    impl ser::SerializeStruct for Compound<'_> {
+        type Ok = ();
+        type Error = Error;
+
+        fn serialize_field<T: Serialize + ?Sized>(&mut self, key: &'static str, value: &T) -> Result<(), Error> {
+            self.separator();
+            ser::Serializer::serialize_str(&mut *self.serializer, key)?;
+            self.serializer.output.push(':');
+            value.serialize(&mut *self.serializer)
+        }
+
+        fn end(self) -> Result<(), Error> {
+            self.close()
+        }
+    }
+
+    pub struct Deserializer<'de> {
+        input: &'de str,
+    }
+
+    impl<'de> Deserializer<'de> {
+        fn peek(&mut self) -> Result<char, Error> {
+            self.input = self.input.trim_start();
+            self.input.chars().next().ok_or_else(|| Error("unexpected end of input".to_string()))
+        }
+
+        fn expect(&mut self, expected: char) -> Result<(), Error> {
+            match self.peek()? == expected {
+                true => {
+                    self.input = &self.input[1..];
+                    Ok(())
+                }
+                false => Err(Error(format!("expected '{expected}' at: {}", self.input))),
+            }
+        }
+
+        fn parse_string(&mut self) -> Result<String, Error> {
+            self.expect('"')?;
+
+            let mut string = String::new();
+            let mut characters = self.input.char_indices();
+            while let Some((index, character)) = characters.next() {
+                match character {
+                    '"' => {
+                        self.input = &self.input[index + 1..];
+                        return Ok(string);
+                    }
+                    '\\' => string.push(characters.next().ok_or_else(|| Error("unterminated string".to_string()))?.1),
+                    _ => string.push(character),
+                }
+            }
+
+            Err(Error("unterminated string".to_string()))
+        }
+    }
+
+    impl<'de> de::Deserializer<'de> for &mut Deserializer<'de> {
+        type Error = Error;
+
+        fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+            match self.peek()? {
+                '[' => {
+                    self.expect('[')?;
+                    let value = visitor.visit_seq(Separated { deserializer: &mut *self, first: true, close: ']' })?;
+                    self.expect(']')?;
+                    Ok(value)
+                }
+                '{' => {
+                    self.expect('{')?;
+                    let value = visitor.visit_map(Separated { deserializer: &mut *self, first: true, close: '}' })?;
+                    self.expect('}')?;
+                    Ok(value)
+                }
+                '"' => visitor.visit_string(self.parse_string()?),
+                _ => {
+                    let end = self
+                        .input
+                        .find(|character: char| character == ',' || character == ']' || character == '}' || character.is_whitespace())
+                        .unwrap_or(self.input.len());
+                    let (token, rest) = self.input.split_at(end);
+                    self.input = rest;
+
+                    match token {
+                        "true" => visitor.visit_bool(true),
+                        "false" => visitor.visit_bool(false),
+                        _ if token.contains(['.', 'e', 'E', 'N', 'i']) => {
+                            visitor.visit_f64(token.parse().map_err(|_| Error(format!("invalid number {token}")))?)
+                        }
+                        _ if token.starts_with('-') => {
+                            visitor.visit_i64(token.parse().map_err(|_| Error(format!("invalid number {token}")))?)
+                        }
+                        _ => visitor.visit_u64(token.parse().map_err(|_| Error(format!("invalid token {token}")))?),
+                    }
+                }
+            }
+        }
+
+        serde::forward_to_deserialize_any! {
+            bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+            bytes byte_buf option unit unit_struct newtype_struct seq tuple
+            tuple_struct map struct enum identifier ignored_any
+        }
+    }
+
+    /// the elements of a sequence or the entries of a map, separated by commas.
+    struct Separated<'a, 'de> {
+        deserializer: &'a mut Deserializer<'de>,
+        first: bool,
+        close: char,
+    }
+
+    impl Separated<'_, '_> {
+        /// returns `false` at the end of the sequence or map.
+        fn has_next(&mut self) -> Result<bool, Error> {
+            if self.deserializer.peek()? == self.close {
+                return Ok(false);
+            }
+            if !self.first {
+                self.deserializer.expect(',')?;
+            }
+            self.first = false;
+            Ok(true)
+        }
+    }
+
+    impl<'de> SeqAccess<'de> for Separated<'_, 'de> {
+        type Error = Error;
+
+        fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>, Error> {
+            match self.has_next()? {
+                true => seed.deserialize(&mut *self.deserializer).map(Some),
+                false => Ok(None),
+            }
+        }
+    }
+
+    impl<'de> MapAccess<'de> for Separated<'_, 'de> {
+        type Error = Error;
+
+        fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>, Error> {
+            match self.has_next()? {
+                true => seed.deserialize(&mut *self.deserializer).map(Some),
+                false => Ok(None),
+            }
+        }
+
+        fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Error> {
+            self.deserializer.expect(':')?;
+            seed.deserialize(&mut *self.deserializer)
+        }
+    }
+}
+
+pub mod binary {
+    use serde::{
+        de::{self, DeserializeSeed, SeqAccess, Visitor},
+        ser::{self, Impossible, Serialize},
+        Deserialize,
+    };
+
+    use super::Error;
+
+    pub fn to_bytes<T: Serialize + ?Sized>(value: &T) -> Result<Vec<u8>, Error> {
+        let mut serializer = Serializer { output: Vec::new() };
+        value.serialize(&mut serializer)?;
+        Ok(serializer.output)
+    }
+
+    pub fn from_bytes<'de, T: Deserialize<'de>>(input: &'de [u8]) -> Result<T, Error> {
+        let mut deserializer = Deserializer { input };
+        let value = T::deserialize(&mut deserializer)?;
+
+        match deserializer.input.is_empty() {
+            true => Ok(value),
+            false => Err(Error(format!("{} trailing bytes", deserializer.input.len()))),
+        }
+    }
+
+    pub struct Serializer {
+        output: Vec<u8>,
+    }
+
+    impl Serializer {
+        fn write_len(&mut self, len: Option<usize>) -> Result<(), Error> {
+            let len = len.ok_or_else(|| Error("the len of sequences must be known".to_string()))?;
+            self.output.extend_from_slice(&(len as u64).to_le_bytes());
+            Ok(())
+        }
+    }
+
+    impl<'a> ser::Serializer for &'a mut Serializer {
+        type Ok = ();
+        type Error = Error;
+        type SerializeSeq = Compound<'a>;
+        type SerializeTuple = Compound<'a>;
+        type SerializeTupleStruct = Compound<'a>;
+        type SerializeTupleVariant = Impossible<(), Error>;
+        type SerializeMap = Impossible<(), Error>;
+        type SerializeStruct = Compound<'a>;
+        type SerializeStructVariant = Impossible<(), Error>;
+
+        fn serialize_bool(self, v: bool) -> Result<(), Error> {
+            self.output.push(v as u8);
+            Ok(())
+        }
+
+        fn serialize_i8(self, v: i8) -> Result<(), Error> {
+            self.output.extend_from_slice(&v.to_le_bytes());
+            Ok(())
+        }
+
+        fn serialize_i16(self, v: i16) -> Result<(), Error> {
+            self.output.extend_from_slice(&v.to_le_bytes());
+            Ok(())
+        }
+
+        fn serialize_i32(self, v: i32) -> Result<(), Error> {
+            self.output.extend_from_slice(&v.to_le_bytes());
+            Ok(())
+        }
+
+        fn serialize_i64(self, v: i64) -> Result<(), Error> {
+            self.output.extend_from_slice(&v.to_le_bytes());
+            Ok(())
+        }
+
+        fn serialize_u8(self, v: u8) -> Result<(), Error> {
+            self.output.push(v);
+            Ok(())
+        }
+
+        fn serialize_u16(self, v: u16) -> Result<(), Error> {
+            self.output.extend_from_slice(&v.to_le_bytes());
+            Ok(())
+        }
+
+        fn serialize_u32(self, v: u32) -> Result<(), Error> {
+            self.output.extend_from_slice(&v.to_le_bytes());
+            Ok(())
+        }
+
+        fn serialize_u64(self, v: u64) -> Result<(), Error> {
+            self.output.extend_from_slice(&v.to_le_bytes());
+            Ok(())
+        }
+
+        fn serialize_f32(self, v: f32) -> Result<(), Error> {
+            self.output.extend_from_slice(&v.to_le_bytes());
+            Ok(())
+        }
+
+        fn serialize_f64(self, v: f64) -> Result<(), Error> {
+            self.output.extend_from_slice(&v.to_le_bytes());
+            Ok(())
+        }
+
+        fn serialize_char(self, v: char) -> Result<(), Error> {
+            self.serialize_u32(v as u32)
+        }
+
+        fn serialize_str(self, v: &str) -> Result<(), Error> {
+            self.serialize_bytes(v.as_bytes())
+        }
+
+        fn serialize_bytes(self, v: &[u8]) -> Result<(), Error> {
+            self.write_len(Some(v.len()))?;
+            self.output.extend_from_slice(v);
+            Ok(())
+        }
+
+        fn serialize_newtype_struct<T: Serialize + ?Sized>(self, _: &'static str, value: &T) -> Result<(), Error> {
+            value.serialize(self)
+        }
+
+        fn serialize_seq(self, len: Option<usize>) -> Result<Compound<'a>, Error> {
+            self.write_len(len)?;
+            Ok(Compound { serializer: self })
+        }
+
+        fn serialize_tuple(self, _: usize) -> Result<Compound<'a>, Error> {
+            Ok(Compound { serializer: self })
+        }
+
+        fn serialize_tuple_struct(self, _: &'static str, _: usize) -> Result<Compound<'a>, Error> {
+            Ok(Compound { serializer: self })
+        }
+
+        fn serialize_struct(self, _: &'static str, _: usize) -> Result<Compound<'a>, Error> {
+            Ok(Compound { serializer: self })
+        }
+
+        unsupported! {
+            serialize_none() -> ();
+            serialize_unit() -> ();
+            serialize_unit_struct(&'static str) -> ();
+            serialize_unit_variant(&'static str, u32, &'static str) -> ();
+            serialize_tuple_variant(&'static str, u32, &'static str, usize) -> Impossible<(), Error>;
+            serialize_map(Option<usize>) -> Impossible<(), Error>;
+            serialize_struct_variant(&'static str, u32, &'static str, usize) -> Impossible<(), Error>;
+        }
+
+        fn serialize_some<T: Serialize + ?Sized>(self, _: &T) -> Result<(), Error> {
+            Err(Error("serialize_some isn't supported".to_string()))
+        }
+
+        fn serialize_newtype_variant<T: Serialize + ?Sized>(
+            self,
+            _: &'static str,
+            _: u32,
+            _: &'static str,
+            _: &T,
+        ) -> Result<(), Error> {
+            Err(Error("serialize_newtype_variant isn't supported".to_string()))
+        }
+
+        fn is_human_readable(&self) -> bool {
+            false
+        }
+    }
+
+    pub struct Compound<'a> {
+        serializer: &'a mut Serializer,
+    }
+
+    impl ser::SerializeSeq for Compound<'_> {
+        type Ok = ();
+        type Error = Error;
+
+        fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+            value.serialize(&mut *self.serializer)
+        }
+
+        fn end(self) -> Result<(), Error> {
+            Ok(())
+        }
+    }
+
+    impl ser::SerializeTuple for Compound<'_> {
+        type Ok = ();
+        type Error = Error;
+
+        fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+            value.serialize(&mut *self.serializer)
+        }
+
+        fn end(self) -> Result<(), Error> {
+            Ok(())
+        }
+    }
+
+    impl ser::SerializeTupleStruct for Compound<'_> {
+        type Ok = ();
+        type Error = Error;
+
+        fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+            value.serialize(&mut *self.serializer)
+        }
+
+        fn end(self) -> Result<(), Error> {
+            Ok(())
+        }
+    }
+
+    impl ser::SerializeStruct for Compound<'_> {
+        type Ok = ();
+        type Error = Error;
+
+        fn serialize_field<T: Serialize + ?Sized>(&mut self, _: &'static str, value: &T) -> Result<(), Error> {
+            value.serialize(&mut *self.serializer)
+        }
+
+        fn end(self) -> Result<(), Error> {
+            Ok(())
+        }
+    }
+
+    pub struct Deserializer<'de> {
+        input: &'de [u8],
+    }
+
+    impl<'de> Deserializer<'de> {
+        fn take<const N: usize>(&mut self) -> Result<[u8; N], Error> {
+            let bytes = self.take_slice(N)?;
+            Ok(bytes.try_into().unwrap())
+        }
+
+        fn take_slice(&mut self, len: usize) -> Result<&'de [u8], Error> {
+            if self.input.len() < len {
+                return Err(Error("unexpected end of input".to_string()));
+            }
+            let (bytes, rest) = self.input.split_at(len);
+            self.input = rest;
+            Ok(bytes)
+        }
+
+        fn take_len(&mut self) -> Result<usize, Error> {
+            usize::try_from(u64::from_le_bytes(self.take()?)).map_err(|_| Error("len is too big".to_string()))
+        }
+    }
+
+    macro_rules! deserialize_numbers {
+        ($($method:ident => $type:ty, $visit:ident;)*) => {
+            $(
+                fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+                    visitor.$visit(<$type>::from_le_bytes(self.take()?))
+                }
+            )*
+        };
+    }
+
+    impl<'de> de::Deserializer<'de> for &mut Deserializer<'de> {
+        type Error = Error;
+
+        fn deserialize_any<V: Visitor<'de>>(self, _: V) -> Result<V::Value, Error> {
+            Err(Error("the binary format isn't self-describing".to_string()))
+        }
+
+        deserialize_numbers! {
+            deserialize_i8 => i8, visit_i8;
+            deserialize_i16 => i16, visit_i16;
+            deserialize_i32 => i32, visit_i32;
+            deserialize_i64 => i64, visit_i64;
+            deserialize_u8 => u8, visit_u8;
+            deserialize_u16 => u16, visit_u16;
+            deserialize_u32 => u32, visit_u32;
+            deserialize_u64 => u64, visit_u64;
+            deserialize_f32 => f32, visit_f32;
+            deserialize_f64 => f64, visit_f64;
+        }
+
+        fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+            visitor.visit_bool(self.take::<1>()?[0] != 0)
+        }
+
+        fn deserialize_char<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+            let code = u32::from_le_bytes(self.take()?);
+            visitor.visit_char(char::from_u32(code).ok_or_else(|| Error("invalid char".to_string()))?)
+        }
+
+        fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+            let len = self.take_len()?;
+            let bytes = self.take_slice(len)?;
+            visitor.visit_borrowed_str(std::str::from_utf8(bytes).map_err(|error| Error(error.to_string()))?)
+        }
+
+        fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+            self.deserialize_str(visitor)
+        }
+
+        fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+            let len = self.take_len()?;
+            visitor.visit_borrowed_bytes(self.take_slice(len)?)
+        }
+
+        fn deserialize_byte_buf<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+            self.deserialize_bytes(visitor)
+        }
+
+        fn deserialize_newtype_struct<V: Visitor<'de>>(self, _: &'static str, visitor: V) -> Result<V::Value, Error> {
+            visitor.visit_newtype_struct(self)
+        }
+
+        fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+            let len = self.take_len()?;
+            visitor.visit_seq(Counted { deserializer: self, remaining: len })
+        }
+
+        fn deserialize_tuple<V: Visitor<'de>>(self, len: usize, visitor: V) -> Result<V::Value, Error> {
+            visitor.visit_seq(Counted { deserializer: self, remaining: len })
+        }
+
+        fn deserialize_tuple_struct<V: Visitor<'de>>(
+            self,
+            _: &'static str,
+            len: usize,
+            visitor: V,
+        ) -> Result<V::Value, Error> {
+            self.deserialize_tuple(len, visitor)
+        }
+
+        fn deserialize_struct<V: Visitor<'de>>(
+            self,
+            _: &'static str,
+            fields: &'static [&'static str],
+            visitor: V,
+        ) -> Result<V::Value, Error> {
+            self.deserialize_tuple(fields.len(), visitor)
+        }
+
+        serde::forward_to_deserialize_any! {
+            i128 u128 option unit unit_struct map enum identifier ignored_any
+        }
+
+        fn is_human_readable(&self) -> bool {
+            false
+        }
+    }
+
+    /// a sequence of a known len.
+    struct Counted<'a, 'de> {
+        deserializer: &'a mut Deserializer<'de>,
+        remaining: usize,
+    }
+
+    impl<'de> SeqAccess<'de> for Counted<'_, 'de> {
+        type Error = Error;
+
+        fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>, Error> {
+            if self.remaining == 0 {
+                return Ok(None);
+            }
+            self.remaining -= 1;
+            seed.deserialize(&mut *self.deserializer).map(Some)
+        }
+
+        fn size_hint(&self) -> Option<usize> {
+            Some(self.remaining)
+        }
+    }
+}
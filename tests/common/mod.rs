@@ -0,0 +1,75 @@
+//! helpers shared by the integration tests:
+//! - [`Tracker`] and [`Tracked`], to count drops. the tests that use them are meant to be run under miri too,
+//!   with `cargo +nightly miri test --test <name>`, so that it catches leaks, double drops and reads of uninitialized elements.
+//! - [`formats`]: two tiny serde formats, with the `serde` feature.
+#![allow(dead_code)]
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use fast_collections::{FastArray, FastMatrix};
+
+#[cfg(feature = "serde")]
+pub mod formats;
+
+/// hands out [`Tracked`] values and counts how many of them have been dropped.
+#[derive(Default)]
+pub struct Tracker {
+    drops: Rc<Cell<usize>>,
+}
+
+impl Tracker {
+    pub fn track(&self, value: usize) -> Tracked {
+        Tracked { value, drops: Rc::clone(&self.drops), explosive: false }
+    }
+
+    /// same as [`Tracker::track`], but cloning the value panics.
+    pub fn track_explosive(&self, value: usize) -> Tracked {
+        Tracked { value, drops: Rc::clone(&self.drops), explosive: true }
+    }
+
+    pub fn dropped(&self) -> usize {
+        self.drops.get()
+    }
+}
+
+/// a value with a heap allocation, so that miri catches both leaks and double drops.
+#[derive(Debug)]
+pub struct Tracked {
+    pub value: usize,
+    drops: Rc<Cell<usize>>,
+    explosive: bool,
+}
+
+impl Clone for Tracked {
+    fn clone(&self) -> Self {
+        assert!(!self.explosive, "Tracked: {} exploded", self.value);
+        Tracked { value: self.value, drops: Rc::clone(&self.drops), explosive: false }
+    }
+}
+
+impl PartialEq for Tracked {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Drop for Tracked {
+    fn drop(&mut self) {
+        self.drops.set(self.drops.get() + 1);
+    }
+}
+
+pub fn values<'a>(items: impl IntoIterator<Item = &'a Tracked>) -> Vec<usize> {
+    items.into_iter().map(|item| item.value).collect()
+}
+
+/// `[0, 1, ..., len - 1]`
+pub fn tracked_array(tracker: &Tracker, len: usize) -> FastArray<Tracked> {
+    FastArray::new_func(len, |index| tracker.track(index))
+}
+
+/// the elements are numbered row after row.
+pub fn tracked_matrix(tracker: &Tracker, rows: usize, columns: usize) -> FastMatrix<Tracked> {
+    FastMatrix::new_func(rows, columns, |(row, column)| tracker.track(row * columns + column))
+}
@@ -0,0 +1,552 @@
+//! the unsafe core of the crate: constructors, [`FastIterator`], the conversions between the collections and drop accounting.
+
+use fast_collections::fast_array::fast_array_basics::IntoFastArray;
+use fast_collections::fast_matrix::fast_matrix::StorageOrder;
+use fast_collections::fast_matrix::fast_matrix_basics::IntoFastMatrix;
+use fast_collections::{
+    fast_arr, fast_matrix, FastArray, FastArrayN, FastError, FastIterator, FastMatrix, FastMatrixN, FastTensor,
+};
+
+mod common;
+
+use common::{tracked_array, tracked_matrix, values, Tracked, Tracker};
+
+// ---------------------------------------- FastArray ----------------------------------------
+
+#[test]
+fn fast_array_constructors() {
+    assert_eq!(FastArray::new(3, 7), FastArray::from(vec![7, 7, 7]));
+    assert_eq!(FastArray::<u8>::new_default(2), FastArray::from(vec![0, 0]));
+    assert_eq!(FastArray::new_func(4, |index| index * 2), FastArray::from(vec![0, 2, 4, 6]));
+    assert_eq!(FastArray::try_new(2, 'a').unwrap(), FastArray::from(vec!['a', 'a']));
+    assert_eq!(FastArray::<i64>::try_new_default(1).unwrap(), FastArray::from(vec![0]));
+    assert_eq!(FastArray::try_new_func(3, |index| index).unwrap(), FastArray::from(vec![0, 1, 2]));
+    assert_eq!(FastArray::<u16>::try_with_len(2).unwrap(), FastArray::from(vec![0, 0]));
+
+    unsafe {
+        assert_eq!(FastArray::new_unchecked(2, 1), FastArray::from(vec![1, 1]));
+        assert_eq!(FastArray::<u32>::new_default_unchecked(3), FastArray::from(vec![0, 0, 0]));
+        assert_eq!(FastArray::new_func_unchecked(2, |index| index + 1), FastArray::from(vec![1, 2]));
+    }
+}
+
+#[test]
+fn fast_array_new_empty() {
+    let tracker = Tracker::default();
+
+    let mut fast_arr: FastArray<Tracked> = unsafe { FastArray::new_empty(3) };
+    for index in 0..3 {
+        // the elements aren't initialized yet, so they have to be written instead of assigned
+        unsafe { fast_arr.as_mut_ptr().add(index).write(tracker.track(index)) };
+    }
+    assert_eq!(values(fast_arr.iter()), [0, 1, 2]);
+
+    drop(fast_arr);
+    assert_eq!(tracker.dropped(), 3);
+}
+
+#[test]
+fn fast_array_conversions() {
+    let mut elements = vec!["a".to_string(), "b".to_string()];
+
+    assert_eq!(FastArray::from(elements.as_slice()), FastArray::from(elements.clone()));
+    assert_eq!(FastArray::from(elements.as_mut_slice()), FastArray::from(elements.clone()));
+    assert_eq!(elements.iter().cloned().collect::<FastArray<_>>(), FastArray::from(elements.clone()));
+    assert_eq!(elements.clone().into_iter().into_fast_array(), FastArray::from(elements.clone()));
+
+    let fast_arr_n = FastArrayN::from([1, 2, 3]);
+    assert_eq!(fast_arr_n.into_fast_array(), FastArray::from(vec![1, 2, 3]));
+    assert_eq!(FastArrayN::<i32, 3>::try_from(FastArray::from(vec![1, 2, 3])).unwrap().into_inner(), [1, 2, 3]);
+}
+
+#[test]
+fn fast_array_try_from_vec() {
+    let tracker = Tracker::default();
+
+    let fast_arr = FastArray::try_from_vec(vec![tracker.track(0), tracker.track(1)]).unwrap();
+    assert_eq!(values(fast_arr.iter()), [0, 1]);
+    assert_eq!(tracker.dropped(), 0);
+
+    assert!(matches!(FastArray::<Tracked>::try_from_vec(Vec::new()), Err(FastError::ZeroLength)));
+
+    drop(fast_arr);
+    assert_eq!(tracker.dropped(), 2);
+}
+
+#[test]
+#[should_panic(expected = "FastArray: the length cannot be 0")]
+fn fast_array_from_empty_vec() {
+    FastArray::<i32>::from(Vec::new());
+}
+
+#[test]
+fn fast_array_macro() {
+    assert_eq!(fast_arr!(1, 2, 3), FastArray::from(vec![1, 2, 3]));
+    assert_eq!(fast_arr!(4; 3), FastArray::from(vec![4, 4, 4]));
+    assert_eq!(fast_arr!([1, 2]; 2), FastArray::from(vec![1, 2, 1, 2]));
+    assert_eq!(fast_arr!(const 1, 2), FastArray::from(vec![1, 2]));
+
+    // the macro writes into uninitialized memory, so it mustn't drop what's there
+    let fast_arr = fast_arr!("a".to_string(), "b".to_string());
+    assert_eq!(fast_arr[1], "b");
+    let fast_arr = fast_arr!(["x".to_string()]; 2);
+    assert_eq!(fast_arr[1], "x");
+}
+
+#[test]
+fn fast_array_drop() {
+    let tracker = Tracker::default();
+
+    drop(tracked_array(&tracker, 5));
+    assert_eq!(tracker.dropped(), 5);
+}
+
+#[test]
+fn fast_array_clone() {
+    let tracker = Tracker::default();
+
+    let fast_arr = tracked_array(&tracker, 3);
+    let mut clone = fast_arr.clone();
+    clone[0].value = 10;
+
+    // the clone owns its own elements
+    assert_eq!(values(fast_arr.iter()), [0, 1, 2]);
+    assert_eq!(values(clone.iter()), [10, 1, 2]);
+
+    drop(fast_arr);
+    assert_eq!(tracker.dropped(), 3);
+    drop(clone);
+    assert_eq!(tracker.dropped(), 6);
+}
+
+#[test]
+fn fast_array_assignment_drops_the_old_element() {
+    let tracker = Tracker::default();
+
+    let mut fast_arr = tracked_array(&tracker, 2);
+    fast_arr[1] = tracker.track(7);
+    assert_eq!(tracker.dropped(), 1);
+
+    drop(fast_arr);
+    assert_eq!(tracker.dropped(), 3);
+}
+
+// ---------------------------------------- FastIterator ----------------------------------------
+
+#[test]
+fn fast_iterator_constructors() {
+    let mut value = 0;
+    let fast_iter = FastIterator::new_func(3, || {
+        value += 1;
+        value
+    });
+    assert_eq!(fast_iter.collect::<Vec<_>>(), [1, 2, 3]);
+
+    let fast_iter: FastIterator<_> = (0..4).collect();
+    assert_eq!(fast_iter.collect::<Vec<_>>(), [0, 1, 2, 3]);
+
+    let fast_iter = unsafe { FastIterator::new_func_unchecked(2, || 'a') };
+    assert_eq!(fast_iter.collect::<String>(), "aa");
+}
+
+#[test]
+fn fast_iterator_next() {
+    let tracker = Tracker::default();
+
+    let mut fast_iter = tracked_array(&tracker, 3).into_fast_iterator();
+    assert_eq!(fast_iter.next().map(|item| item.value), Some(0));
+    assert_eq!(fast_iter.next().map(|item| item.value), Some(1));
+    assert_eq!(fast_iter.next().map(|item| item.value), Some(2));
+    assert!(fast_iter.next().is_none());
+    assert!(fast_iter.next().is_none());
+    assert_eq!(tracker.dropped(), 3);
+
+    drop(fast_iter);
+    assert_eq!(tracker.dropped(), 3);
+}
+
+#[test]
+fn fast_iterator_early_drop() {
+    let tracker = Tracker::default();
+
+    let fast_iter = tracked_array(&tracker, 4).into_fast_iterator();
+    drop(fast_iter);
+    assert_eq!(tracker.dropped(), 4);
+
+    let mut fast_iter = tracked_array(&tracker, 4).into_fast_iterator();
+    let first = fast_iter.next().unwrap();
+    drop(fast_iter);
+    // the element that was taken out is still alive
+    assert_eq!(tracker.dropped(), 7);
+    assert_eq!(first.value, 0);
+    drop(first);
+    assert_eq!(tracker.dropped(), 8);
+}
+
+#[test]
+fn fast_iterator_next_back() {
+    let tracker = Tracker::default();
+
+    let mut fast_iter = tracked_array(&tracker, 3).into_fast_iterator();
+    assert_eq!(fast_iter.next_back().map(|item| item.value), Some(2));
+    assert_eq!(tracker.dropped(), 1);
+
+    drop(fast_iter);
+    assert_eq!(tracker.dropped(), 3);
+}
+
+#[test]
+fn fast_iterator_next_back_exhaust() {
+    let tracker = Tracker::default();
+
+    let fast_iter = tracked_array(&tracker, 3).into_fast_iterator();
+    assert_eq!(values(&fast_iter.rev().collect::<Vec<_>>()), [2, 1, 0]);
+    assert_eq!(tracker.dropped(), 3);
+}
+
+#[test]
+fn fast_iterator_interleaved() {
+    let tracker = Tracker::default();
+
+    let mut fast_iter = tracked_array(&tracker, 5).into_fast_iterator();
+    assert_eq!(fast_iter.next().map(|item| item.value), Some(0));
+    assert_eq!(fast_iter.next_back().map(|item| item.value), Some(4));
+    assert_eq!(fast_iter.next_back().map(|item| item.value), Some(3));
+    assert_eq!(fast_iter.next().map(|item| item.value), Some(1));
+    assert_eq!(fast_iter.len(), 1);
+
+    drop(fast_iter);
+    assert_eq!(tracker.dropped(), 5);
+}
+
+#[test]
+fn fast_iterator_len() {
+    let mut fast_iter = FastArray::new_func(4, |index| index).into_fast_iterator();
+    assert_eq!(fast_iter.len(), 4);
+
+    fast_iter.next();
+    assert_eq!(fast_iter.len(), 3);
+    assert_eq!(fast_iter.size_hint(), (3, Some(3)));
+}
+
+#[test]
+fn fast_iterator_borrowing() {
+    let mut fast_arr = FastArray::new_func(3, |index| index);
+
+    assert_eq!(fast_arr.iter().copied().collect::<Vec<_>>(), [0, 1, 2]);
+    for element in fast_arr.iter_mut() {
+        *element *= 10;
+    }
+    assert_eq!(fast_arr, FastArray::from(vec![0, 10, 20]));
+}
+
+// ---------------------------------------- round trips ----------------------------------------
+
+#[test]
+fn fast_array_iterator_round_trip() {
+    let tracker = Tracker::default();
+
+    let fast_arr = tracked_array(&tracker, 4);
+    let fast_arr = fast_arr.into_fast_iterator().into_fast_array();
+    assert_eq!(values(fast_arr.iter()), [0, 1, 2, 3]);
+    assert_eq!(tracker.dropped(), 0);
+
+    drop(fast_arr);
+    assert_eq!(tracker.dropped(), 4);
+}
+
+#[test]
+fn fast_array_iterator_round_trip_after_next() {
+    let tracker = Tracker::default();
+
+    let mut fast_iter = tracked_array(&tracker, 4).into_fast_iterator();
+    drop(fast_iter.next());
+
+    let fast_arr = fast_iter.into_fast_array();
+    assert_eq!(values(fast_arr.iter()), [1, 2, 3]);
+    assert_eq!(tracker.dropped(), 1);
+
+    drop(fast_arr);
+    assert_eq!(tracker.dropped(), 4);
+}
+
+#[test]
+fn fast_array_into_iter() {
+    let tracker = Tracker::default();
+
+    let collected: Vec<_> = tracked_array(&tracker, 3).into_iter().collect();
+    assert_eq!(values(&collected), [0, 1, 2]);
+    assert_eq!(tracker.dropped(), 0);
+
+    drop(collected);
+    assert_eq!(tracker.dropped(), 3);
+}
+
+// ---------------------------------------- FastMatrix ----------------------------------------
+
+#[test]
+fn fast_matrix_constructors() {
+    let expected = FastMatrix::from(vec![vec![0, 1, 2], vec![10, 11, 12]]);
+
+    assert_eq!(FastMatrix::new_func(2, 3, |(row, column)| row * 10 + column), expected);
+    assert_eq!(FastMatrix::try_new_func(2, 3, |(row, column)| row * 10 + column).unwrap(), expected);
+    assert_eq!(unsafe { FastMatrix::new_func_unchecked(2, 3, |(row, column)| row * 10 + column) }, expected);
+
+    assert_eq!(FastMatrix::new(2, 2, 5), fast_matrix!([5, 5], [5, 5]));
+    assert_eq!(FastMatrix::try_new(1, 2, 5).unwrap(), fast_matrix!([5, 5]));
+    assert_eq!(unsafe { FastMatrix::new_unchecked(2, 1, 5) }, fast_matrix!([5], [5]));
+    assert_eq!(FastMatrix::<u8>::new_default(1, 3), fast_matrix!([0, 0, 0]));
+    assert_eq!(FastMatrix::<u8>::try_new_default(2, 1).unwrap(), fast_matrix!([0], [0]));
+    assert_eq!(unsafe { FastMatrix::<u8>::new_default_unchecked(1, 1) }, fast_matrix!([0]));
+    assert_eq!(FastMatrix::<u8>::try_with_len(1, 2).unwrap(), fast_matrix!([0, 0]));
+}
+
+#[test]
+fn fast_matrix_macro() {
+    let expected = FastMatrix::from(vec![vec![1, 2, 1, 2], vec![3, 3, 3, 3]]);
+
+    assert_eq!(fast_matrix!([1, 2; 2], [3; 4]), expected);
+    assert_eq!(fast_matrix!([1, 2]; 2), FastMatrix::from(vec![vec![1, 2], vec![1, 2]]));
+    assert_eq!(fast_matrix!(0; 2; 3), FastMatrix::new(2, 3, 0));
+    assert_eq!(fast_matrix!(const [1, 2], [3, 4]), FastMatrix::from(vec![vec![1, 2], vec![3, 4]]));
+    assert_eq!(fast_matrix!(const 0; 1; 2), FastMatrix::new(1, 2, 0));
+
+    // the macro writes into uninitialized memory, so it mustn't drop what's there
+    let fast_matrix = fast_matrix!(["a".to_string(), "b".to_string()], ["c".to_string(), "d".to_string()]);
+    assert_eq!(fast_matrix[(1, 0)], "c");
+    let fast_matrix = fast_matrix!(["a".to_string()]; 2);
+    assert_eq!(fast_matrix[(1, 0)], "a");
+    let fast_matrix = fast_matrix!(["a".to_string(), "b".to_string(); 2]);
+    assert_eq!(fast_matrix[(0, 3)], "b");
+}
+
+#[test]
+fn fast_matrix_new_empty() {
+    let tracker = Tracker::default();
+
+    let mut fast_matrix: FastMatrix<Tracked> = unsafe { FastMatrix::new_empty(2, 2) };
+    for row in 0..2 {
+        for column in 0..2 {
+            unsafe { fast_matrix.get_pointer_mut((row, column)).write(tracker.track(row * 2 + column)) };
+        }
+    }
+    assert_eq!(values(fast_matrix.iter()), [0, 1, 2, 3]);
+
+    drop(fast_matrix);
+    assert_eq!(tracker.dropped(), 4);
+}
+
+#[test]
+fn fast_matrix_drop() {
+    let tracker = Tracker::default();
+
+    drop(tracked_matrix(&tracker, 3, 2));
+    assert_eq!(tracker.dropped(), 6);
+}
+
+#[test]
+fn fast_matrix_clone() {
+    let tracker = Tracker::default();
+
+    let fast_matrix = tracked_matrix(&tracker, 2, 2).into_order(StorageOrder::ColumnMajor);
+    let clone = fast_matrix.clone();
+    assert_eq!(clone, fast_matrix);
+    assert_eq!(clone.order(), StorageOrder::ColumnMajor);
+
+    drop(fast_matrix);
+    assert_eq!(tracker.dropped(), 4);
+    drop(clone);
+    assert_eq!(tracker.dropped(), 8);
+}
+
+#[test]
+fn fast_matrix_into_fast_iter() {
+    let tracker = Tracker::default();
+
+    let fast_iter = tracked_matrix(&tracker, 2, 3).into_fast_iter();
+    assert_eq!(values(&fast_iter.collect::<Vec<_>>()), [0, 1, 2, 3, 4, 5]);
+    assert_eq!(tracker.dropped(), 6);
+
+    let mut fast_iter = tracked_matrix(&tracker, 2, 3).into_iter();
+    fast_iter.next();
+    drop(fast_iter);
+    assert_eq!(tracker.dropped(), 12);
+}
+
+#[test]
+fn fast_matrix_iterator_round_trip() {
+    let tracker = Tracker::default();
+
+    let fast_matrix = tracked_matrix(&tracker, 2, 3);
+    let fast_matrix = fast_matrix.into_fast_iter().into_fast_matrix(3, 2);
+    assert_eq!((fast_matrix.rows, fast_matrix.columns), (3, 2));
+    assert_eq!(values(fast_matrix.iter()), [0, 1, 2, 3, 4, 5]);
+    assert_eq!(tracker.dropped(), 0);
+
+    let fast_matrix = fast_matrix.into_fast_iter().into_fast_matrix_with_order(2, 3, StorageOrder::ColumnMajor);
+    assert_eq!(fast_matrix[(1, 0)].value, 1);
+    assert_eq!(fast_matrix[(0, 2)].value, 4);
+
+    drop(fast_matrix);
+    assert_eq!(tracker.dropped(), 6);
+}
+
+#[test]
+fn fast_matrix_iterator_round_trip_after_next() {
+    let tracker = Tracker::default();
+
+    let mut fast_iter = tracked_array(&tracker, 5).into_fast_iterator();
+    drop(fast_iter.next());
+
+    let fast_matrix = fast_iter.into_fast_matrix(2, 2);
+    assert_eq!(values(fast_matrix.iter()), [1, 2, 3, 4]);
+
+    drop(fast_matrix);
+    assert_eq!(tracker.dropped(), 5);
+}
+
+#[test]
+fn fast_matrix_nested_arrays() {
+    let tracker = Tracker::default();
+
+    let rows = tracked_matrix(&tracker, 2, 2).into_nested_arrays();
+    // the rows are copies, the elements of the matrix got dropped with it
+    assert_eq!(tracker.dropped(), 4);
+    assert_eq!(values(rows[1].iter()), [2, 3]);
+
+    // `FastIterator::into_fast_matrix` would make a matrix of arrays, so the trait has to be named
+    let fast_matrix = IntoFastMatrix::into_fast_matrix(rows.into_fast_iterator(), 2, 2);
+    assert_eq!(values(fast_matrix.iter()), [0, 1, 2, 3]);
+
+    drop(fast_matrix);
+    assert_eq!(tracker.dropped(), 8);
+
+    let fast_iter = tracked_matrix(&tracker, 3, 1).into_fast_iter_arrays();
+    assert_eq!(fast_iter.map(|row| row[0].value).collect::<Vec<_>>(), [0, 1, 2]);
+    assert_eq!(tracker.dropped(), 14);
+}
+
+#[test]
+fn fast_matrix_try_from_iterator() {
+    let tracker = Tracker::default();
+
+    let error = tracked_array(&tracker, 6).into_fast_iterator().try_into_fast_matrix(4, 2).unwrap_err();
+    assert!(matches!(error, FastError::ShapeMismatch { expected: (4, 2), found: (1, 6) }));
+    assert_eq!(tracker.dropped(), 6);
+
+    let mut fast_iter = tracked_array(&tracker, 3).into_fast_iterator();
+    fast_iter.by_ref().for_each(drop);
+    assert!(matches!(fast_iter.try_into_fast_matrix(1, 1), Err(FastError::ZeroLength)));
+    assert_eq!(tracker.dropped(), 9);
+
+    let mut fast_iter = tracked_array(&tracker, 7).into_fast_iterator();
+    drop(fast_iter.next());
+    let fast_matrix = fast_iter.try_into_fast_matrix_with_order(3, 2, StorageOrder::ColumnMajor).unwrap();
+    assert_eq!(fast_matrix[(0, 1)].value, 4);
+    assert_eq!(fast_matrix[(2, 0)].value, 3);
+
+    drop(fast_matrix);
+    assert_eq!(tracker.dropped(), 16);
+}
+
+#[test]
+fn fast_matrix_try_from_arrays() {
+    let tracker = Tracker::default();
+
+    let rows = tracked_matrix(&tracker, 2, 3).into_nested_arrays();
+    let fast_matrix = IntoFastMatrix::try_into_fast_matrix(rows.into_fast_iterator(), 3, 2).unwrap();
+    assert_eq!(values(fast_matrix.iter()), [0, 1, 2, 3, 4, 5]);
+    drop(fast_matrix);
+    assert_eq!(tracker.dropped(), 12);
+
+    let rows = tracked_matrix(&tracker, 2, 3).into_nested_arrays();
+    let error = IntoFastMatrix::try_into_fast_matrix(rows.into_fast_iterator(), 2, 2).unwrap_err();
+    assert!(matches!(error, FastError::ShapeMismatch { expected: (2, 2), found: (2, 3) }));
+    assert_eq!(tracker.dropped(), 24);
+
+    let ragged = [tracked_array(&tracker, 2), tracked_array(&tracker, 2), tracked_array(&tracker, 1)];
+    let error = ragged.into_iter().try_into_fast_matrix(1, 5).unwrap_err();
+    assert!(matches!(error, FastError::RaggedRows { row: 2 }));
+    assert_eq!(tracker.dropped(), 29);
+
+    let error = std::iter::empty::<FastArray<Tracked>>().try_into_fast_matrix(1, 1).unwrap_err();
+    assert!(matches!(error, FastError::ZeroLength));
+}
+
+#[test]
+fn fast_matrix_order_and_transpose() {
+    let tracker = Tracker::default();
+
+    let fast_matrix = tracked_matrix(&tracker, 2, 3);
+    let transposed = fast_matrix.transpose();
+    assert_eq!(transposed[(2, 1)].value, 5);
+
+    let reordered = fast_matrix.into_order(StorageOrder::ColumnMajor);
+    assert_eq!(reordered.to_order(StorageOrder::RowMajor), reordered);
+    assert_eq!(tracker.dropped(), 6);
+
+    let mut reordered = reordered;
+    reordered.transpose_in_place();
+    assert_eq!(reordered, transposed);
+
+    drop(reordered);
+    drop(transposed);
+    assert_eq!(tracker.dropped(), 18);
+}
+
+/// panics when cloning `"boom"`, and reads its reference on drop so that dropping an uninitialized one is caught by miri.
+#[derive(Debug)]
+struct CloneBomb(&'static str);
+
+impl Clone for CloneBomb {
+    fn clone(&self) -> Self {
+        assert_ne!(self.0, "boom", "CloneBomb exploded");
+        CloneBomb(self.0)
+    }
+}
+
+impl Drop for CloneBomb {
+    fn drop(&mut self) {
+        std::hint::black_box(self.0.len());
+    }
+}
+
+#[test]
+fn fast_matrix_transpose_panicking_clone() {
+    let fast_matrix = FastMatrix::new_func(3, 2, |(row, column)| CloneBomb(if (row, column) == (1, 1) { "boom" } else { "ok" }));
+
+    let result = std::panic::catch_unwind(|| fast_matrix.transpose());
+    assert!(result.is_err());
+    let result = std::panic::catch_unwind(|| fast_matrix.to_order(StorageOrder::ColumnMajor));
+    assert!(result.is_err());
+
+    assert_eq!(fast_matrix[(2, 1)].0, "ok");
+}
+
+#[test]
+fn fast_matrix_n_conversions() {
+    let fast_matrix: FastMatrix<String> = FastMatrixN::from([["a".to_string()], ["b".to_string()]]).into_fast_matrix();
+    assert_eq!(fast_matrix[(1, 0)], "b");
+
+    let fast_matrix_n = FastMatrixN::<String, 2, 1>::try_from(fast_matrix).unwrap();
+    assert_eq!(fast_matrix_n.into_inner(), [["a".to_string()], ["b".to_string()]]);
+}
+
+// ---------------------------------------- FastTensor ----------------------------------------
+
+#[test]
+fn fast_tensor_panicking_clone() {
+    let tracker = Tracker::default();
+    let tensor = FastTensor::new_func(&[2, 3], |index| match index {
+        [1, 1] => tracker.track_explosive(4),
+        _ => tracker.track(index[0] * 3 + index[1]),
+    });
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| tensor.clone()));
+    assert!(result.is_err());
+    // the 4 elements cloned before the panic are dropped, and nothing else
+    assert_eq!(tracker.dropped(), 4);
+
+    drop(tensor);
+    assert_eq!(tracker.dropped(), 10);
+}
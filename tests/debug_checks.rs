@@ -0,0 +1,89 @@
+//! the `_unchecked` and `_unsafe` methods only check their preconditions when `debug_assertions` are on.
+#![cfg(debug_assertions)]
+
+use fast_collections::{fast_matrix, FastArray, FastIterator, FastMatrix};
+
+#[test]
+#[should_panic(expected = "FastArray::swap_unchecked: index 3 is out of bounds for len 3")]
+fn fast_array_swap_unchecked() {
+    let mut fast_arr = FastArray::new(3, 0);
+    unsafe { fast_arr.swap_unchecked(0, 3) };
+}
+
+#[test]
+#[should_panic(expected = "FastArray::swap_unsafe_unchecked: index 5 is out of bounds for len 2")]
+fn fast_array_swap_unsafe_unchecked() {
+    let fast_arr = FastArray::new(2, 0);
+    unsafe { fast_arr.swap_unsafe_unchecked(5, 0) };
+}
+
+#[test]
+#[should_panic(expected = "FastArray::get_mut_pointer_unsafe_unchecked: index 4 is out of bounds for len 4")]
+fn fast_array_get_mut_pointer_unsafe_unchecked() {
+    let fast_arr = FastArray::new(4, 0u8);
+    unsafe { fast_arr.get_mut_pointer_unsafe_unchecked(4) };
+}
+
+#[test]
+#[should_panic(expected = "FastArray::new_func_unchecked: len cannot be 0!")]
+fn fast_array_new_func_unchecked_zero_len() {
+    unsafe { FastArray::new_func_unchecked(0, |index| index) };
+}
+
+#[test]
+#[should_panic(expected = "FastIterator::allocate_mem_unchecked: len cannot be 0!")]
+fn fast_iterator_allocate_mem_unchecked_zero_len() {
+    unsafe { FastIterator::<u8>::allocate_mem_unchecked(0) };
+}
+
+#[test]
+#[should_panic(expected = "FastMatrix::swap_unchecked: index (0, 2) is out of bounds for a 2x2 matrix")]
+fn fast_matrix_swap_unchecked() {
+    let mut fast_matrix = fast_matrix!([1, 2], [3, 4]);
+    unsafe { fast_matrix.swap_unchecked((0, 0), (0, 2)) };
+}
+
+#[test]
+#[should_panic(expected = "FastMatrix::get_pointer_mut_unchecked_unsafe: index (3, 0) is out of bounds for a 3x1 matrix")]
+fn fast_matrix_get_pointer_mut_unchecked_unsafe() {
+    let fast_matrix = FastMatrix::new(3, 1, 0);
+    unsafe { fast_matrix.get_pointer_mut_unchecked_unsafe((3, 0)) };
+}
+
+#[test]
+#[should_panic(expected = "FastMatrix::swap_rows_unchecked: rows 0 and 2 must be less than 2")]
+fn fast_matrix_swap_rows_unchecked() {
+    let mut fast_matrix = fast_matrix!([1, 2], [3, 4]);
+    unsafe { fast_matrix.swap_rows_unchecked(0, 2) };
+}
+
+#[test]
+#[should_panic(expected = "FastMatrix::swap_columns_unchecked_unsafe: columns 3 and 0 must be less than 3")]
+fn fast_matrix_swap_columns_unchecked_unsafe() {
+    let fast_matrix = fast_matrix!([1, 2, 3]);
+    unsafe { fast_matrix.swap_columns_unchecked_unsafe(3, 0) };
+}
+
+#[test]
+#[should_panic(expected = "FastMatrix::new_empty_unchecked: rows and columns cannot be 0, found 2x0!")]
+fn fast_matrix_new_empty_unchecked_zero_columns() {
+    unsafe { FastMatrix::<u8>::new_empty_unchecked(2, 0) };
+}
+
+#[test]
+fn unchecked_methods_in_bounds() {
+    let mut fast_matrix = fast_matrix!([1, 2, 3], [4, 5, 6]);
+    unsafe {
+        fast_matrix.swap_unchecked((0, 0), (1, 2));
+        fast_matrix.swap_columns_unchecked(0, 1);
+    }
+    assert_eq!(fast_matrix, fast_matrix!([2, 6, 3], [5, 4, 1]));
+}
+
+#[test]
+fn fast_matrix_swap_columns_unsafe_wide() {
+    // there are more columns than rows, so `column2` has to be checked against the columns
+    let fast_matrix = fast_matrix!([1, 2, 3]);
+    unsafe { fast_matrix.swap_columns_unsafe(0, 2) };
+    assert_eq!(fast_matrix, fast_matrix!([3, 2, 1]));
+}
@@ -0,0 +1,163 @@
+//! the pointer bookkeeping of [`FastIterator`], taking elements from both sides in every possible order.
+//!
+//! under miri with the `rayon` feature, add `MIRIFLAGS=-Zmiri-tree-borrows`, as crossbeam doesn't pass the default stacked borrows model.
+
+use std::collections::VecDeque;
+
+use fast_collections::{FastArray, FastIterator, FastMatrix};
+
+mod common;
+
+use common::{tracked_array, Tracked, Tracker};
+
+fn tracked_iterator(tracker: &Tracker, len: usize) -> FastIterator<Tracked> {
+    tracked_array(tracker, len).into_fast_iterator()
+}
+
+/// runs `steps` against a [`FastIterator`] and a [`VecDeque`] of the same elements, then drops the iterator early.
+///
+/// each step takes from the front if its bit in `steps` is 0, and from the back otherwise.
+fn check_steps(len: usize, steps: u32, step_count: usize) {
+    let tracker = Tracker::default();
+    let mut fast_iter = tracked_iterator(&tracker, len);
+    let mut expected: VecDeque<usize> = (0..len).collect();
+
+    for step in 0..step_count {
+        let (element, expected_value) = if steps & (1 << step) == 0 {
+            (fast_iter.next(), expected.pop_front())
+        } else {
+            (fast_iter.next_back(), expected.pop_back())
+        };
+
+        assert_eq!(element.map(|element| element.value), expected_value, "len {len}, steps {steps:b}, step {step}");
+        assert_eq!(fast_iter.len(), expected.len());
+        assert_eq!(fast_iter.size_hint(), (expected.len(), Some(expected.len())));
+    }
+
+    // every element that was yielded has been dropped by now, and the rest is dropped along with the iterator
+    assert_eq!(tracker.dropped(), len - expected.len());
+    drop(fast_iter);
+    assert_eq!(tracker.dropped(), len);
+}
+
+#[test]
+fn every_interleaving() {
+    // small enough to stay fast under miri, while still going past the end of the iterator
+    for len in 1..=4 {
+        for step_count in 0..=len + 1 {
+            for steps in 0..1 << step_count {
+                check_steps(len, steps, step_count);
+            }
+        }
+    }
+}
+
+#[test]
+fn exhausted_from_both_sides() {
+    let tracker = Tracker::default();
+    let mut fast_iter = tracked_iterator(&tracker, 2);
+
+    assert_eq!(fast_iter.next_back().map(|element| element.value), Some(1));
+    assert_eq!(fast_iter.next().map(|element| element.value), Some(0));
+    assert!(fast_iter.next().is_none());
+    assert!(fast_iter.next_back().is_none());
+    assert_eq!(fast_iter.len(), 0);
+
+    drop(fast_iter);
+    assert_eq!(tracker.dropped(), 2);
+}
+
+#[test]
+fn rev() {
+    let fast_iter = FastArray::new_func(5, |index| index.to_string()).into_fast_iterator();
+    assert_eq!(fast_iter.rev().collect::<Vec<_>>(), ["4", "3", "2", "1", "0"]);
+}
+
+#[test]
+fn into_fast_array_after_both_sides() {
+    let tracker = Tracker::default();
+    let mut fast_iter = tracked_iterator(&tracker, 5);
+    fast_iter.next();
+    fast_iter.next_back();
+
+    let fast_arr = fast_iter.into_fast_array();
+    assert_eq!(fast_arr.iter().map(|element| element.value).collect::<Vec<_>>(), [1, 2, 3]);
+    assert_eq!(tracker.dropped(), 2);
+
+    drop(fast_arr);
+    assert_eq!(tracker.dropped(), 5);
+}
+
+#[test]
+fn into_fast_matrix_after_next_back() {
+    let tracker = Tracker::default();
+    let mut fast_iter = tracked_iterator(&tracker, 5);
+    fast_iter.next_back();
+
+    let fast_matrix: FastMatrix<Tracked> = fast_iter.into_fast_matrix(2, 2);
+    assert_eq!(fast_matrix[(1, 1)].value, 3);
+
+    drop(fast_matrix);
+    assert_eq!(tracker.dropped(), 5);
+}
+
+#[test]
+fn clone_takes_the_remaining_elements() {
+    let tracker = Tracker::default();
+    let mut fast_iter = tracked_iterator(&tracker, 4);
+    fast_iter.next();
+    fast_iter.next_back();
+
+    let clone = fast_iter.clone();
+    assert_eq!(clone.len(), 2);
+    assert_eq!(clone.map(|element| element.value).collect::<Vec<_>>(), [1, 2]);
+
+    // the clone has its own elements, so the original still yields them
+    assert_eq!(fast_iter.map(|element| element.value).collect::<Vec<_>>(), [1, 2]);
+    assert_eq!(tracker.dropped(), 6);
+}
+
+#[test]
+fn clone_of_an_exhausted_iterator() {
+    let mut fast_iter = FastArray::new(1, "a".to_string()).into_fast_iterator();
+    fast_iter.next();
+
+    let mut clone = fast_iter.clone();
+    assert!(clone.next().is_none());
+    assert_eq!(clone.len(), 0);
+}
+
+#[test]
+fn zero_sized_elements() {
+    let mut fast_iter = FastArray::new(4, ()).into_fast_iterator();
+
+    assert_eq!(fast_iter.len(), 4);
+    assert_eq!(fast_iter.next(), Some(()));
+    assert_eq!(fast_iter.next_back(), Some(()));
+    assert_eq!(fast_iter.len(), 2);
+    assert_eq!(fast_iter.clone().count(), 2);
+    assert_eq!(fast_iter.count(), 2);
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn rayon_split() {
+    use rayon::prelude::*;
+
+    // rayon splits the iterator into smaller and smaller producers before consuming them
+    let fast_iter = FastArray::new_func(100, |index| index.to_string()).into_fast_iterator();
+    let lengths: usize = ParallelIterator::map(fast_iter, |element| element.len()).with_min_len(1).sum();
+    assert_eq!(lengths, 10 + 90 * 2);
+
+    let fast_iter = FastArray::new_func(100, |index| index).into_fast_iterator();
+    assert_eq!(ParallelIterator::sum::<usize>(fast_iter), 4950);
+}
+
+#[test]
+fn send_and_sync() {
+    fn send_sync<T: Send + Sync>(_: &T) {}
+
+    let fast_iter = FastArray::new_func(3, |index| index.to_string()).into_fast_iterator();
+    send_sync(&fast_iter);
+    assert_eq!(std::thread::spawn(move || fast_iter.count()).join().unwrap(), 3);
+}
@@ -0,0 +1,221 @@
+//! moving part of a [`FastArray`] out of it: [`FastArray::drain_func`], [`FastArray::into_iter_range`] and [`FastArray::split_off_into`].
+
+use fast_collections::{fast_arr, FastArray, FastError};
+
+mod common;
+
+use common::{tracked_array, values, Tracker};
+
+#[test]
+fn drain_func() {
+    let tracker = Tracker::default();
+    let mut fast_arr = tracked_array(&tracker, 5);
+
+    let drained = fast_arr.drain_func(1..4, |index| tracker.track(index * 10));
+    assert_eq!(values(fast_arr.iter()), [0, 10, 20, 30, 4]);
+    // nothing has been dropped, the elements have only been moved
+    assert_eq!(tracker.dropped(), 0);
+
+    assert_eq!(drained.len(), 3);
+    assert_eq!(drained.map(|element| element.value).collect::<Vec<_>>(), [1, 2, 3]);
+    assert_eq!(tracker.dropped(), 3);
+
+    drop(fast_arr);
+    assert_eq!(tracker.dropped(), 8);
+}
+
+#[test]
+fn drain_dropped_early() {
+    let tracker = Tracker::default();
+    let mut fast_arr = tracked_array(&tracker, 4);
+
+    let mut drained = fast_arr.drain_func(.., |value| tracker.track(value));
+    assert_eq!(drained.next_back().map(|element| element.value), Some(3));
+    drop(drained);
+    assert_eq!(tracker.dropped(), 4);
+
+    drop(fast_arr);
+    assert_eq!(tracker.dropped(), 8);
+}
+
+#[test]
+fn drain_with_fill_value() {
+    let mut fast_arr = fast_arr!("a".to_string(), "b".to_string(), "c".to_string());
+
+    assert_eq!(fast_arr.drain(..=1, String::new()).collect::<Vec<_>>(), ["a", "b"]);
+    assert_eq!(fast_arr, fast_arr!(String::new(), String::new(), "c".to_string()));
+}
+
+#[test]
+fn drain_options() {
+    let mut fast_arr = FastArray::new_func(3, |index| Some(Box::new(index)));
+
+    let drained: Vec<_> = fast_arr.drain_default(1..).flatten().collect();
+    assert_eq!(drained, [Box::new(1), Box::new(2)]);
+    assert_eq!(fast_arr, FastArray::from(vec![Some(Box::new(0)), None, None]));
+}
+
+#[test]
+fn drain_empty_range() {
+    let mut fast_arr = fast_arr!(1, 2, 3);
+
+    let mut drained = fast_arr.drain(2..2, 0);
+    assert_eq!(drained.len(), 0);
+    assert!(drained.next().is_none());
+    assert_eq!(fast_arr, fast_arr!(1, 2, 3));
+
+    assert_eq!(fast_arr.drain(3.., 0).count(), 0);
+}
+
+#[test]
+#[should_panic(expected = "FastIterator::into_fast_array: the iterator has no elements left")]
+fn drain_empty_range_into_fast_array() {
+    let mut fast_arr = fast_arr!(1, 2, 3);
+    fast_arr.drain(1..1, 0).into_fast_array();
+}
+
+#[test]
+fn drain_empty_range_try_into_fast_array() {
+    let mut fast_arr = fast_arr!(1, 2, 3);
+
+    assert!(matches!(fast_arr.drain(1..1, 0).try_into_fast_array(), Err(FastError::ZeroLength)));
+    assert_eq!(fast_arr.drain(1..2, 0).try_into_fast_array().unwrap(), fast_arr!(2));
+}
+
+#[test]
+#[should_panic(expected = "FastArray::drain_func: range 1..4 is out of bounds for len 3")]
+fn drain_out_of_bounds() {
+    let mut fast_arr = fast_arr!(1, 2, 3);
+    fast_arr.drain(1..4, 0);
+}
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+#[should_panic(expected = "FastArray::drain_func: range 2..1 is out of bounds for len 3")]
+fn drain_reversed_range() {
+    let mut fast_arr = fast_arr!(1, 2, 3);
+    fast_arr.drain(2..1, 0);
+}
+
+#[test]
+fn drain_panicking_func() {
+    let tracker = Tracker::default();
+    let mut fast_arr = tracked_array(&tracker, 4);
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        fast_arr.drain_func(.., |index| {
+            assert!(index < 2, "no replacement for {index}");
+            tracker.track(10 + index)
+        })
+    }));
+    assert!(result.is_err());
+
+    // the array is still whole: the first two elements were replaced, the other two never left
+    assert_eq!(values(fast_arr.iter()), [10, 11, 2, 3]);
+}
+
+#[test]
+fn into_iter_range() {
+    let tracker = Tracker::default();
+
+    let fast_iter = tracked_array(&tracker, 6).into_iter_range(2..5);
+    // the elements outside of the range are dropped right away
+    assert_eq!(tracker.dropped(), 3);
+    assert_eq!(fast_iter.len(), 3);
+
+    assert_eq!(fast_iter.rev().map(|element| element.value).collect::<Vec<_>>(), [4, 3, 2]);
+    assert_eq!(tracker.dropped(), 6);
+}
+
+#[test]
+fn into_iter_range_dropped_early() {
+    let tracker = Tracker::default();
+
+    let mut fast_iter = tracked_array(&tracker, 5).into_iter_range(1..);
+    assert_eq!(fast_iter.next().map(|element| element.value), Some(1));
+    drop(fast_iter);
+    assert_eq!(tracker.dropped(), 5);
+}
+
+#[test]
+fn into_iter_range_bounds() {
+    assert_eq!(fast_arr!(1, 2, 3).into_iter_range(..).collect::<Vec<_>>(), [1, 2, 3]);
+    assert_eq!(fast_arr!(1, 2, 3).into_iter_range(..=0).collect::<Vec<_>>(), [1]);
+    assert_eq!(fast_arr!(1, 2, 3).into_iter_range(1..1).count(), 0);
+
+    let fast_iter = fast_arr!(1, 2, 3, 4).into_iter_range(1..3);
+    assert_eq!(fast_iter.into_fast_array(), fast_arr!(2, 3));
+}
+
+#[test]
+#[should_panic(expected = "FastIterator::into_fast_array: the iterator has no elements left")]
+fn into_iter_range_empty_into_fast_array() {
+    fast_arr!(1, 2, 3).into_iter_range(1..1).into_fast_array();
+}
+
+#[test]
+fn into_iter_range_empty_try_into_fast_array() {
+    let tracker = Tracker::default();
+
+    let fast_iter = tracked_array(&tracker, 3).into_iter_range(1..1);
+    assert!(matches!(fast_iter.try_into_fast_array(), Err(FastError::ZeroLength)));
+    assert_eq!(tracker.dropped(), 3);
+}
+
+#[test]
+fn into_iter_range_zero_sized() {
+    let mut fast_iter = FastArray::new(5, ()).into_iter_range(1..3);
+
+    assert_eq!(fast_iter.len(), 2);
+    assert_eq!(fast_iter.next(), Some(()));
+    assert_eq!(fast_iter.count(), 1);
+}
+
+#[test]
+#[should_panic(expected = "FastArray::into_iter_range: range 0..6 is out of bounds for len 5")]
+fn into_iter_range_out_of_bounds() {
+    fast_arr!(1, 2, 3, 4, 5).into_iter_range(..6);
+}
+
+#[test]
+fn split_off_into() {
+    let tracker = Tracker::default();
+
+    let (head, tail) = tracked_array(&tracker, 5).split_off_into(2);
+    assert_eq!(values(head.iter()), [0, 1]);
+    assert_eq!(values(tail.iter()), [2, 3, 4]);
+    assert_eq!(tracker.dropped(), 0);
+
+    drop(head);
+    assert_eq!(tracker.dropped(), 2);
+    drop(tail);
+    assert_eq!(tracker.dropped(), 5);
+}
+
+#[test]
+fn split_off_into_edges() {
+    let (head, tail) = fast_arr!(1, 2).split_off_into(1);
+    assert_eq!((head, tail), (fast_arr!(1), fast_arr!(2)));
+
+    let (head, tail) = FastArray::new(3, ()).split_off_into(2);
+    assert_eq!((head.len(), tail.len()), (2, 1));
+
+    // the halves can be split again, as they're freed with the layout of their own length
+    let (head, tail) = FastArray::new_func(8, |index| index.to_string()).split_off_into(5);
+    let (first, second) = head.split_off_into(1);
+    assert_eq!(first, fast_arr!("0".to_string()));
+    assert_eq!(second.len(), 4);
+    assert_eq!(tail.into_iter_range(1..).collect::<Vec<_>>(), ["6", "7"]);
+}
+
+#[test]
+#[should_panic(expected = "FastArray::split_off_into: can't split at 3 an array of len 3")]
+fn split_off_into_at_len() {
+    fast_arr!(1, 2, 3).split_off_into(3);
+}
+
+#[test]
+#[should_panic(expected = "FastArray::split_off_into: can't split at 0 an array of len 3")]
+fn split_off_into_at_zero() {
+    fast_arr!(1, 2, 3).split_off_into(0);
+}
@@ -0,0 +1,153 @@
+//! the raw pointer and unchecked element access of [`FastArray`] and [`FastMatrix`].
+//!
+//! these tests are meant to be run under miri too: `cargo +nightly miri test --test raw_pointers`.
+
+use fast_collections::{fast_matrix::fast_matrix::StorageOrder, FastArray, FastMatrix};
+
+fn fast_array() -> FastArray<String> {
+    FastArray::from(vec!["a".to_string(), "b".to_string(), "c".to_string()])
+}
+
+fn fast_matrix() -> FastMatrix<i32> {
+    // [[0, 1, 2], [3, 4, 5]]
+    FastMatrix::new_func(2, 3, |(row, column)| (row * 3 + column) as i32)
+}
+
+#[test]
+fn fast_array_as_ptr() {
+    let fast_arr = fast_array();
+    let pointer = fast_arr.as_ptr();
+
+    let elements = unsafe { std::slice::from_raw_parts(pointer, fast_arr.len()) };
+    assert_eq!(elements, ["a", "b", "c"]);
+    // shared pointers can be used alongside shared borrows
+    assert_eq!(unsafe { &*pointer.add(1) }, &fast_arr[1]);
+}
+
+#[test]
+fn fast_array_as_mut_ptr() {
+    let mut fast_arr = fast_array();
+    let pointer = fast_arr.as_mut_ptr();
+
+    unsafe {
+        *pointer = "x".to_string();
+        pointer.add(1).swap(pointer.add(2));
+    }
+    assert_eq!(fast_arr, FastArray::from(vec!["x".to_string(), "c".to_string(), "b".to_string()]));
+}
+
+#[test]
+fn fast_array_get_unchecked() {
+    let mut fast_arr = fast_array();
+
+    assert_eq!(unsafe { fast_arr.get_unchecked(2) }, "c");
+    unsafe { fast_arr.get_unchecked_mut(0).push('!') };
+    assert_eq!(fast_arr[0], "a!");
+}
+
+#[test]
+fn fast_array_get_pointer() {
+    let mut fast_arr = fast_array();
+
+    assert_eq!(unsafe { &*fast_arr.get_pointer(1) }, "b");
+    assert_eq!(unsafe { &*fast_arr.get_pointer_unchecked(2) }, "c");
+
+    unsafe { *fast_arr.get_mut_pointer(0) = "y".to_string() };
+    unsafe { *fast_arr.get_mut_pointer_unchecked(1) = "z".to_string() };
+    assert_eq!(fast_arr[0], "y");
+    assert_eq!(fast_arr[1], "z");
+}
+
+#[test]
+fn fast_array_get_mut_pointer_unsafe() {
+    let fast_arr = fast_array();
+
+    unsafe {
+        let first = fast_arr.get_mut_pointer_unsafe(0);
+        let last = fast_arr.get_mut_pointer_unsafe_unchecked(2);
+        first.swap(last);
+    }
+    assert_eq!(fast_arr[0], "c");
+    assert_eq!(fast_arr[2], "a");
+}
+
+#[test]
+fn fast_array_swaps() {
+    let mut fast_arr = fast_array();
+
+    fast_arr.swap(0, 1);
+    unsafe { fast_arr.swap_unchecked(1, 2) };
+    unsafe { fast_arr.swap_unsafe(0, 2) };
+    unsafe { fast_arr.swap_unsafe_unchecked(0, 0) };
+    assert_eq!(fast_arr, FastArray::from(vec!["a".to_string(), "c".to_string(), "b".to_string()]));
+}
+
+#[test]
+fn fast_matrix_as_ptr() {
+    let mut fast_matrix = fast_matrix();
+
+    let elements = unsafe { std::slice::from_raw_parts(fast_matrix.as_ptr(), 6) };
+    assert_eq!(elements, [0, 1, 2, 3, 4, 5]);
+
+    let pointer = fast_matrix.as_mut_ptr();
+    unsafe { *pointer.add(4) = 40 };
+    assert_eq!(fast_matrix[(1, 1)], 40);
+}
+
+#[test]
+fn fast_matrix_as_ptr_follows_order() {
+    let fast_matrix = fast_matrix().into_order(StorageOrder::ColumnMajor);
+
+    let elements = unsafe { std::slice::from_raw_parts(fast_matrix.as_ptr(), 6) };
+    assert_eq!(elements, [0, 3, 1, 4, 2, 5]);
+    assert_eq!(unsafe { fast_matrix.get_unchecked((0, 2)) }, &2);
+}
+
+#[test]
+fn fast_matrix_get_unchecked() {
+    let mut fast_matrix = fast_matrix();
+
+    assert_eq!(unsafe { fast_matrix.get_unchecked((1, 2)) }, &5);
+    unsafe { *fast_matrix.get_unchecked_mut((0, 1)) += 10 };
+    assert_eq!(fast_matrix[(0, 1)], 11);
+}
+
+#[test]
+fn fast_matrix_get_pointer() {
+    let mut fast_matrix = fast_matrix();
+
+    assert_eq!(unsafe { *fast_matrix.get_pointer((1, 0)) }, 3);
+    assert_eq!(unsafe { *fast_matrix.get_pointer_unchecked((0, 2)) }, 2);
+
+    unsafe {
+        *fast_matrix.get_pointer_mut((0, 0)) = -1;
+        *fast_matrix.get_pointer_mut_unchecked((1, 2)) = -2;
+        *fast_matrix.get_pointer_mut_unsafe((1, 1)) = -3;
+        *fast_matrix.get_pointer_mut_unchecked_unsafe((0, 1)) = -4;
+    }
+    assert_eq!(fast_matrix, FastMatrix::from(vec![vec![-1, -4, 2], vec![3, -3, -2]]));
+}
+
+#[test]
+fn fast_matrix_swaps() {
+    let mut fast_matrix = fast_matrix();
+
+    fast_matrix.swap((0, 0), (1, 2));
+    unsafe {
+        fast_matrix.swap_unchecked((0, 1), (1, 1));
+        fast_matrix.swap_unsafe((0, 2), (1, 0));
+        fast_matrix.swap_unchecked_unsafe((1, 0), (1, 0));
+    }
+    assert_eq!(fast_matrix, FastMatrix::from(vec![vec![5, 4, 3], vec![2, 1, 0]]));
+}
+
+#[test]
+fn fast_matrix_get_row_and_column() {
+    let fast_matrix = fast_matrix();
+
+    assert_eq!(fast_matrix.get_row(1), FastArray::from(vec![3, 4, 5]));
+    assert_eq!(unsafe { fast_matrix.get_row_unchecked(0) }, FastArray::from(vec![0, 1, 2]));
+    // the matrix isn't square, so a column has as many elements as there are rows
+    assert_eq!(fast_matrix.get_column(2), FastArray::from(vec![2, 5]));
+    assert_eq!(unsafe { fast_matrix.get_column_unchecked(1) }, FastArray::from(vec![1, 4]));
+}
@@ -0,0 +1,290 @@
+#![cfg(feature = "serde")]
+
+mod common;
+
+use std::sync::atomic::{AtomicIsize, Ordering};
+
+use common::formats::{binary, json};
+use fast_collections::{
+    fast_arr,
+    fast_array::fast_array_serde::{bytes, AsBytes},
+    fast_matrix,
+    fast_matrix::{fast_matrix::StorageOrder, fast_matrix_serde::nested},
+    FastArray, FastMatrix,
+};
+use serde::{
+    de::{self, value::SeqAccessDeserializer, SeqAccess},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+#[test]
+fn fast_array_json_round_trip() {
+    let fast_arr = fast_arr!(1, -2, 3);
+    let text = json::to_string(&fast_arr).unwrap();
+
+    assert_eq!(text, "[1,-2,3]");
+    assert_eq!(json::from_str::<FastArray<i32>>(&text).unwrap(), fast_arr);
+
+    let strings = FastArray::from(vec!["a".to_string(), "b \"quoted\"".to_string()]);
+    let text = json::to_string(&strings).unwrap();
+    assert_eq!(json::from_str::<FastArray<String>>(&text).unwrap(), strings);
+}
+
+#[test]
+fn fast_array_binary_round_trip() {
+    let fast_arr = FastArray::new_func(1000, |index| index as f64 * 0.5);
+    let bytes = binary::to_bytes(&fast_arr).unwrap();
+
+    assert_eq!(bytes.len(), 8 + 1000 * 8);
+    assert_eq!(binary::from_bytes::<FastArray<f64>>(&bytes).unwrap(), fast_arr);
+
+    let strings = FastArray::from(vec!["first".to_string(), "second".to_string()]);
+    let bytes = binary::to_bytes(&strings).unwrap();
+    assert_eq!(binary::from_bytes::<FastArray<String>>(&bytes).unwrap(), strings);
+}
+
+#[test]
+fn fast_array_nested_round_trip() {
+    let nested = FastArray::from(vec![fast_arr!(1_u8), fast_arr!(2, 3), fast_arr!(4, 5, 6)]);
+
+    let text = json::to_string(&nested).unwrap();
+    assert_eq!(text, "[[1],[2,3],[4,5,6]]");
+    assert_eq!(json::from_str::<FastArray<FastArray<u8>>>(&text).unwrap(), nested);
+
+    let bytes = binary::to_bytes(&nested).unwrap();
+    assert_eq!(binary::from_bytes::<FastArray<FastArray<u8>>>(&bytes).unwrap(), nested);
+}
+
+#[test]
+fn fast_array_empty_sequence_is_an_error() {
+    assert!(json::from_str::<FastArray<i32>>("[]").is_err());
+    assert!(binary::from_bytes::<FastArray<i32>>(&0_u64.to_le_bytes()).is_err());
+}
+
+#[test]
+fn fast_array_invalid_elements_are_errors() {
+    assert!(json::from_str::<FastArray<i32>>("[1,2,\"three\"]").is_err());
+    assert!(json::from_str::<FastArray<i32>>("[1,2").is_err());
+    assert!(json::from_str::<FastArray<u8>>("[1,256]").is_err());
+
+    // announces 3 elements, but only has 2
+    let mut bytes = 3_u64.to_le_bytes().to_vec();
+    bytes.extend_from_slice(&[1, 0, 0, 0, 2, 0, 0, 0]);
+    assert!(binary::from_bytes::<FastArray<i32>>(&bytes).is_err());
+}
+
+/// a sequence whose size hint is wrong, to test the growing and shrinking paths.
+struct WrongHint {
+    elements: std::vec::IntoIter<i32>,
+    hint: usize,
+}
+
+impl<'de> SeqAccess<'de> for WrongHint {
+    type Error = de::value::Error;
+
+    fn next_element_seed<T: de::DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error> {
+        self.elements
+            .next()
+            .map(|element| seed.deserialize(de::value::I32Deserializer::new(element)))
+            .transpose()
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.hint)
+    }
+}
+
+#[test]
+fn fast_array_wrong_size_hint() {
+    let elements: Vec<i32> = (0..100).collect();
+
+    for hint in [0, 1, 50, 100, 1000] {
+        let seq = WrongHint { elements: elements.clone().into_iter(), hint };
+        let fast_arr = FastArray::<i32>::deserialize(SeqAccessDeserializer::new(seq)).unwrap();
+
+        assert_eq!(fast_arr, FastArray::from(elements.clone()));
+    }
+}
+
+static LIVE: AtomicIsize = AtomicIsize::new(0);
+
+/// counts how many values are alive, to check that nothing leaks or gets dropped twice.
+#[derive(Debug, PartialEq)]
+struct Counted(i32);
+
+impl<'de> Deserialize<'de> for Counted {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = i32::deserialize(deserializer)?;
+        LIVE.fetch_add(1, Ordering::SeqCst);
+        Ok(Counted(value))
+    }
+}
+
+impl Drop for Counted {
+    fn drop(&mut self) {
+        LIVE.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn fast_array_partial_elements_are_dropped() {
+    let fast_arr = json::from_str::<FastArray<Counted>>("[1,2,3,4,5]").unwrap();
+    assert_eq!(LIVE.load(Ordering::SeqCst), 5);
+    drop(fast_arr);
+    assert_eq!(LIVE.load(Ordering::SeqCst), 0);
+
+    // fails after 4 elements were deserialized, while growing
+    assert!(json::from_str::<FastArray<Counted>>("[1,2,3,4,oops]").is_err());
+    assert_eq!(LIVE.load(Ordering::SeqCst), 0);
+
+    // fails after 2 elements were deserialized, with a preallocated array
+    let mut bytes = 3_u64.to_le_bytes().to_vec();
+    bytes.extend_from_slice(&[1, 0, 0, 0, 2, 0, 0, 0]);
+    assert!(binary::from_bytes::<FastArray<Counted>>(&bytes).is_err());
+    assert_eq!(LIVE.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn fast_matrix_json_round_trip() {
+    let fast_matrix = fast_matrix!([1, 2, 3], [4, 5, 6]);
+    let text = json::to_string(&fast_matrix).unwrap();
+
+    assert_eq!(text, "{\"rows\":2,\"columns\":3,\"data\":[1,2,3,4,5,6]}");
+    assert_eq!(json::from_str::<FastMatrix<i32>>(&text).unwrap(), fast_matrix);
+
+    // the fields can come in any order, and unknown ones are skipped
+    let text = "{\"data\":[1,2,3,4,5,6],\"version\":[1,0],\"columns\":2,\"rows\":3}";
+    assert_eq!(json::from_str::<FastMatrix<i32>>(text).unwrap(), fast_matrix!([1, 2], [3, 4], [5, 6]));
+}
+
+#[test]
+fn fast_matrix_binary_round_trip() {
+    let fast_matrix = FastMatrix::new_func(3, 4, |(row, column)| (row * 4 + column) as f32);
+    let bytes = binary::to_bytes(&fast_matrix).unwrap();
+
+    // rows, columns, the len of data, then the elements
+    assert_eq!(bytes.len(), 8 * 3 + 12 * 4);
+    assert_eq!(binary::from_bytes::<FastMatrix<f32>>(&bytes).unwrap(), fast_matrix);
+}
+
+#[test]
+fn fast_matrix_is_serialized_in_row_major_order() {
+    let column_major = fast_matrix!([1, 2, 3], [4, 5, 6]).into_order(StorageOrder::ColumnMajor);
+    let text = json::to_string(&column_major).unwrap();
+
+    assert_eq!(text, "{\"rows\":2,\"columns\":3,\"data\":[1,2,3,4,5,6]}");
+    assert_eq!(json::from_str::<FastMatrix<i32>>(&text).unwrap(), fast_matrix!([1, 2, 3], [4, 5, 6]));
+}
+
+#[test]
+fn fast_matrix_invalid_shapes_are_errors() {
+    assert!(json::from_str::<FastMatrix<i32>>("{\"rows\":2,\"columns\":2,\"data\":[1,2,3]}").is_err());
+    assert!(json::from_str::<FastMatrix<i32>>("{\"rows\":0,\"columns\":2,\"data\":[]}").is_err());
+    assert!(json::from_str::<FastMatrix<i32>>("{\"rows\":1,\"data\":[1]}").is_err());
+    assert!(json::from_str::<FastMatrix<i32>>("{\"rows\":1,\"rows\":1,\"columns\":1,\"data\":[1]}").is_err());
+
+    let overflowing = format!("{{\"rows\":{},\"columns\":2,\"data\":[1,2]}}", usize::MAX);
+    assert!(json::from_str::<FastMatrix<i32>>(&overflowing).is_err());
+}
+
+/// a [`FastMatrix`] using the nested representation, like `#[serde(with = "nested")]` would.
+#[derive(Debug, PartialEq)]
+struct Nested(FastMatrix<i32>);
+
+impl Serialize for Nested {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        nested::serialize(&self.0, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Nested {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        nested::deserialize(deserializer).map(Nested)
+    }
+}
+
+#[test]
+fn fast_matrix_nested_round_trip() {
+    let fast_matrix = Nested(fast_matrix!([1, 2, 3], [4, 5, 6]));
+
+    let text = json::to_string(&fast_matrix).unwrap();
+    assert_eq!(text, "[[1,2,3],[4,5,6]]");
+    assert_eq!(json::from_str::<Nested>(&text).unwrap(), fast_matrix);
+
+    let bytes = binary::to_bytes(&fast_matrix).unwrap();
+    assert_eq!(binary::from_bytes::<Nested>(&bytes).unwrap(), fast_matrix);
+}
+
+#[test]
+fn fast_matrix_nested_invalid_shapes_are_errors() {
+    assert!(json::from_str::<Nested>("[[1,2],[3]]").is_err());
+    assert!(json::from_str::<Nested>("[[1],[2,3]]").is_err());
+    assert!(json::from_str::<Nested>("[]").is_err());
+    assert!(json::from_str::<Nested>("[[],[]]").is_err());
+}
+
+/// a byte buffer using the bytes representation, like `#[serde(with = "bytes")]` would.
+#[derive(Debug, PartialEq)]
+struct Bytes(FastArray<u8>);
+
+impl Serialize for Bytes {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        bytes::serialize(&self.0, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Bytes {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        bytes::deserialize(deserializer).map(Bytes)
+    }
+}
+
+#[test]
+fn fast_array_bytes_round_trip() {
+    let buffer = Bytes(FastArray::new_func(300, |index| index as u8));
+
+    let bytes = binary::to_bytes(&buffer).unwrap();
+    assert_eq!(bytes.len(), 8 + 300);
+    assert_eq!(&bytes[8..], buffer.0.as_bytes());
+    assert_eq!(binary::from_bytes::<Bytes>(&bytes).unwrap(), buffer);
+
+    // formats without a bytes type fall back to a sequence
+    let text = json::to_string(&Bytes(fast_arr!(1, 2, 255))).unwrap();
+    assert_eq!(text, "[1,2,255]");
+    assert_eq!(json::from_str::<Bytes>(&text).unwrap(), Bytes(fast_arr!(1, 2, 255)));
+
+    assert!(binary::from_bytes::<Bytes>(&0_u64.to_le_bytes()).is_err());
+}
+
+#[test]
+fn fast_array_from_byte_blob() {
+    let deserializer = de::value::BytesDeserializer::<de::value::Error>::new(&[7, 8, 9]);
+    assert_eq!(FastArray::<u8>::deserialize(deserializer).unwrap(), fast_arr!(7, 8, 9));
+
+    let deserializer = de::value::BytesDeserializer::<de::value::Error>::new(&[7, 8, 9]);
+    assert_eq!(FastArray::<u32>::deserialize(deserializer).unwrap(), fast_arr!(7, 8, 9));
+}
+
+#[test]
+fn fast_array_as_bytes_round_trip() {
+    let samples = AsBytes(FastArray::new_func(100, |index| index as f32 * 0.25 - 3.0));
+
+    let bytes = binary::to_bytes(&samples).unwrap();
+    assert_eq!(bytes.len(), 8 + 100 * 4);
+    assert_eq!(&bytes[8..12], &(-3.0_f32).to_le_bytes());
+    assert_eq!(binary::from_bytes::<AsBytes<FastArray<f32>>>(&bytes).unwrap(), samples);
+
+    // a borrowed array serializes the same way
+    assert_eq!(binary::to_bytes(&AsBytes(&samples.0)).unwrap(), bytes);
+
+    let text = json::to_string(&AsBytes(fast_arr!(1_u16, 258))).unwrap();
+    assert_eq!(text, "[1,0,2,1]");
+    assert_eq!(json::from_str::<AsBytes<FastArray<u16>>>(&text).unwrap().into_inner(), fast_arr!(1, 258));
+}
+
+#[test]
+fn fast_array_as_bytes_invalid_lens_are_errors() {
+    assert!(json::from_str::<AsBytes<FastArray<u32>>>("[1,2,3]").is_err());
+    assert!(json::from_str::<AsBytes<FastArray<u32>>>("[]").is_err());
+    assert!(json::from_str::<AsBytes<FastArray<u32>>>("[1,2,3,256]").is_err());
+}
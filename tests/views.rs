@@ -0,0 +1,40 @@
+//! comparing [`MatrixView`]s with matrices, whatever the storage order of either side.
+
+use fast_collections::{
+    fast_matrix,
+    fast_matrix::{fast_matrix::StorageOrder, fast_matrix_view::MatrixView},
+    FastMatrix,
+};
+
+fn fast_matrix() -> FastMatrix<i32> {
+    fast_matrix!([1, 2, 3], [4, 5, 6])
+}
+
+#[test]
+fn view_eq_column_major_matrix() {
+    let fast_matrix = fast_matrix();
+    let column_major = fast_matrix.clone().to_order(StorageOrder::ColumnMajor);
+
+    assert!(fast_matrix.as_view() == column_major);
+    assert!(column_major.as_view() == fast_matrix);
+    assert!(column_major.as_view() == column_major);
+}
+
+#[test]
+fn view_ne_column_major_matrix() {
+    let row_major = fast_matrix!([1, 2], [3, 4]);
+    let column_major = fast_matrix!([1, 3], [2, 4]).to_order(StorageOrder::ColumnMajor);
+
+    // same elements in memory, but not the same matrix
+    assert!(row_major.as_view() != column_major);
+    assert!(row_major.transpose_view() == column_major);
+}
+
+#[test]
+fn sub_view_eq_column_major_matrix() {
+    let fast_matrix = fast_matrix!([1, 2, 3], [4, 5, 6], [7, 8, 9]).to_order(StorageOrder::ColumnMajor);
+    let view: MatrixView<'_, i32> = fast_matrix.view(1..3, 0..2);
+
+    assert!(view == fast_matrix!([4, 5], [7, 8]).to_order(StorageOrder::ColumnMajor));
+    assert!(view == fast_matrix!([4, 5], [7, 8]));
+}